@@ -0,0 +1,41 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pathogen::{KeyPathElement, KeyPathFrom};
+
+/// Stands in for a tree of nested maps, e.g. `BTreeMap<String, Directory>`
+struct Directory;
+
+/// A keypath five directories deep, with a map key at every level
+fn deep_key_path() -> KeyPathFrom<Directory> {
+    let path = ["home", "someone", "projects", "pathogen", "src"]
+        .into_iter()
+        .flat_map(|name| {
+            [
+                KeyPathElement::Field {
+                    key: "entries".into(),
+                },
+                KeyPathElement::StringKey { key: name.into() },
+            ]
+        })
+        .collect();
+
+    KeyPathFrom::dangerously_construct_from_path(path)
+}
+
+fn clone_key_paths(c: &mut Criterion) {
+    let key_path = deep_key_path();
+
+    c.bench_function("clone map-heavy key path", |b| {
+        b.iter(|| black_box(&key_path).clone())
+    });
+
+    c.bench_function("clone map-heavy key path 100 times", |b| {
+        b.iter(|| {
+            (0..100)
+                .map(|_| black_box(&key_path).clone())
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
+criterion_group!(benches, clone_key_paths);
+criterion_main!(benches);
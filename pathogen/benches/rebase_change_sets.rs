@@ -0,0 +1,61 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pathogen::{ChangeOf, ChangeSet, KeyPathElement, KeyPathFrom};
+
+/// Stands in for a tree of nested maps, e.g. `BTreeMap<String, Directory>`
+struct Directory;
+
+/// A keypath five directories deep, with a map key at every level
+fn deep_key_path() -> KeyPathFrom<Directory> {
+    let path = ["home", "someone", "projects", "pathogen", "src"]
+        .into_iter()
+        .flat_map(|name| {
+            [
+                KeyPathElement::Field {
+                    key: "entries".into(),
+                },
+                KeyPathElement::StringKey { key: name.into() },
+            ]
+        })
+        .collect();
+
+    KeyPathFrom::dangerously_construct_from_path(path)
+}
+
+/// 100 updates of the size of files in a directory
+fn change_set() -> ChangeSet<Directory> {
+    (0..100)
+        .map(|i| ChangeOf::Update {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                KeyPathElement::Field {
+                    key: "entries".into(),
+                },
+                KeyPathElement::StringKey {
+                    key: format!("file{i}").as_str().into(),
+                },
+                KeyPathElement::Field { key: "size".into() },
+            ]),
+            value: i.into(),
+        })
+        .collect()
+}
+
+fn rebase_change_sets(c: &mut Criterion) {
+    let base = deep_key_path().downcast::<Directory>();
+    let changes = change_set();
+
+    c.bench_function("rebase 100 changes one by one", |b| {
+        b.iter(|| {
+            black_box(&changes)
+                .iter()
+                .map(|change| change.rebase(&base))
+                .collect::<ChangeSet<_>>()
+        })
+    });
+
+    c.bench_function("rebase a set of 100 changes", |b| {
+        b.iter(|| black_box(&changes).rebase(&base))
+    });
+}
+
+criterion_group!(benches, rebase_change_sets);
+criterion_main!(benches);
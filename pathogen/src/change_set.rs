@@ -0,0 +1,1061 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use crate::{
+    diff::diff_json, ChangeOf, KeyPath, KeyPathElement, KeyPathError, KeyPathFrom, KeyPathMutable,
+};
+
+/// An ordered batch of changes to the same root type
+#[derive(Debug, PartialEq)]
+pub struct ChangeSet<Root> {
+    changes: Vec<ChangeOf<Root>>,
+}
+
+impl<Root> ChangeSet<Root> {
+    pub fn new() -> Self {
+        Self { changes: vec![] }
+    }
+
+    /// Merge consecutive splices on the same list into a single splice where possible.
+    ///
+    /// Two splices are merged when they're next to each other in the set, have the same keypath
+    /// and the second one starts right where the first one's inserted values end, i.e.
+    /// `second.start == first.start + first.value.len()`. The merged splice inserts the values
+    /// of both and replaces `first.replace + second.replace` items starting at `first.start`,
+    /// which results in the same list as applying the two separately.
+    pub fn merge_adjacent_splices(&mut self) {
+        let mut merged: Vec<ChangeOf<Root>> = Vec::with_capacity(self.changes.len());
+
+        for change in self.changes.drain(..) {
+            if let (
+                Some(ChangeOf::Splice {
+                    key_path: previous_path,
+                    value: previous_value,
+                    start: previous_start,
+                    replace: previous_replace,
+                }),
+                ChangeOf::Splice {
+                    key_path,
+                    value,
+                    start,
+                    replace,
+                },
+            ) = (merged.last_mut(), &change)
+            {
+                if previous_path.path == key_path.path
+                    && *start == *previous_start + previous_value.len()
+                {
+                    previous_value.extend(value.iter().cloned());
+                    *previous_replace += replace;
+                    continue;
+                }
+            }
+
+            merged.push(change);
+        }
+
+        self.changes = merged;
+    }
+
+    /// Drop updates and deletes which are followed by another update or delete of the exact same
+    /// keypath, since the later one overwrites them. The remaining changes keep their relative
+    /// order.
+    ///
+    /// Splices depend on the state of the list they're applied to, so they're always kept. A
+    /// splice also shifts the items after it, so a later change to an item of its list doesn't
+    /// overwrite changes before the splice.
+    pub fn dedup_by_path(&mut self) {
+        let mut seen: HashSet<&[KeyPathElement]> = HashSet::new();
+        let keep: Vec<bool> = self
+            .changes
+            .iter()
+            .rev()
+            .map(|change| match change {
+                ChangeOf::Update { key_path, .. } | ChangeOf::Delete { key_path } => {
+                    seen.insert(key_path.path.as_slice())
+                }
+                ChangeOf::Splice { key_path, .. } => {
+                    let list = key_path.path.as_slice();
+                    seen.retain(|later| later.len() <= list.len() || !later.starts_with(list));
+
+                    true
+                }
+            })
+            .collect();
+
+        let mut keep = keep.into_iter().rev();
+        self.changes.retain(|_| keep.next().unwrap_or(true));
+    }
+
+    pub fn push(&mut self, change: ChangeOf<Root>) {
+        self.changes.push(change);
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, ChangeOf<Root>> {
+        self.changes.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, ChangeOf<Root>> {
+        self.changes.iter_mut()
+    }
+
+    /// Keep only the changes for which `keep` returns true, in their order
+    pub fn retain(&mut self, keep: impl FnMut(&ChangeOf<Root>) -> bool) {
+        self.changes.retain(keep);
+    }
+
+    /// Transform the value of every change in place: the value of each update and each of the
+    /// values inserted by each splice. Deletes have no value to transform.
+    ///
+    /// The values aren't checked against the types at the keypaths, so a transform changing
+    /// their shape produces changes which fail to apply.
+    pub fn map_value_each(&mut self, mut transform: impl FnMut(&mut serde_json::Value)) {
+        for change in &mut self.changes {
+            match change {
+                ChangeOf::Update { value, .. } => transform(value),
+                ChangeOf::Splice { value, .. } => value.iter_mut().for_each(&mut transform),
+                ChangeOf::Delete { .. } => {}
+            }
+        }
+    }
+}
+
+impl<Root: 'static> ChangeSet<Root> {
+    /// Drop changes which are overwritten by a later update or delete of the same keypath or of
+    /// one containing it, e.g. an update of `meta.version` followed by an update of `meta`. The
+    /// remaining changes keep their relative order and result in the same state.
+    ///
+    /// Splices are positional, so they're kept unless a later change overwrites their whole list.
+    /// A splice also shifts the items after it, so a later change to an item of its list doesn't
+    /// overwrite changes before the splice.
+    pub fn coalesce(&mut self) {
+        let mut overwritten: Vec<KeyPathFrom<Root>> = vec![];
+        let keep: Vec<bool> = self
+            .changes
+            .iter()
+            .rev()
+            .map(|change| {
+                let key_path = change.key_path();
+
+                if overwritten
+                    .iter()
+                    .any(|later| later.path == key_path.path || later.is_subpath_of(key_path))
+                {
+                    return false;
+                }
+
+                match change {
+                    ChangeOf::Update { .. } | ChangeOf::Delete { .. } => {
+                        overwritten.push(key_path.clone())
+                    }
+                    ChangeOf::Splice { .. } => {
+                        overwritten.retain(|later| !key_path.is_subpath_of(later))
+                    }
+                }
+
+                true
+            })
+            .collect();
+
+        let mut keep = keep.into_iter().rev();
+        self.changes.retain(|_| keep.next().unwrap_or(true));
+    }
+
+    /// Rebase every change onto `base`, like `ChangeOf::rebase`. Each rebased keypath is built
+    /// in a single allocation straight from `base`'s elements.
+    pub fn rebase<Base>(&self, base: &KeyPath<Base, Root>) -> ChangeSet<Base> {
+        self.changes
+            .iter()
+            .map(|change| change.prepend_elements(&base.path))
+            .collect()
+    }
+
+    /// Find the pairs of changes, as indices into `self` and `other`, whose keypaths overlap,
+    /// i.e. are equal or one contains the other
+    pub fn conflicts_with(&self, other: &ChangeSet<Root>) -> Vec<(usize, usize)> {
+        let mut conflicts = vec![];
+
+        for (own_index, own_change) in self.changes.iter().enumerate() {
+            let own_path = own_change.key_path();
+
+            for (other_index, other_change) in other.changes.iter().enumerate() {
+                let other_path = other_change.key_path();
+
+                if own_path.path == other_path.path
+                    || own_path.is_subpath_of(other_path)
+                    || other_path.is_subpath_of(own_path)
+                {
+                    conflicts.push((own_index, other_index));
+                }
+            }
+        }
+
+        conflicts
+    }
+}
+
+impl<Root: KeyPathMutable> ChangeSet<Root> {
+    /// Structurally diff two JSON values and collect the changes turning `before` into `after`,
+    /// with their keypaths starting at `base`.
+    ///
+    /// This is useful for callers which only have untyped JSON. Like `diff`, each difference is
+    /// addressed with the keypath `Root` accepts for it, so members of a map are its entries,
+    /// while objects with a different set of keys which aren't maps (e.g. enums with a different
+    /// variant) are replaced whole.
+    pub fn from_json_diff(
+        base: &KeyPathFrom<Root>,
+        before: &serde_json::Value,
+        after: &serde_json::Value,
+    ) -> Self {
+        let mut changes = vec![];
+        diff_json(&mut base.path.clone(), before, after, &mut changes);
+
+        Self { changes }
+    }
+
+    /// Diff two values and collect the changes turning `before` into `after`, e.g. to forward
+    /// only what changed in a new snapshot of the state instead of replacing all of it.
+    ///
+    /// The values are diffed in their serialized form, addressing each difference with the
+    /// keypath `Root` accepts for it: struct fields, map entries (which are inserted and deleted
+    /// individually), tuple items and list items. Lists which only differ in a few items get
+    /// targeted splices. Values which can't be diffed further, like enums, are replaced whole.
+    pub fn diff(before: &Root, after: &Root) -> Self
+    where
+        Root: Serialize,
+    {
+        let before = serde_json::to_value(before).expect("Failed to serialize value");
+        let after = serde_json::to_value(after).expect("Failed to serialize value");
+
+        let mut changes = vec![];
+        diff_json(&mut vec![], &before, &after, &mut changes);
+
+        Self { changes }
+    }
+
+    /// Check every change's keypath against the structure of `Root`, independently of any value,
+    /// and report the index of the first one which is invalid.
+    ///
+    /// This allows rejecting a malformed batch before applying any of it.
+    pub fn validate_structure(&self) -> Result<(), (usize, KeyPathError)> {
+        self.changes
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, change)| {
+                Root::validate_path(&change.key_path().path).map_err(|error| (index, error))
+            })
+    }
+}
+
+// Implement these manualy in order to not require `Root` to implement them too
+impl<Root> Default for ChangeSet<Root> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Root> Clone for ChangeSet<Root> {
+    fn clone(&self) -> Self {
+        Self {
+            changes: self.changes.clone(),
+        }
+    }
+}
+
+impl<Root> From<Vec<ChangeOf<Root>>> for ChangeSet<Root> {
+    fn from(changes: Vec<ChangeOf<Root>>) -> Self {
+        Self { changes }
+    }
+}
+
+impl<Root> FromIterator<ChangeOf<Root>> for ChangeSet<Root> {
+    fn from_iter<I: IntoIterator<Item = ChangeOf<Root>>>(iter: I) -> Self {
+        Self {
+            changes: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<Root> IntoIterator for ChangeSet<Root> {
+    type Item = ChangeOf<Root>;
+    type IntoIter = std::vec::IntoIter<ChangeOf<Root>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.into_iter()
+    }
+}
+
+impl<'a, Root> IntoIterator for &'a ChangeSet<Root> {
+    type Item = &'a ChangeOf<Root>;
+    type IntoIter = std::slice::Iter<'a, ChangeOf<Root>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.iter()
+    }
+}
+
+impl<'a, Root> IntoIterator for &'a mut ChangeSet<Root> {
+    type Item = &'a mut ChangeOf<Root>;
+    type IntoIter = std::slice::IterMut<'a, ChangeOf<Root>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use pretty_assertions::assert_eq;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        keypath,
+        macros::{KeyPathMutable, Navigable},
+        Change, ChangeKind, KeyPathElement, Navigable as _, PatchBatch,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Document {
+        title: String,
+        meta: Meta,
+        tags: Vec<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Meta {
+        version: usize,
+        author: String,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Library {
+        documents: Vec<Document>,
+    }
+
+    #[test]
+    fn rebases_every_change() {
+        let changes = ChangeSet::from(vec![
+            Change::update(keypath![Document: title], "Final".to_string()),
+            Change::splice(keypath![Document: tags], vec!["c".to_string()], 2, 0),
+            Change::update(keypath![Document: meta.version], 2),
+        ]);
+
+        let rebased = changes.rebase(&keypath![Library: documents[1]]);
+
+        assert_eq!(
+            rebased,
+            changes
+                .iter()
+                .map(|change| change.rebase(&keypath![Library: documents[1]]))
+                .collect()
+        );
+        assert_eq!(
+            rebased
+                .iter()
+                .map(|change| change.key_path().to_string())
+                .collect::<Vec<_>>(),
+            [
+                ".documents[1].title",
+                ".documents[1].tags",
+                ".documents[1].meta.version"
+            ]
+        );
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Snapshot {
+        document: Document,
+        scores: BTreeMap<String, usize>,
+        position: (f64, f64),
+        status: Status,
+        note: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    enum Status {
+        Draft { revision: usize },
+        Published { url: String },
+    }
+
+    fn snapshot() -> Snapshot {
+        Snapshot {
+            document: Document {
+                title: "Draft".to_string(),
+                meta: Meta {
+                    version: 1,
+                    author: "Ann".to_string(),
+                },
+                tags: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            },
+            scores: BTreeMap::from([("ann".to_string(), 1), ("bob".to_string(), 2)]),
+            position: (0.0, 0.0),
+            status: Status::Draft { revision: 1 },
+            note: None,
+        }
+    }
+
+    fn assert_diff_applies(before: &Snapshot, after: &Snapshot) {
+        let mut patched = before.clone();
+        patched
+            .apply_change_set(&ChangeSet::diff(before, after))
+            .unwrap();
+
+        assert_eq!(&patched, after);
+    }
+
+    #[test]
+    fn diffs_typed_values_down_to_the_changed_leaves() {
+        let before = snapshot();
+        let mut after = snapshot();
+        after.document.meta.version = 2;
+        after.position.1 = 3.0;
+        after.note = Some("checked".to_string());
+
+        let changes = ChangeSet::diff(&before, &after);
+
+        assert_eq!(
+            changes,
+            ChangeSet::from(vec![
+                Change::update(keypath![Snapshot: document.meta.version], 2),
+                Change::update(keypath![Snapshot: note], Some("checked".to_string())),
+                Change::update(keypath![Snapshot: position].fields().1, 3.0),
+            ])
+        );
+        assert_diff_applies(&before, &after);
+        assert!(ChangeSet::diff(&before, &before).is_empty());
+    }
+
+    #[test]
+    fn diffs_lists_into_targeted_splices() {
+        let before = snapshot();
+        let mut after = snapshot();
+        after.document.tags.insert(1, "x".to_string());
+
+        assert_eq!(
+            ChangeSet::diff(&before, &after),
+            ChangeSet::from(vec![Change::splice(
+                keypath![Snapshot: document.tags],
+                vec!["x".to_string()],
+                1,
+                0
+            )])
+        );
+        assert_diff_applies(&before, &after);
+    }
+
+    #[test]
+    fn replaces_lists_whole_where_they_cant_be_spliced() {
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, KeyPathMutable)]
+        struct Frozen {
+            tags: Box<[String]>,
+        }
+
+        let before = Frozen {
+            tags: vec!["a".to_string(), "b".to_string()].into(),
+        };
+        let after = Frozen {
+            tags: vec!["a".to_string(), "x".to_string(), "b".to_string()].into(),
+        };
+
+        let changes = ChangeSet::diff(&before, &after);
+
+        assert_eq!(
+            changes,
+            ChangeSet::from(vec![ChangeOf::Update {
+                key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                    KeyPathElement::Field { key: "tags".into() }
+                ]),
+                value: json!(["a", "x", "b"]),
+            }])
+        );
+
+        let mut patched = before.clone();
+        patched.apply_change_set(&changes).unwrap();
+        assert_eq!(patched, after);
+    }
+
+    #[test]
+    fn diffs_map_entries_individually() {
+        let before = snapshot();
+        let mut after = snapshot();
+        after.scores.remove("ann");
+        after.scores.insert("bob".to_string(), 3);
+        after.scores.insert("cid".to_string(), 4);
+
+        assert_eq!(
+            ChangeSet::diff(&before, &after),
+            ChangeSet::from(vec![
+                Change::delete(keypath![Snapshot: scores["ann".to_string()]]),
+                Change::update(keypath![Snapshot: scores["bob".to_string()]], 3),
+                Change::update(keypath![Snapshot: scores["cid".to_string()]], 4),
+            ])
+        );
+        assert_diff_applies(&before, &after);
+    }
+
+    #[test]
+    fn diffs_json_map_members_as_entries() {
+        let before = snapshot();
+        let mut after = snapshot();
+        after.scores.remove("ann");
+        after.scores.insert("cid".to_string(), 4);
+
+        let changes = ChangeSet::<Snapshot>::from_json_diff(
+            &KeyPathFrom::dangerously_construct_from_path(vec![]),
+            &serde_json::to_value(&before).unwrap(),
+            &serde_json::to_value(&after).unwrap(),
+        );
+
+        assert_eq!(
+            changes,
+            ChangeSet::from(vec![
+                Change::delete(keypath![Snapshot: scores["ann".to_string()]]),
+                Change::update(keypath![Snapshot: scores["cid".to_string()]], 4),
+            ])
+        );
+    }
+
+    #[test]
+    fn diffs_dynamic_json_members_as_entries() {
+        let before = json!({ "a": 1, "b": 2 });
+        let after = json!({ "a": 1, "c": 3 });
+        let entry = |key: &str| {
+            KeyPathFrom::dangerously_construct_from_path(vec![KeyPathElement::StringKey {
+                key: key.into(),
+            }])
+        };
+
+        let changes = ChangeSet::diff(&before, &after);
+
+        assert_eq!(
+            changes,
+            ChangeSet::from(vec![
+                ChangeOf::Delete {
+                    key_path: entry("b"),
+                },
+                ChangeOf::Update {
+                    key_path: entry("c"),
+                    value: json!(3),
+                },
+            ])
+        );
+
+        let mut patched = before.clone();
+        patched.apply_change_set(&changes).unwrap();
+        assert_eq!(patched, after);
+    }
+
+    #[test]
+    fn replaces_enums_whole() {
+        let before = snapshot();
+        let mut after = snapshot();
+        after.status = Status::Draft { revision: 2 };
+
+        assert_eq!(
+            ChangeSet::diff(&before, &after),
+            ChangeSet::from(vec![Change::update(
+                keypath![Snapshot: status],
+                Status::Draft { revision: 2 }
+            )])
+        );
+        assert_diff_applies(&before, &after);
+
+        after.status = Status::Published {
+            url: "https://example.com".to_string(),
+        };
+        assert_diff_applies(&before, &after);
+    }
+
+    #[test]
+    fn diffs_nested_json_objects() {
+        let before = json!({
+            "title": "Draft",
+            "meta": { "version": 1, "author": "Ann" },
+            "tags": ["a", "b"],
+        });
+        let after = json!({
+            "title": "Draft",
+            "meta": { "version": 2, "author": "Ann" },
+            "tags": ["a", "b"],
+        });
+
+        let changes = ChangeSet::<Document>::from_json_diff(
+            &KeyPathFrom::dangerously_construct_from_path(vec![]),
+            &before,
+            &after,
+        );
+
+        assert_eq!(
+            changes,
+            ChangeSet::from(vec![ChangeOf::Update {
+                key_path: keypath![Document: meta.version].into(),
+                value: json!(2),
+            }])
+        );
+
+        let mut document: Document = serde_json::from_value(before).unwrap();
+        for change in &changes {
+            document.apply_change(change);
+        }
+
+        assert_eq!(document, serde_json::from_value(after).unwrap());
+    }
+
+    #[test]
+    fn diffs_a_changed_array() {
+        let before = json!({
+            "title": "Draft",
+            "meta": { "version": 1, "author": "Ann" },
+            "tags": ["a", "b", "c", "d"],
+        });
+        let after = json!({
+            "title": "Final",
+            "meta": { "version": 1, "author": "Ann" },
+            "tags": ["a", "x", "y", "z", "d"],
+        });
+
+        let changes = ChangeSet::<Document>::from_json_diff(
+            &KeyPathFrom::dangerously_construct_from_path(vec![]),
+            &before,
+            &after,
+        );
+
+        assert_eq!(
+            changes,
+            ChangeSet::from(vec![
+                ChangeOf::Splice {
+                    key_path: keypath![Document: tags].into(),
+                    value: vec![json!("x"), json!("y"), json!("z")],
+                    start: 1,
+                    replace: 2,
+                },
+                ChangeOf::Update {
+                    key_path: keypath![Document: title].into(),
+                    value: json!("Final"),
+                },
+            ])
+        );
+
+        let mut document: Document = serde_json::from_value(before).unwrap();
+        for change in &changes {
+            document.apply_change(change);
+        }
+
+        assert_eq!(document, serde_json::from_value(after).unwrap());
+    }
+
+    #[test]
+    fn validates_the_structure_of_a_change_set() {
+        let changes: ChangeSet<Document> = ChangeSet::from(vec![
+            Change::update(keypath![Document: meta.version], 2),
+            Change::update(keypath![Document: tags[3]], "d".to_string()),
+            Change::splice(keypath![Document: tags], strings(&["e"]), 0, 0),
+        ]);
+
+        assert!(changes.validate_structure().is_ok());
+    }
+
+    #[test]
+    fn reports_the_first_change_with_an_invalid_path() {
+        let changes: ChangeSet<Document> = ChangeSet::from(vec![
+            Change::update(keypath![Document: title], "Final".to_string()),
+            ChangeOf::Update {
+                key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                    KeyPathElement::Field { key: "meta".into() },
+                    KeyPathElement::Field {
+                        key: "editor".into(),
+                    },
+                ]),
+                value: json!("Bob"),
+            },
+            ChangeOf::Update {
+                key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                    KeyPathElement::Field {
+                        key: "title".into(),
+                    },
+                    KeyPathElement::Index { key: 0 },
+                ]),
+                value: json!("F"),
+            },
+        ]);
+
+        let Err((index, error)) = changes.validate_structure() else {
+            panic!("Expected an invalid change set");
+        };
+
+        assert_eq!(index, 1);
+        assert!(matches!(
+            error,
+            KeyPathError::UnknownField { field, .. } if field == "editor"
+        ));
+    }
+
+    #[test]
+    fn disjoint_change_sets_dont_conflict() {
+        let ours: ChangeSet<Document> = ChangeSet::from(vec![
+            Change::update(keypath![Document: title], "Ours".to_string()),
+            Change::update(keypath![Document: meta.version], 2),
+        ]);
+        let theirs: ChangeSet<Document> = ChangeSet::from(vec![
+            Change::update(keypath![Document: meta.author], "Bob".to_string()),
+            Change::splice(keypath![Document: tags], strings(&["x"]), 0, 0),
+        ]);
+
+        assert_eq!(ours.conflicts_with(&theirs), vec![]);
+    }
+
+    #[test]
+    fn changes_to_the_same_path_conflict() {
+        let ours: ChangeSet<Document> = ChangeSet::from(vec![
+            Change::update(keypath![Document: meta.version], 2),
+            Change::update(keypath![Document: title], "Ours".to_string()),
+        ]);
+        let theirs: ChangeSet<Document> = ChangeSet::from(vec![Change::update(
+            keypath![Document: title],
+            "Theirs".to_string(),
+        )]);
+
+        assert_eq!(ours.conflicts_with(&theirs), vec![(1, 0)]);
+    }
+
+    #[test]
+    fn changes_to_ancestors_and_descendants_conflict() {
+        let ours: ChangeSet<Document> = ChangeSet::from(vec![
+            Change::update(keypath![Document: meta.version], 2),
+            Change::update(keypath![Document: tags[1]], "b".to_string()),
+        ]);
+        let theirs: ChangeSet<Document> = ChangeSet::from(vec![
+            Change::splice(keypath![Document: tags], strings(&["x"]), 0, 0),
+            Change::update(
+                keypath![Document: meta],
+                Meta {
+                    version: 3,
+                    author: "Bob".to_string(),
+                },
+            ),
+        ]);
+
+        assert_eq!(ours.conflicts_with(&theirs), vec![(0, 1), (1, 0)]);
+        assert_eq!(theirs.conflicts_with(&ours), vec![(0, 1), (1, 0)]);
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Lists {
+        first: Vec<String>,
+        second: Vec<String>,
+    }
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn merges_adjacent_splices() {
+        let mut changes: ChangeSet<Lists> = ChangeSet::from(vec![
+            Change::splice(keypath![Lists: first], strings(&["x"]), 1, 1),
+            Change::splice(keypath![Lists: first], strings(&["y", "z"]), 2, 0),
+            Change::splice(keypath![Lists: first], vec![], 4, 1),
+        ]);
+
+        let mut separately = Lists {
+            first: strings(&["a", "b", "c", "d", "e"]),
+            second: vec![],
+        };
+        let mut merged = separately.clone();
+
+        for change in &changes {
+            separately.apply_change(change);
+        }
+
+        changes.merge_adjacent_splices();
+
+        assert_eq!(
+            changes,
+            ChangeSet::from(vec![Change::splice(
+                keypath![Lists: first],
+                strings(&["x", "y", "z"]),
+                1,
+                2,
+            )])
+        );
+
+        for change in &changes {
+            merged.apply_change(change);
+        }
+
+        assert_eq!(merged, separately);
+        assert_eq!(merged.first, strings(&["a", "x", "y", "z", "d", "e"]));
+    }
+
+    #[test]
+    fn does_not_merge_non_adjacent_splices() {
+        let unmergeable = vec![
+            // Gap between the ranges
+            Change::splice(keypath![Lists: first], strings(&["x"]), 0, 0),
+            Change::splice(keypath![Lists: first], strings(&["y"]), 2, 0),
+            // Overlapping ranges
+            Change::splice(keypath![Lists: first], strings(&["z"]), 2, 0),
+            // Different list
+            Change::splice(keypath![Lists: second], strings(&["w"]), 3, 0),
+        ];
+
+        let mut changes: ChangeSet<Lists> = ChangeSet::from(unmergeable.clone());
+        changes.merge_adjacent_splices();
+
+        assert_eq!(changes, ChangeSet::from(unmergeable));
+    }
+
+    #[test]
+    fn keeps_the_last_of_repeated_updates() {
+        let mut changes: ChangeSet<Document> = ChangeSet::from(vec![
+            Change::update(keypath![Document: title], "First".to_string()),
+            Change::update(keypath![Document: title], "Second".to_string()),
+            Change::update(keypath![Document: title], "Third".to_string()),
+        ]);
+
+        changes.dedup_by_path();
+
+        assert_eq!(
+            changes,
+            ChangeSet::from(vec![Change::update(
+                keypath![Document: title],
+                "Third".to_string()
+            )])
+        );
+    }
+
+    #[test]
+    fn keeps_updates_of_items_shifted_by_a_splice() {
+        let input = vec![
+            Change::update(keypath![Document: tags[0]], "x".to_string()),
+            Change::splice(keypath![Document: tags], strings(&["a"]), 0, 0),
+            Change::update(keypath![Document: tags[0]], "y".to_string()),
+        ];
+        let mut changes: ChangeSet<Document> = ChangeSet::from(input.clone());
+
+        changes.dedup_by_path();
+
+        assert_eq!(changes, ChangeSet::from(input));
+    }
+
+    #[test]
+    fn dedups_interleaved_updates_and_keeps_splices() {
+        let mut changes: ChangeSet<Document> = ChangeSet::from(vec![
+            Change::update(keypath![Document: title], "First".to_string()),
+            Change::update(keypath![Document: meta.version], 2),
+            Change::splice(keypath![Document: tags], strings(&["a"]), 0, 0),
+            Change::update(keypath![Document: title], "Second".to_string()),
+            Change::splice(keypath![Document: tags], strings(&["a"]), 0, 0),
+            Change::update(keypath![Document: meta.author], "Ann".to_string()),
+            Change::update(keypath![Document: meta.version], 3),
+        ]);
+
+        changes.dedup_by_path();
+
+        assert_eq!(
+            changes,
+            ChangeSet::from(vec![
+                Change::splice(keypath![Document: tags], strings(&["a"]), 0, 0),
+                Change::update(keypath![Document: title], "Second".to_string()),
+                Change::splice(keypath![Document: tags], strings(&["a"]), 0, 0),
+                Change::update(keypath![Document: meta.author], "Ann".to_string()),
+                Change::update(keypath![Document: meta.version], 3),
+            ])
+        );
+    }
+
+    #[test]
+    fn coalesces_changes_overwritten_by_later_ones() {
+        let input = vec![
+            Change::update(keypath![Document: meta.version], 2),
+            Change::update(keypath![Document: title], "First".to_string()),
+            Change::splice(keypath![Document: tags], strings(&["x"]), 0, 0),
+            Change::update(
+                keypath![Document: meta],
+                Meta {
+                    version: 3,
+                    author: "Bob".to_string(),
+                },
+            ),
+            Change::update(keypath![Document: title], "Second".to_string()),
+            Change::splice(keypath![Document: tags], strings(&["y"]), 1, 0),
+        ];
+        let mut changes: ChangeSet<Document> = ChangeSet::from(input.clone());
+
+        changes.coalesce();
+
+        assert_eq!(
+            changes,
+            ChangeSet::from(vec![
+                Change::splice(keypath![Document: tags], strings(&["x"]), 0, 0),
+                Change::update(
+                    keypath![Document: meta],
+                    Meta {
+                        version: 3,
+                        author: "Bob".to_string(),
+                    },
+                ),
+                Change::update(keypath![Document: title], "Second".to_string()),
+                Change::splice(keypath![Document: tags], strings(&["y"]), 1, 0),
+            ])
+        );
+        assert_same_result(&ChangeSet::from(input), &changes);
+    }
+
+    #[test]
+    fn keeps_item_changes_before_a_splice_of_their_list() {
+        let input = vec![
+            Change::update(keypath![Document: tags[0]], "x".to_string()),
+            Change::splice(keypath![Document: tags], strings(&["new"]), 0, 0),
+            Change::update(keypath![Document: tags[0]], "y".to_string()),
+            Change::splice(keypath![Document: tags], strings(&["z"]), 0, 1),
+            Change::update(keypath![Document: tags], strings(&["replaced"])),
+        ];
+        let mut changes: ChangeSet<Document> = ChangeSet::from(input[..3].to_vec());
+
+        changes.coalesce();
+
+        assert_eq!(changes, ChangeSet::from(input[..3].to_vec()));
+        assert_same_result(&ChangeSet::from(input[..3].to_vec()), &changes);
+
+        // Replacing the whole list overwrites the splices and changes to its items
+        let mut changes: ChangeSet<Document> = ChangeSet::from(input.clone());
+
+        changes.coalesce();
+
+        assert_eq!(changes, ChangeSet::from(input[4..].to_vec()));
+        assert_same_result(&ChangeSet::from(input), &changes);
+    }
+
+    fn assert_same_result(input: &ChangeSet<Document>, coalesced: &ChangeSet<Document>) {
+        let before = Document {
+            title: "Draft".to_string(),
+            meta: Meta {
+                version: 1,
+                author: "Ann".to_string(),
+            },
+            tags: strings(&["a", "b"]),
+        };
+
+        let mut sequential = before.clone();
+        sequential.apply_change_set(input).unwrap();
+        let mut applied = before;
+        applied.apply_change_set(coalesced).unwrap();
+
+        assert_eq!(applied, sequential);
+    }
+
+    #[test]
+    fn round_trips_through_a_patch_batch() {
+        let changes: ChangeSet<Document> = ChangeSet::from(vec![
+            Change::update(keypath![Document: meta.version], 2),
+            Change::splice(keypath![Document: tags], strings(&["c"]), 1, 1),
+            Change::update(keypath![Document: title], "Final".to_string()),
+        ]);
+        let before = json!({
+            "title": "Draft",
+            "meta": { "version": 1, "author": "Ann" },
+            "tags": ["a", "b"],
+        });
+
+        let sent = serde_json::to_string(&PatchBatch::from(&changes)).unwrap();
+        let batch: PatchBatch = serde_json::from_str(&sent).unwrap();
+
+        let mut document: Document = serde_json::from_value(before.clone()).unwrap();
+        document.apply_patch_batch(&batch).unwrap();
+
+        let mut expected: Document = serde_json::from_value(before).unwrap();
+        expected.apply_change_set(&changes).unwrap();
+
+        assert_eq!(document, expected);
+        assert_eq!(document.tags, strings(&["a", "c"]));
+    }
+
+    #[test]
+    fn diffs_relative_to_a_base_path() {
+        let base: KeyPathFrom<Document> = keypath![Document: meta].into();
+
+        let changes = ChangeSet::from_json_diff(
+            &base,
+            &json!({ "version": 1, "author": "Ann" }),
+            &json!({ "version": 1, "author": "Bob" }),
+        );
+
+        assert_eq!(
+            changes,
+            ChangeSet::from(vec![ChangeOf::Update {
+                key_path: keypath![Document: meta.author].into(),
+                value: json!("Bob"),
+            }])
+        );
+    }
+
+    #[test]
+    fn transforms_values_in_place() {
+        let mut changes = ChangeSet::from(vec![
+            Change::update(keypath![Document: title], "draft".to_string()),
+            Change::splice(keypath![Document: tags], strings(&["a", "b"]), 0, 0),
+            Change::update(keypath![Document: meta.version], 1),
+        ]);
+
+        changes.map_value_each(|value| {
+            if let serde_json::Value::String(text) = value {
+                *text = text.to_uppercase();
+            }
+        });
+
+        for change in &mut changes {
+            if let ChangeOf::Update { value, .. } = change {
+                if value.is_number() {
+                    *value = json!(2);
+                }
+            }
+        }
+
+        let mut document = Document {
+            title: String::new(),
+            meta: Meta {
+                version: 0,
+                author: "Ann".to_string(),
+            },
+            tags: vec![],
+        };
+        document.apply_change_set(&changes).unwrap();
+
+        assert_eq!(document.title, "DRAFT");
+        assert_eq!(document.tags, strings(&["A", "B"]));
+        assert_eq!(document.meta.version, 2);
+    }
+
+    #[test]
+    fn retains_only_matching_changes() {
+        let mut changes: ChangeSet<Document> = ChangeSet::from(vec![
+            Change::update(keypath![Document: title], "Draft".to_string()),
+            Change::splice(keypath![Document: tags], strings(&["a"]), 0, 0),
+            Change::update(keypath![Document: meta.author], "Bob".to_string()),
+        ]);
+
+        changes.retain(|change| change.kind() == ChangeKind::Update);
+
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| change.key_path().to_string())
+                .collect::<Vec<_>>(),
+            vec![".title", ".meta.author"]
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use std::borrow::Cow;
+
+use serde_json::Value;
+
+use crate::{ChangeOf, KeyPathElement, KeyPathFrom, KeyPathMutable};
+
+/// Structurally diff two JSON values, pushing the changes which turn `before` into `after`
+/// onto `changes`, with keypaths starting at `path`.
+///
+/// JSON doesn't tell us whether an object is a struct, a map or an enum variant, so the
+/// structure of `Root` decides which keypaths to follow. Object members are addressed with `StringKey`
+/// elements (which is what maps expect) where valid, otherwise with `Field` elements (which is
+/// what derived structs expect). Members of maps are inserted and deleted individually,
+/// other objects with different sets of keys, or which can't be addressed either way (e.g. enum
+/// variants), are replaced whole.
+///
+/// Arrays are trimmed to the differing range in the middle. If that range has the same length
+/// on both sides, it's diffed element by element, otherwise it's replaced with a single splice,
+/// or the whole array is replaced if `Root` can't splice it (e.g. a boxed slice).
+/// Items are addressed with `Index` elements where valid, otherwise by position with `Field`
+/// elements (which is what tuples expect).
+pub(crate) fn diff_json<Root: KeyPathMutable>(
+    path: &mut Vec<KeyPathElement>,
+    before: &Value,
+    after: &Value,
+    changes: &mut Vec<ChangeOf<Root>>,
+) {
+    if before == after {
+        return;
+    }
+
+    let diffed = match (before, after) {
+        (Value::Object(before_map), Value::Object(after_map)) => {
+            diff_objects(path, before_map, after_map, changes)
+        }
+        (Value::Array(before_items), Value::Array(after_items)) => {
+            diff_arrays(path, before_items, after_items, changes)
+        }
+        _ => false,
+    };
+    if diffed {
+        return;
+    }
+
+    changes.push(ChangeOf::Update {
+        key_path: KeyPathFrom::dangerously_construct_from_path(path.clone()),
+        value: after.clone(),
+    });
+}
+
+/// The element addressing `key` of an object at `path`, if any
+fn member_element<Root: KeyPathMutable>(
+    path: &mut Vec<KeyPathElement>,
+    key: &str,
+) -> Option<KeyPathElement> {
+    let candidates = [
+        KeyPathElement::StringKey { key: key.into() },
+        KeyPathElement::Field {
+            key: Cow::Owned(key.to_string()),
+        },
+    ];
+
+    candidates.into_iter().find(|element| {
+        path.push(element.clone());
+        let valid = Root::validate_path(path).is_ok();
+        path.pop();
+
+        valid
+    })
+}
+
+/// Diff the members of two objects, returning false if they have to be replaced whole instead
+fn diff_objects<Root: KeyPathMutable>(
+    path: &mut Vec<KeyPathElement>,
+    before: &serde_json::Map<String, Value>,
+    after: &serde_json::Map<String, Value>,
+    changes: &mut Vec<ChangeOf<Root>>,
+) -> bool {
+    let same_keys = before.len() == after.len() && before.keys().all(|k| after.contains_key(k));
+
+    let mut elements = vec![];
+    for key in before
+        .keys()
+        .chain(after.keys().filter(|k| !before.contains_key(*k)))
+    {
+        match member_element::<Root>(path, key) {
+            // Only map entries can be inserted and deleted
+            Some(element @ KeyPathElement::StringKey { .. }) => elements.push((key, element)),
+            Some(element) if same_keys => elements.push((key, element)),
+            _ => return false,
+        }
+    }
+
+    for (key, element) in elements {
+        path.push(element);
+        match (before.get(key), after.get(key)) {
+            (Some(before_value), Some(after_value)) => {
+                diff_json(path, before_value, after_value, changes)
+            }
+            (Some(_), None) => changes.push(ChangeOf::Delete {
+                key_path: KeyPathFrom::dangerously_construct_from_path(path.clone()),
+            }),
+            (None, Some(after_value)) => changes.push(ChangeOf::Update {
+                key_path: KeyPathFrom::dangerously_construct_from_path(path.clone()),
+                value: after_value.clone(),
+            }),
+            (None, None) => unreachable!("key of neither object"),
+        }
+        path.pop();
+    }
+
+    true
+}
+
+/// Diff the items of two arrays, returning false if they have to be replaced whole instead
+fn diff_arrays<Root: KeyPathMutable>(
+    path: &mut Vec<KeyPathElement>,
+    before: &[Value],
+    after: &[Value],
+    changes: &mut Vec<ChangeOf<Root>>,
+) -> bool {
+    let prefix = before.iter().zip(after).take_while(|(b, a)| b == a).count();
+    let suffix = before[prefix..]
+        .iter()
+        .rev()
+        .zip(after[prefix..].iter().rev())
+        .take_while(|(b, a)| b == a)
+        .count();
+
+    let before_middle = &before[prefix..before.len() - suffix];
+    let after_middle = &after[prefix..after.len() - suffix];
+
+    if before_middle.len() != after_middle.len() {
+        if Root::validate_splice(path).is_err() {
+            return false;
+        }
+
+        changes.push(ChangeOf::Splice {
+            key_path: KeyPathFrom::dangerously_construct_from_path(path.clone()),
+            value: after_middle.to_vec(),
+            start: prefix,
+            replace: before_middle.len(),
+        });
+
+        return true;
+    }
+
+    let item_element: fn(usize) -> KeyPathElement = |index| KeyPathElement::Index { key: index };
+    let position_element: fn(usize) -> KeyPathElement = |index| KeyPathElement::Field {
+        key: Cow::Owned(index.to_string()),
+    };
+
+    path.push(item_element(prefix));
+    let by_index = Root::validate_path(path).is_ok();
+    path.pop();
+
+    let element = if by_index {
+        item_element
+    } else {
+        path.push(position_element(prefix));
+        let by_position = Root::validate_path(path).is_ok();
+        path.pop();
+
+        if !by_position {
+            return false;
+        }
+
+        position_element
+    };
+
+    for (ix, (b, a)) in before_middle.iter().zip(after_middle).enumerate() {
+        path.push(element(prefix + ix));
+        diff_json(path, b, a, changes);
+        path.pop();
+    }
+
+    true
+}
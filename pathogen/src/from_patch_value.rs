@@ -0,0 +1,202 @@
+//! Helpers for the `FromPatchValue` impls generated by `derive(KeyPathMutable)`, and for the impls
+//! of the containers, which take patch values apart the way serde would deserialize them, so
+//! every part is converted with its own `FromPatchValue`.
+//!
+//! Errors are built like serde's, so they read the same as deserializing the whole value.
+
+use serde::de::{self, DeserializeOwned, Error as _, Unexpected};
+use serde_json::{Error, Map, Value};
+
+use crate::FromPatchValue;
+
+/// The part of an error message describing `value`, like serde_json's
+pub fn unexpected(value: &Value) -> Unexpected<'_> {
+    match value {
+        Value::Null => Unexpected::Unit,
+        Value::Bool(bool) => Unexpected::Bool(*bool),
+        Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+            (Some(unsigned), _) => Unexpected::Unsigned(unsigned),
+            (None, Some(signed)) => Unexpected::Signed(signed),
+            (None, None) => Unexpected::Float(number.as_f64().unwrap_or(f64::NAN)),
+        },
+        Value::String(string) => Unexpected::Str(string),
+        Value::Array(_) => Unexpected::Seq,
+        Value::Object(_) => Unexpected::Map,
+    }
+}
+
+/// The members of a JSON object, e.g. the fields of a struct
+pub fn object(value: Value, expected: &'static str) -> Result<Map<String, Value>, Error> {
+    match value {
+        Value::Object(members) => Ok(members),
+        value => Err(Error::invalid_type(unexpected(&value), &expected)),
+    }
+}
+
+/// The items of a JSON array of any length
+pub fn items(value: Value, expected: &'static str) -> Result<Vec<Value>, Error> {
+    match value {
+        Value::Array(items) => Ok(items),
+        value => Err(Error::invalid_type(unexpected(&value), &expected)),
+    }
+}
+
+/// The items of a JSON array of exactly `N` items, e.g. the fields of a tuple struct
+pub fn array<const N: usize>(value: Value, expected: &'static str) -> Result<[Value; N], Error> {
+    let items = items(value, expected)?;
+    let len = items.len();
+
+    items
+        .try_into()
+        .map_err(|_| Error::invalid_length(len, &expected))
+}
+
+/// A unit, i.e. `null`
+pub fn unit(value: Value, expected: &'static str) -> Result<(), Error> {
+    match value {
+        Value::Null => Ok(()),
+        value => Err(Error::invalid_type(unexpected(&value), &expected)),
+    }
+}
+
+/// Remove the value of a field from the members of an object, by its name or any of its aliases
+pub fn take(members: &mut Map<String, Value>, name: &str, aliases: &[&str]) -> Option<Value> {
+    members
+        .remove(name)
+        .or_else(|| aliases.iter().find_map(|alias| members.remove(*alias)))
+}
+
+/// Convert the value of a field, which may be missing
+pub fn field<T: FromPatchValue>(value: Option<Value>, name: &'static str) -> Result<T, Error> {
+    match value {
+        Some(value) => T::from_patch_value(value),
+        None => T::from_missing_field(name),
+    }
+}
+
+/// Deserialize the value of a field with serde, for fields the derive doesn't require to be
+/// `KeyPathMutable`
+pub fn deserialize_field<T: DeserializeOwned>(
+    value: Option<Value>,
+    name: &'static str,
+) -> Result<T, Error> {
+    match value {
+        Some(value) => serde_json::from_value(value),
+        None => T::deserialize(MissingField(name)),
+    }
+}
+
+/// Reject any members left after taking the known fields
+pub fn deny_unknown_fields(
+    members: Map<String, Value>,
+    expected: &'static [&'static str],
+) -> Result<(), Error> {
+    match members.keys().next() {
+        Some(field) => Err(Error::unknown_field(field, expected)),
+        None => Ok(()),
+    }
+}
+
+/// The variant and content of an externally tagged enum, i.e. `"Variant"` or
+/// `{ "Variant": content }`
+pub fn externally_tagged(
+    value: Value,
+    expected: &'static str,
+) -> Result<(String, Option<Value>), Error> {
+    match value {
+        Value::String(variant) => Ok((variant, None)),
+        Value::Object(members) if members.len() == 1 => {
+            let (variant, content) = members.into_iter().next().expect("one member");
+
+            Ok((variant, Some(content)))
+        }
+        Value::Object(_) => Err(Error::invalid_value(
+            Unexpected::Map,
+            &"a map with a single key",
+        )),
+        value => Err(Error::invalid_type(unexpected(&value), &expected)),
+    }
+}
+
+/// The variant of an internally tagged enum, and the rest of the members as its content
+pub fn internally_tagged(
+    value: Value,
+    tag: &'static str,
+    expected: &'static str,
+) -> Result<(String, Map<String, Value>), Error> {
+    let mut members = object(value, expected)?;
+    let variant = variant_tag(members.remove(tag), tag)?;
+
+    Ok((variant, members))
+}
+
+/// The variant and content of an adjacently tagged enum, i.e. `{ tag: "Variant", content: content }`
+pub fn adjacently_tagged(
+    value: Value,
+    tag: &'static str,
+    content: &'static str,
+    expected: &'static str,
+) -> Result<(String, Option<Value>), Error> {
+    let mut members = object(value, expected)?;
+    let variant = variant_tag(members.remove(tag), tag)?;
+
+    Ok((variant, members.remove(content)))
+}
+
+fn variant_tag(tag: Option<Value>, name: &'static str) -> Result<String, Error> {
+    match tag {
+        Some(Value::String(variant)) => Ok(variant),
+        Some(value) => Err(Error::invalid_type(
+            unexpected(&value),
+            &"variant identifier",
+        )),
+        None => Err(Error::missing_field(name)),
+    }
+}
+
+/// The content of a unit variant, which is either missing or `null`
+pub fn unit_variant(content: Option<Value>) -> Result<(), Error> {
+    match content {
+        None => Ok(()),
+        Some(value) => unit(value, "unit variant"),
+    }
+}
+
+/// The content of a newtype, tuple or struct variant, which can't be missing
+pub fn variant_content(content: Option<Value>, expected: &'static str) -> Result<Value, Error> {
+    content.ok_or_else(|| Error::invalid_type(Unexpected::UnitVariant, &expected))
+}
+
+/// A variant which isn't one of `expected`
+pub fn unknown_variant(variant: &str, expected: &'static [&'static str]) -> Error {
+    Error::unknown_variant(variant, expected)
+}
+
+/// A value none of the variants of an untagged enum could be converted from
+pub fn no_untagged_variant_matched(enum_name: &'static str) -> Error {
+    Error::custom(format!(
+        "data did not match any variant of untagged enum {enum_name}"
+    ))
+}
+
+/// Deserializes a missing field like serde does: as `None` for an `Option`, and as a
+/// `missing_field` error otherwise
+struct MissingField(&'static str);
+
+impl<'de> de::Deserializer<'de> for MissingField {
+    type Error = Error;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Error> {
+        Err(Error::missing_field(self.0))
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_none()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
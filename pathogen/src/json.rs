@@ -0,0 +1,605 @@
+use serde::ser::{
+    Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant, Serializer,
+};
+use serde_json::Value;
+
+use crate::{KeyPathElement, VariantTagType};
+
+/// Find the JSON value a keypath points at inside the serialized form of its root.
+///
+/// Elements are followed the way serde lays out the corresponding Rust values. A `Field` of a
+/// tuple struct or tuple variant is an array index, except `"0"` of a newtype, which is the
+/// value itself. Consequently a path through a newtype wrapping a list is ambiguous and treated
+/// as indexing into the list.
+#[cfg(any(test, feature = "testing"))]
+pub(crate) fn value_at<'a>(value: &'a Value, path: &[KeyPathElement]) -> Option<&'a Value> {
+    let Some((element, rest)) = path.split_first() else {
+        return Some(value);
+    };
+
+    let next = match element {
+        KeyPathElement::Field { key } => match value {
+            Value::Object(map) => map.get::<str>(key),
+            Value::Array(items) => key.parse::<usize>().ok().and_then(|ix| items.get(ix)),
+            _ if *key == "0" => Some(value),
+            _ => None,
+        },
+        KeyPathElement::Variant { key, tag } => variant_payload(value, key, tag),
+        KeyPathElement::Index { key } => value.as_array().and_then(|items| items.get(*key)),
+        KeyPathElement::StringKey { key } => value.as_object().and_then(|map| map.get::<str>(key)),
+    }?;
+
+    value_at(next, rest)
+}
+
+#[cfg(any(test, feature = "testing"))]
+fn variant_payload<'a>(value: &'a Value, variant: &str, tag: &VariantTagType) -> Option<&'a Value> {
+    match tag {
+        VariantTagType::External => {
+            let map = value.as_object()?;
+
+            if map.len() == 1 {
+                map.get(variant)
+            } else {
+                None
+            }
+        }
+        // The tag field name isn't part of the keypath, so the variant can't be checked and
+        // its fields sit next to the tag
+        VariantTagType::Internal => value.is_object().then_some(value),
+        VariantTagType::Adjacent => {
+            let map = value.as_object()?;
+            let (tag_key, _) = map.iter().find(|(_, v)| v.as_str() == Some(variant))?;
+
+            map.iter()
+                .find(|(key, _)| *key != tag_key)
+                .map(|(_, content)| content)
+        }
+        VariantTagType::Untagged => Some(value),
+    }
+}
+
+/// Build the JSON Pointer (RFC 6901) to the location a keypath points at inside the serialized
+/// form of its root.
+///
+/// Internally tagged and untagged variants don't add a level to the JSON, so they add no
+/// segment. Returns `None` for a path through an adjacently tagged variant, since the key of
+/// its content isn't part of the keypath. Unlike `value_at`, this can't tell a newtype from a
+/// tuple struct, so its `"0"` field is always a segment.
+pub(crate) fn json_pointer(path: &[KeyPathElement]) -> Option<String> {
+    let mut pointer = String::new();
+
+    for element in path {
+        match element {
+            KeyPathElement::Variant { tag, .. } => match tag {
+                VariantTagType::External => {}
+                VariantTagType::Internal | VariantTagType::Untagged => continue,
+                VariantTagType::Adjacent => return None,
+            },
+            KeyPathElement::Field { .. }
+            | KeyPathElement::Index { .. }
+            | KeyPathElement::StringKey { .. } => {}
+        }
+
+        push_pointer_segment(&mut pointer, &element.wire_key());
+    }
+
+    Some(pointer)
+}
+
+/// Append `key` to a JSON Pointer as a segment, escaping `~` and `/`
+pub(crate) fn push_pointer_segment(pointer: &mut String, key: &str) {
+    pointer.push('/');
+    pointer.push_str(&key.replace('~', "~0").replace('/', "~1"));
+}
+
+/// Split a JSON Pointer into its unescaped segments, or `None` if it's malformed, i.e. not
+/// empty and not starting with `/`, or containing a `~` which isn't followed by `0` or `1`.
+pub(crate) fn pointer_segments(pointer: &str) -> Option<Vec<String>> {
+    if pointer.is_empty() {
+        return Some(vec![]);
+    }
+
+    pointer
+        .strip_prefix('/')?
+        .split('/')
+        .map(|segment| {
+            let mut unescaped = String::with_capacity(segment.len());
+            let mut chars = segment.chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    '~' => match chars.next() {
+                        Some('0') => unescaped.push('~'),
+                        Some('1') => unescaped.push('/'),
+                        _ => return None,
+                    },
+                    c => unescaped.push(c),
+                }
+            }
+
+            Some(unescaped)
+        })
+        .collect()
+}
+
+/// Serialize only the part of `value` a keypath points at, without serializing the rest of it,
+/// or `None` if it isn't part of the serialized form.
+///
+/// This follows elements the way `value_at` does, but on the serde data model, so newtypes and
+/// newtype variants are told apart from tuples and their `"0"` field is always the content.
+pub(crate) fn serialize_at<T: Serialize + ?Sized>(
+    value: &T,
+    path: &[KeyPathElement],
+) -> Result<Option<Value>, serde_json::Error> {
+    // Internally tagged and untagged variants don't add a level to the serialized form
+    let skipped = path
+        .iter()
+        .take_while(|element| {
+            matches!(
+                element,
+                KeyPathElement::Variant {
+                    tag: VariantTagType::Internal | VariantTagType::Untagged,
+                    ..
+                }
+            )
+        })
+        .count();
+    let path = &path[skipped..];
+
+    if path.is_empty() {
+        return serde_json::to_value(value).map(Some);
+    }
+
+    value.serialize(Extractor { path })
+}
+
+/// Serializer following a non-empty keypath, which only serializes the value at its end
+struct Extractor<'a> {
+    path: &'a [KeyPathElement],
+}
+
+impl<'a> Extractor<'a> {
+    fn member(&self) -> Option<&'a str> {
+        match &self.path[0] {
+            KeyPathElement::Field { key } => Some(key),
+            KeyPathElement::StringKey { key } => Some(key),
+            KeyPathElement::Variant { .. } | KeyPathElement::Index { .. } => None,
+        }
+    }
+
+    fn item(&self) -> Option<usize> {
+        match &self.path[0] {
+            KeyPathElement::Index { key } => Some(*key),
+            KeyPathElement::Field { key } => key.parse().ok(),
+            KeyPathElement::Variant { .. } | KeyPathElement::StringKey { .. } => None,
+        }
+    }
+
+    /// The rest of the path after the element selecting `variant`, if it does
+    fn variant(&self, variant: &str) -> Option<&'a [KeyPathElement]> {
+        match &self.path[0] {
+            KeyPathElement::Variant { key, .. } if key == variant => Some(&self.path[1..]),
+            _ => None,
+        }
+    }
+
+    fn is_newtype_content(element: &KeyPathElement) -> bool {
+        matches!(element, KeyPathElement::Field { key } if key == "0")
+    }
+
+    /// Like `value_at`, a `"0"` field of a value which isn't a list or object is the value itself
+    fn scalar<T: Serialize + ?Sized>(self, value: &T) -> Result<Option<Value>, serde_json::Error> {
+        if Self::is_newtype_content(&self.path[0]) {
+            return serialize_at(value, &self.path[1..]);
+        }
+
+        Ok(None)
+    }
+
+    fn items(self, rest: &'a [KeyPathElement]) -> Compound<'a> {
+        match self.item() {
+            Some(index) => Compound::Item {
+                index,
+                next: 0,
+                rest,
+                found: None,
+            },
+            None => Compound::Nothing,
+        }
+    }
+
+    fn members(self, rest: &'a [KeyPathElement]) -> Compound<'a> {
+        match self.member() {
+            Some(key) => Compound::Member {
+                key,
+                rest,
+                matched: false,
+                found: None,
+            },
+            None => Compound::Nothing,
+        }
+    }
+}
+
+/// The state of an `Extractor` inside a list, map or struct
+enum Compound<'a> {
+    /// Collecting the whole content of a tuple variant
+    Items(Vec<Value>),
+    /// Collecting the whole content of a struct variant
+    Fields(serde_json::Map<String, Value>),
+    /// Looking for the item at `index`, then following `rest` into it
+    Item {
+        index: usize,
+        next: usize,
+        rest: &'a [KeyPathElement],
+        found: Option<Value>,
+    },
+    /// Looking for the member `key` of a map or struct, then following `rest` into it
+    Member {
+        key: &'a str,
+        rest: &'a [KeyPathElement],
+        matched: bool,
+        found: Option<Value>,
+    },
+    /// Checking the tag of an adjacently tagged enum, then following `rest` into the content
+    Adjacent {
+        variant: &'a str,
+        rest: &'a [KeyPathElement],
+        tagged: Option<bool>,
+        found: Option<Value>,
+    },
+    /// The path doesn't lead anywhere inside this value
+    Nothing,
+}
+
+impl Compound<'_> {
+    fn item<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), serde_json::Error> {
+        match self {
+            Compound::Items(items) => items.push(serde_json::to_value(value)?),
+            Compound::Item {
+                index,
+                next,
+                rest,
+                found,
+            } => {
+                if next == index {
+                    *found = serialize_at(value, rest)?;
+                }
+                *next += 1;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn field<T: Serialize + ?Sized>(
+        &mut self,
+        name: &str,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        match self {
+            Compound::Fields(fields) => {
+                fields.insert(name.to_string(), serde_json::to_value(value)?);
+            }
+            Compound::Member {
+                key, rest, found, ..
+            } if name == *key => *found = serialize_at(value, rest)?,
+            // The tag comes first, followed by the content
+            Compound::Adjacent {
+                variant,
+                rest,
+                tagged,
+                found,
+            } => match tagged {
+                None => *tagged = Some(serde_json::to_value(value)? == *variant),
+                Some(true) if found.is_none() => *found = serialize_at(value, rest)?,
+                Some(_) => {}
+            },
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Option<Value>, serde_json::Error> {
+        Ok(match self {
+            Compound::Items(items) => Some(Value::Array(items)),
+            Compound::Fields(fields) => Some(Value::Object(fields)),
+            Compound::Item { found, .. }
+            | Compound::Member { found, .. }
+            | Compound::Adjacent { found, .. } => found,
+            Compound::Nothing => None,
+        })
+    }
+}
+
+macro_rules! extract_scalars {
+    ($($method:ident($t:ty))*) => {
+        $(
+            fn $method(self, value: $t) -> Result<Self::Ok, Self::Error> {
+                self.scalar(&value)
+            }
+        )*
+    };
+}
+
+impl<'a> Serializer for Extractor<'a> {
+    type Ok = Option<Value>;
+    type Error = serde_json::Error;
+
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    extract_scalars! {
+        serialize_bool(bool)
+        serialize_i8(i8) serialize_i16(i16) serialize_i32(i32) serialize_i64(i64)
+        serialize_i128(i128)
+        serialize_u8(u8) serialize_u16(u16) serialize_u32(u32) serialize_u64(u64)
+        serialize_u128(u128)
+        serialize_f32(f32) serialize_f64(f64)
+        serialize_char(char) serialize_str(&str) serialize_bytes(&[u8])
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    // Options are transparent in keypaths
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.scalar(&())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.scalar(&())
+    }
+
+    // A unit variant has no content
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        if Self::is_newtype_content(&self.path[0]) {
+            return serialize_at(value, &self.path[1..]);
+        }
+
+        Ok(None)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        match self.variant(variant) {
+            Some([]) => serialize_at(value, &[]),
+            Some([content, rest @ ..]) if Self::is_newtype_content(content) => {
+                serialize_at(value, rest)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        let rest = &self.path[1..];
+        Ok(self.items(rest))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        let rest = &self.path[1..];
+        Ok(self.items(rest))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        let rest = &self.path[1..];
+        Ok(self.items(rest))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(match self.variant(variant) {
+            Some([]) => Compound::Items(Vec::with_capacity(len)),
+            Some(path) => Extractor { path }.items(&path[1..]),
+            None => Compound::Nothing,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        let rest = &self.path[1..];
+        Ok(self.members(rest))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        // Adjacently tagged enums serialize as a struct of the tag and the content
+        if let KeyPathElement::Variant {
+            key,
+            tag: VariantTagType::Adjacent,
+        } = &self.path[0]
+        {
+            return Ok(Compound::Adjacent {
+                variant: key,
+                rest: &self.path[1..],
+                tagged: None,
+                found: None,
+            });
+        }
+
+        let rest = &self.path[1..];
+        Ok(self.members(rest))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(match self.variant(variant) {
+            Some([]) => Compound::Fields(serde_json::Map::new()),
+            Some(path) => Extractor { path }.members(&path[1..]),
+            None => Compound::Nothing,
+        })
+    }
+}
+
+impl SerializeSeq for Compound<'_> {
+    type Ok = Option<Value>;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl SerializeTuple for Compound<'_> {
+    type Ok = Option<Value>;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl SerializeTupleStruct for Compound<'_> {
+    type Ok = Option<Value>;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl SerializeTupleVariant for Compound<'_> {
+    type Ok = Option<Value>;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl SerializeMap for Compound<'_> {
+    type Ok = Option<Value>;
+    type Error = serde_json::Error;
+
+    // Keys are compared in their JSON form, where e.g. numbers become strings
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        if let Compound::Member {
+            key: wanted,
+            matched,
+            ..
+        } = self
+        {
+            *matched = match serde_json::to_value(key)? {
+                Value::String(key) => key == *wanted,
+                key => wanted.parse::<Value>().is_ok_and(|wanted| wanted == key),
+            };
+        }
+
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        if let Compound::Member {
+            rest,
+            matched: true,
+            found,
+            ..
+        } = self
+        {
+            *found = serialize_at(value, rest)?;
+        }
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl SerializeStruct for Compound<'_> {
+    type Ok = Option<Value>;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl SerializeStructVariant for Compound<'_> {
+    type Ok = Option<Value>;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
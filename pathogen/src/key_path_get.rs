@@ -0,0 +1,432 @@
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{KeyPath, KeyPathElement};
+
+/// Trait for types whose values can be read by keypath, the reading counterpart of
+/// `KeyPathMutable`
+pub trait KeyPathGet
+where
+    Self: Sized + 'static,
+{
+    /// The value at the location of `keys`, or `None` if there is nothing there, e.g. because an
+    /// index is out of bounds or the value is a different enum variant
+    fn get_keypath(&self, keys: &[KeyPathElement]) -> Option<&dyn Any>;
+
+    /// The value `keypath` points at.
+    ///
+    /// An option is transparent in keypaths, so the keypath to its `Some` content is the same as
+    /// the one to the option itself. The content is returned for keypaths typed for it.
+    ///
+    /// It's named `get_at` so it doesn't shadow the `get` methods of lists and maps.
+    fn get_at<Value: 'static>(&self, keypath: &KeyPath<Self, Value>) -> Option<&Value> {
+        let value = self.get_keypath(&keypath.path)?;
+
+        value
+            .downcast_ref()
+            .or_else(|| value.downcast_ref::<Option<Value>>()?.as_ref())
+    }
+}
+
+impl<T: KeyPathGet> KeyPathGet for Vec<T> {
+    fn get_keypath(&self, keys: &[KeyPathElement]) -> Option<&dyn Any> {
+        if keys.is_empty() {
+            return Some(self);
+        }
+
+        let KeyPathElement::Index { key } = keys[0] else {
+            return None;
+        };
+
+        self.get(key)?.get_keypath(&keys[1..])
+    }
+}
+
+impl<T: KeyPathGet> KeyPathGet for VecDeque<T> {
+    fn get_keypath(&self, keys: &[KeyPathElement]) -> Option<&dyn Any> {
+        if keys.is_empty() {
+            return Some(self);
+        }
+
+        let KeyPathElement::Index { key } = keys[0] else {
+            return None;
+        };
+
+        self.get(key)?.get_keypath(&keys[1..])
+    }
+}
+
+impl<T: KeyPathGet, const N: usize> KeyPathGet for [T; N] {
+    fn get_keypath(&self, keys: &[KeyPathElement]) -> Option<&dyn Any> {
+        if keys.is_empty() {
+            return Some(self);
+        }
+
+        let KeyPathElement::Index { key } = keys[0] else {
+            return None;
+        };
+
+        self.get(key)?.get_keypath(&keys[1..])
+    }
+}
+
+impl<T: KeyPathGet> KeyPathGet for Box<[T]> {
+    fn get_keypath(&self, keys: &[KeyPathElement]) -> Option<&dyn Any> {
+        if keys.is_empty() {
+            return Some(self);
+        }
+
+        let KeyPathElement::Index { key } = keys[0] else {
+            return None;
+        };
+
+        self.get(key)?.get_keypath(&keys[1..])
+    }
+}
+
+impl<K, V> KeyPathGet for BTreeMap<K, V>
+where
+    K: FromStr + Ord + 'static,
+    V: KeyPathGet,
+{
+    fn get_keypath(&self, keys: &[KeyPathElement]) -> Option<&dyn Any> {
+        if keys.is_empty() {
+            return Some(self);
+        }
+
+        let KeyPathElement::StringKey { key } = &keys[0] else {
+            return None;
+        };
+
+        let key = K::from_str(key).ok()?;
+
+        self.get(&key)?.get_keypath(&keys[1..])
+    }
+}
+
+impl<K, V> KeyPathGet for HashMap<K, V>
+where
+    K: FromStr + Eq + Hash + 'static,
+    V: KeyPathGet,
+{
+    fn get_keypath(&self, keys: &[KeyPathElement]) -> Option<&dyn Any> {
+        if keys.is_empty() {
+            return Some(self);
+        }
+
+        let KeyPathElement::StringKey { key } = &keys[0] else {
+            return None;
+        };
+
+        let key = K::from_str(key).ok()?;
+
+        self.get(&key)?.get_keypath(&keys[1..])
+    }
+}
+
+// Pointers are transparent in keypaths, so only an empty keypath points at the pointer itself
+impl<T: KeyPathGet> KeyPathGet for Box<T> {
+    fn get_keypath(&self, keys: &[KeyPathElement]) -> Option<&dyn Any> {
+        if keys.is_empty() {
+            return Some(self);
+        }
+
+        self.as_ref().get_keypath(keys)
+    }
+}
+
+impl<T: KeyPathGet> KeyPathGet for Arc<T> {
+    fn get_keypath(&self, keys: &[KeyPathElement]) -> Option<&dyn Any> {
+        if keys.is_empty() {
+            return Some(self);
+        }
+
+        self.as_ref().get_keypath(keys)
+    }
+}
+
+impl<T: KeyPathGet> KeyPathGet for Rc<T> {
+    fn get_keypath(&self, keys: &[KeyPathElement]) -> Option<&dyn Any> {
+        if keys.is_empty() {
+            return Some(self);
+        }
+
+        self.as_ref().get_keypath(keys)
+    }
+}
+
+impl<T: KeyPathGet> KeyPathGet for Option<T> {
+    fn get_keypath(&self, keys: &[KeyPathElement]) -> Option<&dyn Any> {
+        if keys.is_empty() {
+            return Some(self);
+        }
+
+        self.as_ref()?.get_keypath(keys)
+    }
+}
+
+/// Dynamic JSON, read by object member (either a `Field` or a `StringKey`) and by array `Index`
+impl KeyPathGet for serde_json::Value {
+    fn get_keypath(&self, keys: &[KeyPathElement]) -> Option<&dyn Any> {
+        let Some((first, rest)) = keys.split_first() else {
+            return Some(self);
+        };
+
+        let value = match (self, first) {
+            (serde_json::Value::Object(map), KeyPathElement::Field { key }) => map.get::<str>(key),
+            (serde_json::Value::Object(map), KeyPathElement::StringKey { key }) => {
+                map.get::<str>(key)
+            }
+            (serde_json::Value::Array(items), KeyPathElement::Index { key }) => items.get(*key),
+            _ => None,
+        };
+
+        value?.get_keypath(rest)
+    }
+}
+
+macro_rules! keypath_get_impl {
+    ($($t:ty)*) => ($(
+        impl KeyPathGet for $t {
+            fn get_keypath(&self, keys: &[KeyPathElement]) -> Option<&dyn Any> {
+                if !keys.is_empty() {
+                    return None;
+                }
+
+                Some(self)
+            }
+        }
+    )*);
+}
+
+keypath_get_impl! {
+    bool char String
+    usize u8 u16 u32 u64 u128
+    isize i8 i16 i32 i64 i128
+    f32 f64 DateTime<Utc> Uuid
+}
+
+// Tuples are serialized like tuple structs, so their items are addressed by position with
+// `Field` elements
+macro_rules! keypath_get_tuple_impl {
+    ($(($($item:ident $index:tt),+))*) => ($(
+        impl<$($item: KeyPathGet),+> KeyPathGet for ($($item,)+) {
+            fn get_keypath(&self, keys: &[KeyPathElement]) -> Option<&dyn Any> {
+                if keys.is_empty() {
+                    return Some(self);
+                }
+
+                let KeyPathElement::Field { key } = &keys[0] else {
+                    return None;
+                };
+
+                match key.as_ref() {
+                    $(stringify!($index) => self.$index.get_keypath(&keys[1..]),)+
+                    _ => None,
+                }
+            }
+        }
+    )*);
+}
+
+keypath_get_tuple_impl! {
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::macros::{KeyPathGet, Navigable};
+    use pretty_assertions::assert_eq;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::{keypath, KeyPath, Navigable};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathGet)]
+    struct Library {
+        name: String,
+        shelves: Vec<Shelf>,
+        #[serde(rename = "lookup")]
+        by_title: HashMap<String, usize>,
+        ratings: BTreeMap<usize, f64>,
+        featured: Option<Book>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathGet)]
+    struct Shelf {
+        books: Vec<Book>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathGet)]
+    #[serde(rename_all = "camelCase")]
+    struct Book {
+        title: String,
+        page_count: usize,
+        format: Format,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathGet)]
+    enum Format {
+        Paper { hardcover: bool },
+        Ebook { size_bytes: u64 },
+        Audio(f64),
+    }
+
+    fn library() -> Library {
+        Library {
+            name: "Central".to_string(),
+            shelves: vec![Shelf {
+                books: vec![
+                    Book {
+                        title: "Dune".to_string(),
+                        page_count: 412,
+                        format: Format::Ebook { size_bytes: 1024 },
+                    },
+                    Book {
+                        title: "Emma".to_string(),
+                        page_count: 474,
+                        format: Format::Audio(11.5),
+                    },
+                ],
+            }],
+            by_title: HashMap::from([("Dune".to_string(), 0)]),
+            ratings: BTreeMap::from([(1, 4.5)]),
+            featured: None,
+        }
+    }
+
+    #[test]
+    fn gets_nested_fields() {
+        let data = library();
+
+        assert_eq!(
+            data.get_at(&keypath![Library: name]),
+            Some(&"Central".to_string())
+        );
+        assert_eq!(
+            data.get_at(&keypath![Library: shelves[0].books[1].page_count]),
+            Some(&474)
+        );
+        assert_eq!(
+            data.get_at(&keypath![Library: shelves[0].books[0]]),
+            Some(&data.shelves[0].books[0])
+        );
+    }
+
+    #[test]
+    fn gets_map_values() {
+        let data = library();
+
+        assert_eq!(
+            data.get_at(&keypath![Library: by_title["Dune".to_string()]]),
+            Some(&0)
+        );
+        assert_eq!(data.get_at(&keypath![Library: ratings[1]]), Some(&4.5));
+        assert_eq!(data.get_at(&keypath![Library: ratings[2]]), None);
+    }
+
+    #[test]
+    fn gets_enum_variant_fields() {
+        let data = library();
+
+        assert_eq!(
+            data.get_at(&keypath![Library: shelves[0].books[0].format.Ebook.size_bytes]),
+            Some(&1024)
+        );
+        assert_eq!(
+            data.get_at(&keypath![Library: shelves[0].books[1].format.Audio.0]),
+            Some(&11.5)
+        );
+
+        // A keypath through another variant points at nothing
+        assert_eq!(
+            data.get_at(&keypath![Library: shelves[0].books[1].format.Ebook.size_bytes]),
+            None
+        );
+    }
+
+    #[test]
+    fn gets_options_and_their_content() {
+        let mut data = library();
+
+        assert_eq!(data.get_at(&keypath![Library: featured]), Some(&None));
+        assert_eq!(data.get_at(&keypath![Library: featured.Some]), None);
+        assert_eq!(data.get_at(&keypath![Library: featured.Some.title]), None);
+
+        data.featured = Some(Book {
+            title: "Ulysses".to_string(),
+            page_count: 730,
+            format: Format::Paper { hardcover: true },
+        });
+
+        assert_eq!(
+            data.get_at(&keypath![Library: featured.Some]),
+            data.featured.as_ref()
+        );
+        assert_eq!(
+            data.get_at(&keypath![Library: featured.Some.title]),
+            Some(&"Ulysses".to_string())
+        );
+    }
+
+    #[test]
+    fn returns_nothing_for_missing_locations() {
+        let data = library();
+
+        assert_eq!(data.get_at(&keypath![Library: shelves[3].books]), None);
+        assert_eq!(
+            data.get_at(&keypath![Library: by_title["Emma".to_string()]]),
+            None
+        );
+        assert!(data
+            .get_keypath(&KeyPath::<Library, usize>::field("missing").path)
+            .is_none());
+    }
+
+    #[test]
+    fn gets_from_json() {
+        let data = serde_json::json!({ "items": [{ "id": 7 }] });
+        let id = serde_json::Value::keypaths().key("items").fields().index(0);
+        let id = id.fields().key("id");
+
+        assert_eq!(data.get_at(&id), Some(&serde_json::json!(7)));
+    }
+
+    #[derive(Debug, PartialEq, KeyPathGet)]
+    #[keypath_mutable(direct_dispatch)]
+    enum Message {
+        Text(Text),
+        Count(usize),
+    }
+
+    #[derive(Debug, PartialEq, KeyPathGet)]
+    struct Text {
+        body: String,
+    }
+
+    #[test]
+    fn gets_through_directly_dispatched_variants() {
+        let text = Message::Text(Text {
+            body: "hi".to_string(),
+        });
+        let body = [KeyPathElement::Field { key: "body".into() }];
+
+        assert_eq!(
+            text.get_keypath(&body)
+                .and_then(|value| value.downcast_ref::<String>()),
+            Some(&"hi".to_string())
+        );
+        assert!(Message::Count(1).get_keypath(&body).is_none());
+    }
+}
@@ -1,13 +1,19 @@
-use std::collections::BTreeMap;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::rc::Rc;
+use std::sync::Arc;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
 use std::{any::type_name, str::FromStr};
 
 use chrono::{DateTime, Utc};
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
 
 use super::Patch;
-use crate::KeyPathElement;
+use crate::{from_patch_value, json::serialize_at, ChangeSet, KeyPath, KeyPathElement, PatchBatch};
 
 use super::{AsPatch, ChangeOf};
 
@@ -19,15 +25,27 @@ pub enum KeyPathError {
     CannotMutatePrimitiveChildren { type_name: &'static str },
     #[error("attempt to splice type {type_name}")]
     CannotSpliceType { type_name: &'static str },
+    #[error("attempt to splice type {type_name}, which is not a list")]
+    SpliceOnNonList { type_name: &'static str },
+    #[error("attempt to delete type {type_name}, which is neither a map entry nor an option")]
+    CannotDelete { type_name: &'static str },
     #[error("error deserializing type {type_name}: {error}")]
     DeserializationError {
         type_name: &'static str,
         error: serde_json::Error,
     },
+    #[error("error serializing value: {error}")]
+    SerializationError { error: serde_json::Error },
+    #[error("error deserializing spliced value {index} as type {type_name}: {error}")]
+    SpliceDeserializationError {
+        index: usize,
+        type_name: &'static str,
+        error: serde_json::Error,
+    },
     #[error("attempt to mutate enum variant {type_name}::{variant}, but the KeyPathElement was not a field")]
     MustMutateEnumVariantWithField {
         type_name: &'static str,
-        variant: &'static str,
+        variant: String,
     },
     #[error("attempt to mutate type {type_name}, but the KeyPathElement was not a variant")]
     MustMutateEnumWithVariant { type_name: &'static str },
@@ -35,20 +53,33 @@ pub enum KeyPathError {
     MustMutateStructWithField { type_name: &'static str },
     #[error("attempt to mutate a vector, but the KeyPathElement was not an index")]
     MustMutateVectorWithIndex,
+    #[error("attempt to mutate index {index} of an array with {len} items")]
+    IndexOutOfBounds { index: usize, len: usize },
     #[error("attempt to mutate a map, but the KeyPathElement was not a string key")]
     MustMutateMapWithStringKey,
     #[error("attempt to mutate type {type_name} with unknown field: {field}")]
     UnknownField {
         type_name: &'static str,
-        field: &'static str,
+        field: String,
     },
+    #[error("attempt to apply a value of {size} bytes, exceeding the limit of {max} bytes")]
+    ValueTooLarge { size: usize, max: usize },
+    #[error("attempt to mutate a RefCell which is already borrowed")]
+    AlreadyBorrowed,
+    #[error("attempt to mutate through a poisoned lock")]
+    PoisonedLock,
     #[error("attempt to mutate non-existing key {key}")]
     UnknownStringKey { key: String },
+    #[error("attempt to mutate read-only field {field} of type {type_name}")]
+    ReadOnlyField {
+        type_name: &'static str,
+        field: &'static str,
+    },
     #[error("attempt to mutate enum {type_name} with unknown variant or field: {variant}.{field}")]
     UnknownVariantOrField {
         type_name: &'static str,
-        variant: &'static str,
-        field: &'static str,
+        variant: String,
+        field: String,
     },
 }
 
@@ -59,6 +90,18 @@ impl KeyPathError {
         }
     }
 
+    pub fn splice_on_non_list<T>() -> Self {
+        KeyPathError::SpliceOnNonList {
+            type_name: type_name::<T>(),
+        }
+    }
+
+    pub fn cannot_delete<T>() -> Self {
+        KeyPathError::CannotDelete {
+            type_name: type_name::<T>(),
+        }
+    }
+
     pub fn from_deserialization_error<T>(error: serde_json::Error) -> Self {
         KeyPathError::DeserializationError {
             type_name: type_name::<T>(),
@@ -66,16 +109,24 @@ impl KeyPathError {
         }
     }
 
+    pub fn from_splice_deserialization_error<T>(index: usize, error: serde_json::Error) -> Self {
+        KeyPathError::SpliceDeserializationError {
+            index,
+            type_name: type_name::<T>(),
+            error,
+        }
+    }
+
     pub fn must_mutate_enum_with_variant<T>() -> Self {
         KeyPathError::MustMutateEnumWithVariant {
             type_name: type_name::<T>(),
         }
     }
 
-    pub fn must_mutate_enum_variant_with_field<T>(variant: &'static str) -> Self {
+    pub fn must_mutate_enum_variant_with_field<T>(variant: &str) -> Self {
         KeyPathError::MustMutateEnumVariantWithField {
             type_name: type_name::<T>(),
-            variant,
+            variant: variant.to_string(),
         }
     }
 
@@ -85,26 +136,68 @@ impl KeyPathError {
         }
     }
 
-    pub fn unknown_field<T>(field: &'static str) -> Self {
+    pub fn unknown_field<T>(field: &str) -> Self {
         KeyPathError::UnknownField {
+            type_name: type_name::<T>(),
+            field: field.to_string(),
+        }
+    }
+
+    pub fn read_only_field<T>(field: &'static str) -> Self {
+        KeyPathError::ReadOnlyField {
             type_name: type_name::<T>(),
             field,
         }
     }
 
-    pub fn unknown_variant_or_field<T>(variant: &'static str, field: &'static str) -> Self {
+    pub fn unknown_variant_or_field<T>(variant: &str, field: &str) -> Self {
         KeyPathError::UnknownVariantOrField {
             type_name: type_name::<T>(),
-            variant,
-            field,
+            variant: variant.to_string(),
+            field: field.to_string(),
         }
     }
+
+    /// Whether the change addressed something which doesn't exist (any more), e.g. because it
+    /// comes from an out of date client and can be ignored, as opposed to being malformed
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            KeyPathError::UnknownField { .. }
+                | KeyPathError::UnknownVariantOrField { .. }
+                | KeyPathError::UnknownStringKey { .. }
+                | KeyPathError::IndexOutOfBounds { .. }
+        )
+    }
+}
+
+/// Converts the value of a patch into a `KeyPathMutable` type.
+///
+/// Every value a patch creates goes through it: a value updated at its keypath, the items spliced
+/// into a list, an entry inserted into a map, the content of an `Option` or a pointer, and each
+/// field of a value being replaced as a whole. The impls for collections, options, pointers and
+/// tuples take the patch value apart the way serde would and convert each part with its own
+/// `FromPatchValue`, while primitives deserialize with serde.
+///
+/// `derive(KeyPathMutable)` implements it field by field, following the serde attributes the
+/// derive understands. Fields the derive doesn't require to be `KeyPathMutable`, because they are
+/// skipped or read-only, are deserialized with serde. Types marked
+/// `#[keypath_mutable(custom_from_patch_value)]` implement it by hand instead, e.g. to parse a
+/// decimal from a patch without a serde `with` module.
+pub trait FromPatchValue: Sized {
+    fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error>;
+
+    /// The value of a field missing from the patch value of its struct, which is an error unless
+    /// the type can stand for nothing, like `None` does
+    fn from_missing_field(field: &'static str) -> Result<Self, serde_json::Error> {
+        Err(serde::de::Error::missing_field(field))
+    }
 }
 
 // TODO: consider making this part of Navigable when finished
 pub trait KeyPathMutable
 where
-    Self: Sized + 'static,
+    Self: FromPatchValue + 'static,
 {
     /// Mutate by a keypath (as a slice of elements) in a member that is a struct or enum
     //
@@ -124,14 +217,215 @@ where
     // 3. Match on Patch type and update self.[key] to deserialised value (type is now known based on Self)
     fn patch_keypath(&mut self, keys: &[KeyPathElement], patch: Patch) -> Result<(), KeyPathError>;
 
+    /// Check that a keypath (as a slice of elements) is valid for the structure of Self, without
+    /// needing a value, i.e. that `patch_keypath` could follow it for at least some values.
+    ///
+    /// There is no default, since accepting every keypath would let invalid changes through
+    /// validation unnoticed.
+    fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError>;
+
+    /// Check that a keypath is valid for the structure of Self and points at a list which can be
+    /// spliced, without needing a value, e.g. to replace a list whole where it can't be.
+    ///
+    /// By default nothing can be spliced, which suits everything but lists and containers of them.
+    fn validate_splice(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        Self::validate_path(keys)?;
+        Err(KeyPathError::splice_on_non_list::<Self>())
+    }
+
+    /// Check that a typed keypath is valid for the structure of Self before persisting or
+    /// applying a change along it, without mutating or deserializing anything.
+    ///
+    /// Like `validate_path` this only knows the type, so fields are confirmed to exist, but map
+    /// keys and indices can't be, and any variant of an enum is accepted.
+    fn validate_keypath<Value>(key_path: &KeyPath<Self, Value>) -> Result<(), KeyPathError> {
+        Self::validate_path(&key_path.path)
+    }
+
     /// Apply a `ChangeOf<Self>` to self, which will mutate a deeply nested value based on the keypath
     fn apply_change(&mut self, change: &ChangeOf<Self>) {
+        self.try_apply_change(change)
+            .unwrap_or_else(|error| panic!("patch failure at {}: {error}", change.key_path()));
+    }
+
+    /// Apply a `ChangeOf<Self>` to self, returning an error instead of panicking if the change
+    /// can't be applied
+    fn try_apply_change(&mut self, change: &ChangeOf<Self>) -> Result<(), KeyPathError> {
         self.patch_keypath(&change.key_path().path, change.as_patch())
-            .expect("patch failure");
+    }
+
+    /// Apply a sequence of changes to self in order, stopping at the first one which can't be
+    /// applied. Changes before it stay applied.
+    fn apply_changes(
+        &mut self,
+        changes: impl IntoIterator<Item = ChangeOf<Self>>,
+    ) -> Result<(), KeyPathError> {
+        changes
+            .into_iter()
+            .try_for_each(|change| self.try_apply_change(&change))
+    }
+
+    /// Apply a `ChangeOf<Self>` to self, rejecting it without applying if its serialized value
+    /// is larger than `max_value_bytes`, e.g. when the change comes from an untrusted client
+    fn apply_change_bounded(
+        &mut self,
+        change: &ChangeOf<Self>,
+        max_value_bytes: usize,
+    ) -> Result<(), KeyPathError> {
+        let size = change.value_size();
+        if size > max_value_bytes {
+            return Err(KeyPathError::ValueTooLarge {
+                size,
+                max: max_value_bytes,
+            });
+        }
+
+        self.try_apply_change(change)
+    }
+
+    /// Apply a `ChangeOf<Self>` to self and return the changed value serialized as JSON (the whole
+    /// list for a splice), e.g. to send to a client instead of the entire state.
+    ///
+    /// Only the changed value is serialized. It's `None` if there's nothing left at the keypath,
+    /// e.g. after deleting a map entry.
+    fn apply_and_extract(
+        &mut self,
+        change: &ChangeOf<Self>,
+    ) -> Result<Option<serde_json::Value>, KeyPathError>
+    where
+        Self: Serialize,
+    {
+        self.try_apply_change(change)?;
+
+        serialize_at(&*self, &change.key_path().path)
+            .map_err(|error| KeyPathError::SerializationError { error })
+    }
+
+    /// Apply a `ChangeOf<Self>` to self, returning whether it changed anything.
+    ///
+    /// Only the value at the keypath (the whole list for a splice) is compared, in its serialized
+    /// form, before and after applying the change.
+    fn apply_change_if_changed(&mut self, change: &ChangeOf<Self>) -> Result<bool, KeyPathError>
+    where
+        Self: Serialize,
+    {
+        let path = &change.key_path().path;
+        let serialize = |root: &Self| {
+            serialize_at(root, path).map_err(|error| KeyPathError::SerializationError { error })
+        };
+        let before = serialize(self)?;
+
+        self.try_apply_change(change)?;
+
+        Ok(serialize(self)? != before)
+    }
+
+    /// Apply all changes in a `ChangeSet<Self>` to self, in order.
+    ///
+    /// Stops at the first change which can't be applied, leaving the changes before it applied.
+    fn apply_change_set(&mut self, set: &ChangeSet<Self>) -> Result<(), KeyPathError> {
+        set.iter()
+            .try_for_each(|change| self.try_apply_change(change))
+    }
+
+    /// Apply all patches in a `PatchBatch` to self, in order, e.g. after receiving the batch from
+    /// another process.
+    ///
+    /// Stops at the first patch which can't be applied, leaving the patches before it applied.
+    fn apply_patch_batch(&mut self, batch: &PatchBatch) -> Result<(), KeyPathError> {
+        batch.patches.iter().try_for_each(|patch| {
+            let (keys, _) = patch
+                .decode()
+                .map_err(KeyPathError::from_deserialization_error::<KeyPathElement>)?;
+
+            self.patch_keypath(&keys, patch.clone())
+        })
+    }
+
+    /// Apply all changes in a `ChangeSet<Self>` to self, in order, and return how many of them
+    /// changed anything
+    fn apply_change_set_dedup(&mut self, set: &ChangeSet<Self>) -> Result<usize, KeyPathError>
+    where
+        Self: Serialize,
+    {
+        let mut effective = 0;
+
+        for change in set {
+            if self.apply_change_if_changed(change)? {
+                effective += 1;
+            }
+        }
+
+        Ok(effective)
     }
 }
 
-impl<T: KeyPathMutable + DeserializeOwned> KeyPathMutable for Vec<T> {
+/// Apply a `ChangeOf<T>` to state shared behind an `Arc<Mutex<T>>`, holding the lock only for
+/// the duration of the change
+#[cfg(feature = "std")]
+pub fn apply_change_locked<T: KeyPathMutable>(
+    arc: &Arc<Mutex<T>>,
+    change: &ChangeOf<T>,
+) -> Result<(), KeyPathError> {
+    let mut state = arc.lock().map_err(|_| KeyPathError::PoisonedLock)?;
+
+    state.try_apply_change(change)
+}
+
+/// Apply a `ChangeOf<T>` to state behind a `RefCell<T>`, returning an error instead of panicking
+/// if the cell is already borrowed
+pub fn apply_change_refcell<T: KeyPathMutable>(
+    cell: &RefCell<T>,
+    change: &ChangeOf<T>,
+) -> Result<(), KeyPathError> {
+    let mut state = cell
+        .try_borrow_mut()
+        .map_err(|_| KeyPathError::AlreadyBorrowed)?;
+
+    state.try_apply_change(change)
+}
+
+/// The range of a list of `len` items which a splice replaces, or an error if it doesn't fit,
+/// e.g. because the splice is stale and the list has shrunk since
+fn splice_range(
+    start: usize,
+    replace: usize,
+    len: usize,
+) -> Result<std::ops::Range<usize>, KeyPathError> {
+    match start.checked_add(replace) {
+        Some(end) if end <= len => Ok(start..end),
+        // Report the first position the splice reaches which doesn't exist
+        _ => Err(KeyPathError::IndexOutOfBounds {
+            index: start.max(len),
+            len,
+        }),
+    }
+}
+
+/// Convert all values of a splice before any of them is applied, reporting which one failed
+fn deserialize_spliced<T: FromPatchValue>(
+    values: Vec<serde_json::Value>,
+) -> Result<Vec<T>, KeyPathError> {
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| {
+            T::from_patch_value(value)
+                .map_err(|error| KeyPathError::from_splice_deserialization_error::<T>(index, error))
+        })
+        .collect()
+}
+
+impl<T: FromPatchValue> FromPatchValue for Vec<T> {
+    fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        from_patch_value::items(value, "a sequence")?
+            .into_iter()
+            .map(T::from_patch_value)
+            .collect()
+    }
+}
+
+impl<T: KeyPathMutable> KeyPathMutable for Vec<T> {
     fn patch_keypath(&mut self, keys: &[KeyPathElement], patch: Patch) -> Result<(), KeyPathError> {
         if keys.is_empty() {
             match patch {
@@ -141,20 +435,18 @@ impl<T: KeyPathMutable + DeserializeOwned> KeyPathMutable for Vec<T> {
                     replace,
                     ..
                 } => {
-                    let replacements = value
-                        .into_iter()
-                        .map(|v| serde_json::from_value(v))
-                        .collect::<Result<Vec<_>, _>>()
-                        .map_err(KeyPathError::from_deserialization_error::<T>)?;
+                    let range = splice_range(start, replace, self.len())?;
+                    let replacements = deserialize_spliced::<T>(value)?;
 
-                    self.splice(start..(start + replace), replacements);
+                    self.splice(range, replacements);
                 }
                 Patch::Update { value, .. } => {
-                    let replacement: Vec<T> = serde_json::from_value(value)
+                    let replacement = Vec::<T>::from_patch_value(value)
                         .map_err(KeyPathError::from_deserialization_error::<T>)?;
 
                     self.splice(.., replacement);
                 }
+                Patch::Delete { .. } => return Err(KeyPathError::cannot_delete::<Self>()),
             };
             return Ok(());
         }
@@ -163,456 +455,3390 @@ impl<T: KeyPathMutable + DeserializeOwned> KeyPathMutable for Vec<T> {
             return Err(KeyPathError::MustMutateVectorWithIndex);
         };
 
-        let value = &mut self[key];
+        // A stale change may address an item which has since been removed
+        let len = self.len();
+        let Some(value) = self.get_mut(key) else {
+            return Err(KeyPathError::IndexOutOfBounds { index: key, len });
+        };
 
         // If there are more keys, recurse
         value.patch_keypath(&keys[1..], patch)
     }
+
+    fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let KeyPathElement::Index { .. } = keys[0] else {
+            return Err(KeyPathError::MustMutateVectorWithIndex);
+        };
+
+        T::validate_path(&keys[1..])
+    }
+
+    fn validate_splice(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let KeyPathElement::Index { .. } = keys[0] else {
+            return Err(KeyPathError::MustMutateVectorWithIndex);
+        };
+
+        T::validate_splice(&keys[1..])
+    }
 }
 
-impl<K, V> KeyPathMutable for BTreeMap<K, V>
-where
-    K: DeserializeOwned + FromStr + Ord + ToString + 'static,
-    V: KeyPathMutable + DeserializeOwned,
-{
+impl<T: FromPatchValue> FromPatchValue for VecDeque<T> {
+    fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        Vec::from_patch_value(value).map(VecDeque::from)
+    }
+}
+
+impl<T: KeyPathMutable> KeyPathMutable for VecDeque<T> {
     fn patch_keypath(&mut self, keys: &[KeyPathElement], patch: Patch) -> Result<(), KeyPathError> {
         if keys.is_empty() {
-            return match patch {
+            match patch {
+                Patch::Splice {
+                    value,
+                    start,
+                    replace,
+                    ..
+                } => {
+                    let range = splice_range(start, replace, self.len())?;
+                    let replacements = deserialize_spliced::<T>(value)?;
+
+                    // VecDeque has no splice, so cut out the range and stitch the rest back on
+                    let tail = self.split_off(range.end);
+                    self.truncate(range.start);
+                    self.extend(replacements);
+                    self.extend(tail);
+                }
                 Patch::Update { value, .. } => {
-                    *self = serde_json::from_value(value)
-                        .map_err(KeyPathError::from_deserialization_error::<Self>)?;
-                    Ok(())
+                    *self = Self::from_patch_value(value)
+                        .map_err(KeyPathError::from_deserialization_error::<T>)?;
                 }
-                Patch::Splice { .. } => Err(KeyPathError::CannotSpliceType {
-                    type_name: "BTreeMap",
-                }),
+                Patch::Delete { .. } => return Err(KeyPathError::cannot_delete::<Self>()),
             };
+            return Ok(());
         }
 
-        let KeyPathElement::StringKey { key } = &keys[0] else {
-            return Err(KeyPathError::MustMutateMapWithStringKey);
+        let KeyPathElement::Index { key } = keys[0] else {
+            return Err(KeyPathError::MustMutateVectorWithIndex);
         };
 
-        let Ok(key) = K::from_str(key) else {
-            return Err(KeyPathError::UnknownStringKey { key: key.clone() });
+        // A stale change may address an item which has since been removed
+        let len = self.len();
+        let Some(value) = self.get_mut(key) else {
+            return Err(KeyPathError::IndexOutOfBounds { index: key, len });
         };
 
-        if keys.len() == 1 {
-            if let Patch::Update { value, .. } = patch {
-                let value = serde_json::from_value(value)
-                    .map_err(KeyPathError::from_deserialization_error::<V>)?;
-                self.insert(key, value);
-                return Ok(());
-            }
+        // If there are more keys, recurse
+        value.patch_keypath(&keys[1..], patch)
+    }
+
+    fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            return Ok(());
         }
 
-        if let Some(value) = self.get_mut(&key) {
-            value.patch_keypath(&keys[1..], patch)
-        } else {
-            Err(KeyPathError::UnknownStringKey {
-                key: key.to_string(),
-            })
+        let KeyPathElement::Index { .. } = keys[0] else {
+            return Err(KeyPathError::MustMutateVectorWithIndex);
+        };
+
+        T::validate_path(&keys[1..])
+    }
+
+    fn validate_splice(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            return Ok(());
         }
+
+        let KeyPathElement::Index { .. } = keys[0] else {
+            return Err(KeyPathError::MustMutateVectorWithIndex);
+        };
+
+        T::validate_splice(&keys[1..])
     }
 }
 
-impl<T> KeyPathMutable for Option<T>
-where
-    T: DeserializeOwned + KeyPathMutable + 'static,
-{
+impl<T: FromPatchValue, const N: usize> FromPatchValue for [T; N] {
+    fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        let items = Vec::<T>::from_patch_value(value)?;
+        let len = items.len();
+
+        items.try_into().map_err(|_| {
+            serde::de::Error::invalid_length(len, &format!("an array of {N} items").as_str())
+        })
+    }
+}
+
+impl<T: KeyPathMutable, const N: usize> KeyPathMutable for [T; N] {
     fn patch_keypath(&mut self, keys: &[KeyPathElement], patch: Patch) -> Result<(), KeyPathError> {
-        if !keys.is_empty() {
-            if let Some(inner) = self.as_mut() {
-                return inner.patch_keypath(keys, patch);
-            }
+        if keys.is_empty() {
+            let value = match patch {
+                Patch::Update { value, .. } => value,
+                Patch::Splice { .. } => return Err(KeyPathError::cannot_splice_type::<Self>()),
+                Patch::Delete { .. } => return Err(KeyPathError::cannot_delete::<Self>()),
+            };
 
-            return Err(KeyPathError::CannotMutateNone);
+            *self = Self::from_patch_value(value)
+                .map_err(KeyPathError::from_deserialization_error::<Self>)?;
+            return Ok(());
         }
 
-        let Patch::Update { value, .. } = patch else {
-            return Err(KeyPathError::cannot_splice_type::<Option<T>>());
+        let KeyPathElement::Index { key } = keys[0] else {
+            return Err(KeyPathError::MustMutateVectorWithIndex);
         };
 
-        let value: Option<T> = serde_json::from_value(value)
-            .map_err(KeyPathError::from_deserialization_error::<Option<T>>)?;
+        let Some(value) = self.get_mut(key) else {
+            return Err(KeyPathError::IndexOutOfBounds { index: key, len: N });
+        };
 
-        *self = value;
-        Ok(())
+        value.patch_keypath(&keys[1..], patch)
     }
-}
 
-macro_rules! keypath_mutable_impl {
-    ($($t:ty)*) => ($(
-        impl KeyPathMutable for $t {
-            fn patch_keypath(&mut self, keys: &[KeyPathElement], patch: Patch) -> Result<(), KeyPathError> {
+    fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            return Ok(());
+        }
 
-                if !keys.is_empty() {
-                    return Err(KeyPathError::CannotMutatePrimitiveChildren { type_name: type_name::<$t>() });
-                }
+        let KeyPathElement::Index { key } = keys[0] else {
+            return Err(KeyPathError::MustMutateVectorWithIndex);
+        };
 
-                let Patch::Update { value, .. } = patch else {
-                    return Err(KeyPathError::cannot_splice_type::<$t>());
-                };
+        if key >= N {
+            return Err(KeyPathError::IndexOutOfBounds { index: key, len: N });
+        }
 
-                let value: $t = serde_json::from_value(value)
-                    .map_err(KeyPathError::from_deserialization_error::<$t>)?;
+        T::validate_path(&keys[1..])
+    }
 
-                *self = value;
-                Ok(())
-            }
+    fn validate_splice(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            return Err(KeyPathError::cannot_splice_type::<Self>());
         }
-    )*);
-}
 
-keypath_mutable_impl! {
-    bool char String
-    usize u8 u16 u32 u64 u128
-    isize i8 i16 i32 i64 i128
-    f32 f64 DateTime<Utc> Uuid
+        Self::validate_path(&keys[..1])?;
+        T::validate_splice(&keys[1..])
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use std::convert::Infallible;
+impl<T: FromPatchValue> FromPatchValue for Box<[T]> {
+    fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        Vec::from_patch_value(value).map(Vec::into_boxed_slice)
+    }
+}
 
-    use crate::macros::{KeyPathMutable, Navigable};
-    use pretty_assertions::assert_eq;
-    use serde::{Deserialize, Serialize};
+impl<T: KeyPathMutable> KeyPathMutable for Box<[T]> {
+    fn patch_keypath(&mut self, keys: &[KeyPathElement], patch: Patch) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            let value = match patch {
+                Patch::Update { value, .. } => value,
+                Patch::Splice { .. } => return Err(KeyPathError::cannot_splice_type::<Self>()),
+                Patch::Delete { .. } => return Err(KeyPathError::cannot_delete::<Self>()),
+            };
 
-    use super::*;
-    use crate::{key_path_mutable::KeyPathError, keypath, Change, KeyPath, Navigable};
+            *self = Self::from_patch_value(value)
+                .map_err(KeyPathError::from_deserialization_error::<Self>)?;
+            return Ok(());
+        }
 
-    #[test]
-    fn updates_a_vector_element() {
-        let mut data = vec![1, 2, 3];
-        let change = Change::update(keypath![Vec<usize>: [1]], 5);
+        let KeyPathElement::Index { key } = keys[0] else {
+            return Err(KeyPathError::MustMutateVectorWithIndex);
+        };
 
-        data.apply_change(&change);
+        let len = self.len();
+        let Some(value) = self.get_mut(key) else {
+            return Err(KeyPathError::IndexOutOfBounds { index: key, len });
+        };
 
-        assert_eq!(data, vec![1, 5, 3]);
+        value.patch_keypath(&keys[1..], patch)
     }
 
-    #[test]
-    fn splices_a_vector() {
-        let mut data = vec![1, 2, 3];
-        let change = Change::splice(KeyPath::unit(), vec![5, 6], 1, 0);
+    fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            return Ok(());
+        }
 
-        data.apply_change(&change);
+        let KeyPathElement::Index { .. } = keys[0] else {
+            return Err(KeyPathError::MustMutateVectorWithIndex);
+        };
 
-        assert_eq!(data, vec![1, 5, 6, 2, 3]);
+        T::validate_path(&keys[1..])
     }
 
-    #[derive(PartialEq, Debug, Clone, Serialize, Deserialize, Navigable)]
-    struct SimpleStruct {
-        first_field: usize,
-        second_field: String,
-        third_field: Vec<String>,
+    fn validate_splice(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            return Err(KeyPathError::cannot_splice_type::<Self>());
+        }
+
+        Self::validate_path(&keys[..1])?;
+        T::validate_splice(&keys[1..])
     }
+}
 
-    // This impl will be generated by a derive macro
-    impl KeyPathMutable for SimpleStruct {
-        fn patch_keypath(
-            &mut self,
-            keys: &[KeyPathElement],
-            patch: Patch,
-        ) -> Result<(), KeyPathError> {
-            if keys.is_empty() {
-                return if let Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(KeyPathError::from_deserialization_error::<SimpleStruct>)?;
-                    Ok(())
-                } else {
-                    Err(KeyPathError::cannot_splice_type::<SimpleStruct>())
+// The keys are deserialized with serde, which parses them from strings like it does from a JSON
+// object, since they are never mutated by keypath
+impl<K, V> FromPatchValue for BTreeMap<K, V>
+where
+    K: DeserializeOwned + Ord,
+    V: FromPatchValue,
+{
+    fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        serde_json::from_value::<BTreeMap<K, serde_json::Value>>(value)?
+            .into_iter()
+            .map(|(key, value)| Ok((key, V::from_patch_value(value)?)))
+            .collect()
+    }
+}
+
+impl<K, V> KeyPathMutable for BTreeMap<K, V>
+where
+    K: DeserializeOwned + FromStr + Ord + ToString + 'static,
+    V: KeyPathMutable,
+{
+    fn patch_keypath(&mut self, keys: &[KeyPathElement], patch: Patch) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            return match patch {
+                Patch::Update { value, .. } => {
+                    *self = Self::from_patch_value(value)
+                        .map_err(KeyPathError::from_deserialization_error::<Self>)?;
+                    Ok(())
+                }
+                Patch::Splice { .. } => Err(KeyPathError::cannot_splice_type::<Self>()),
+                Patch::Delete { .. } => Err(KeyPathError::cannot_delete::<Self>()),
+            };
+        }
+
+        let KeyPathElement::StringKey { key } = &keys[0] else {
+            return Err(KeyPathError::MustMutateMapWithStringKey);
+        };
+
+        let Ok(key) = K::from_str(key) else {
+            return Err(KeyPathError::UnknownStringKey {
+                key: key.to_string(),
+            });
+        };
+
+        if keys.len() == 1 {
+            match patch {
+                Patch::Update { value, .. } => {
+                    let value = V::from_patch_value(value)
+                        .map_err(KeyPathError::from_deserialization_error::<V>)?;
+                    self.insert(key, value);
+                    return Ok(());
+                }
+                // Deleting a missing entry is a no-op, so it doesn't matter whether an earlier
+                // change inserted it
+                Patch::Delete { .. } => {
+                    self.remove(&key);
+                    return Ok(());
+                }
+                Patch::Splice { .. } => {}
+            }
+        }
+
+        if let Some(value) = self.get_mut(&key) {
+            value.patch_keypath(&keys[1..], patch)
+        } else {
+            Err(KeyPathError::UnknownStringKey {
+                key: key.to_string(),
+            })
+        }
+    }
+
+    fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let KeyPathElement::StringKey { key } = &keys[0] else {
+            return Err(KeyPathError::MustMutateMapWithStringKey);
+        };
+
+        if K::from_str(key).is_err() {
+            return Err(KeyPathError::UnknownStringKey {
+                key: key.to_string(),
+            });
+        }
+
+        V::validate_path(&keys[1..])
+    }
+
+    fn validate_splice(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            return Err(KeyPathError::cannot_splice_type::<Self>());
+        }
+
+        Self::validate_path(&keys[..1])?;
+        V::validate_splice(&keys[1..])
+    }
+}
+
+// The keys are deserialized with serde, which parses them from strings like it does from a JSON
+// object, since they are never mutated by keypath
+impl<K, V> FromPatchValue for HashMap<K, V>
+where
+    K: DeserializeOwned + Eq + Hash,
+    V: FromPatchValue,
+{
+    fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        serde_json::from_value::<HashMap<K, serde_json::Value>>(value)?
+            .into_iter()
+            .map(|(key, value)| Ok((key, V::from_patch_value(value)?)))
+            .collect()
+    }
+}
+
+impl<K, V> KeyPathMutable for HashMap<K, V>
+where
+    K: DeserializeOwned + FromStr + Eq + Hash + ToString + 'static,
+    V: KeyPathMutable,
+{
+    fn patch_keypath(&mut self, keys: &[KeyPathElement], patch: Patch) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            return match patch {
+                Patch::Update { value, .. } => {
+                    *self = Self::from_patch_value(value)
+                        .map_err(KeyPathError::from_deserialization_error::<Self>)?;
+                    Ok(())
+                }
+                Patch::Splice { .. } => Err(KeyPathError::cannot_splice_type::<Self>()),
+                Patch::Delete { .. } => Err(KeyPathError::cannot_delete::<Self>()),
+            };
+        }
+
+        let KeyPathElement::StringKey { key } = &keys[0] else {
+            return Err(KeyPathError::MustMutateMapWithStringKey);
+        };
+
+        let Ok(key) = K::from_str(key) else {
+            return Err(KeyPathError::UnknownStringKey {
+                key: key.to_string(),
+            });
+        };
+
+        if keys.len() == 1 {
+            match patch {
+                Patch::Update { value, .. } => {
+                    let value = V::from_patch_value(value)
+                        .map_err(KeyPathError::from_deserialization_error::<V>)?;
+                    self.insert(key, value);
+                    return Ok(());
+                }
+                // Deleting a missing entry is a no-op, so it doesn't matter whether an earlier
+                // change inserted it
+                Patch::Delete { .. } => {
+                    self.remove(&key);
+                    return Ok(());
+                }
+                Patch::Splice { .. } => {}
+            }
+        }
+
+        if let Some(value) = self.get_mut(&key) {
+            value.patch_keypath(&keys[1..], patch)
+        } else {
+            Err(KeyPathError::UnknownStringKey {
+                key: key.to_string(),
+            })
+        }
+    }
+
+    fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let KeyPathElement::StringKey { key } = &keys[0] else {
+            return Err(KeyPathError::MustMutateMapWithStringKey);
+        };
+
+        if K::from_str(key).is_err() {
+            return Err(KeyPathError::UnknownStringKey {
+                key: key.to_string(),
+            });
+        }
+
+        V::validate_path(&keys[1..])
+    }
+
+    fn validate_splice(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            return Err(KeyPathError::cannot_splice_type::<Self>());
+        }
+
+        Self::validate_path(&keys[..1])?;
+        V::validate_splice(&keys[1..])
+    }
+}
+
+// Pointers are transparent in the serialized form, so a missing field is missing content too
+impl<T: FromPatchValue> FromPatchValue for Box<T> {
+    fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        T::from_patch_value(value).map(Box::new)
+    }
+
+    fn from_missing_field(field: &'static str) -> Result<Self, serde_json::Error> {
+        T::from_missing_field(field).map(Box::new)
+    }
+}
+
+impl<T: FromPatchValue> FromPatchValue for Arc<T> {
+    fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        T::from_patch_value(value).map(Arc::new)
+    }
+
+    fn from_missing_field(field: &'static str) -> Result<Self, serde_json::Error> {
+        T::from_missing_field(field).map(Arc::new)
+    }
+}
+
+impl<T: FromPatchValue> FromPatchValue for Rc<T> {
+    fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        T::from_patch_value(value).map(Rc::new)
+    }
+
+    fn from_missing_field(field: &'static str) -> Result<Self, serde_json::Error> {
+        T::from_missing_field(field).map(Rc::new)
+    }
+}
+
+// Forwarding the empty keypath too replaces the boxed value in place, which is the same as
+// replacing the box, since a box serializes as its content
+impl<T: KeyPathMutable> KeyPathMutable for Box<T> {
+    fn patch_keypath(&mut self, keys: &[KeyPathElement], patch: Patch) -> Result<(), KeyPathError> {
+        self.as_mut().patch_keypath(keys, patch)
+    }
+
+    fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        T::validate_path(keys)
+    }
+
+    fn validate_splice(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        T::validate_splice(keys)
+    }
+}
+
+/// Copy-on-write: if the value is shared with other `Arc`s, it's cloned before being mutated
+/// (see `Arc::make_mut`), so the other owners keep seeing the old value
+impl<T: KeyPathMutable + Clone> KeyPathMutable for Arc<T> {
+    fn patch_keypath(&mut self, keys: &[KeyPathElement], patch: Patch) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            if let Patch::Update { value, .. } = patch {
+                *self = Self::from_patch_value(value)
+                    .map_err(KeyPathError::from_deserialization_error::<T>)?;
+                return Ok(());
+            }
+        }
+
+        Arc::make_mut(self).patch_keypath(keys, patch)
+    }
+
+    fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        T::validate_path(keys)
+    }
+
+    fn validate_splice(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        T::validate_splice(keys)
+    }
+}
+
+/// Copy-on-write: if the value is shared with other `Rc`s, it's cloned before being mutated
+/// (see `Rc::make_mut`), so the other owners keep seeing the old value
+impl<T: KeyPathMutable + Clone> KeyPathMutable for Rc<T> {
+    fn patch_keypath(&mut self, keys: &[KeyPathElement], patch: Patch) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            if let Patch::Update { value, .. } = patch {
+                *self = Self::from_patch_value(value)
+                    .map_err(KeyPathError::from_deserialization_error::<T>)?;
+                return Ok(());
+            }
+        }
+
+        Rc::make_mut(self).patch_keypath(keys, patch)
+    }
+
+    fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        T::validate_path(keys)
+    }
+
+    fn validate_splice(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        T::validate_splice(keys)
+    }
+}
+
+// serde deserializes `null` as `None`, whatever the content, and a missing field too
+impl<T: FromPatchValue> FromPatchValue for Option<T> {
+    fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        match value {
+            serde_json::Value::Null => Ok(None),
+            value => T::from_patch_value(value).map(Some),
+        }
+    }
+
+    fn from_missing_field(_field: &'static str) -> Result<Self, serde_json::Error> {
+        Ok(None)
+    }
+}
+
+// Forwarding the empty keypath to the content, like `Box` does, would make replacing a `None`
+// with a `Some` impossible
+impl<T: KeyPathMutable> KeyPathMutable for Option<T> {
+    fn patch_keypath(&mut self, keys: &[KeyPathElement], patch: Patch) -> Result<(), KeyPathError> {
+        if !keys.is_empty() {
+            if let Some(inner) = self.as_mut() {
+                return inner.patch_keypath(keys, patch);
+            }
+
+            return Err(KeyPathError::CannotMutateNone);
+        }
+
+        let value = match patch {
+            Patch::Update { value, .. } => value,
+            Patch::Splice { .. } => return Err(KeyPathError::splice_on_non_list::<Option<T>>()),
+            Patch::Delete { .. } => {
+                *self = None;
+                return Ok(());
+            }
+        };
+
+        *self = Self::from_patch_value(value)
+            .map_err(KeyPathError::from_deserialization_error::<Option<T>>)?;
+        Ok(())
+    }
+
+    fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        T::validate_path(keys)
+    }
+
+    fn validate_splice(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        if keys.is_empty() {
+            return Err(KeyPathError::splice_on_non_list::<Self>());
+        }
+
+        T::validate_splice(keys)
+    }
+}
+
+impl FromPatchValue for serde_json::Value {
+    fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        Ok(value)
+    }
+}
+
+/// Dynamic JSON, navigated by object member (either a `Field` or a `StringKey`) and by array
+/// `Index`. As with maps, updating a missing object member inserts it and deleting one removes
+/// it.
+impl KeyPathMutable for serde_json::Value {
+    fn patch_keypath(&mut self, keys: &[KeyPathElement], patch: Patch) -> Result<(), KeyPathError> {
+        let Some((first, rest)) = keys.split_first() else {
+            return match patch {
+                Patch::Update { value, .. } => {
+                    *self = value;
+                    Ok(())
+                }
+                Patch::Splice {
+                    value,
+                    start,
+                    replace,
+                    ..
+                } => {
+                    let serde_json::Value::Array(items) = self else {
+                        return Err(KeyPathError::splice_on_non_list::<Self>());
+                    };
+
+                    let range = splice_range(start, replace, items.len())?;
+
+                    items.splice(range, value);
+                    Ok(())
+                }
+                Patch::Delete { .. } => Err(KeyPathError::cannot_delete::<Self>()),
+            };
+        };
+
+        let (key, map) = match (first, self) {
+            (KeyPathElement::Field { key }, serde_json::Value::Object(map)) => (&**key, map),
+            (KeyPathElement::StringKey { key }, serde_json::Value::Object(map)) => (&**key, map),
+            (KeyPathElement::Index { key }, serde_json::Value::Array(items)) => {
+                let len = items.len();
+
+                return match items.get_mut(*key) {
+                    Some(item) => item.patch_keypath(rest, patch),
+                    None => Err(KeyPathError::IndexOutOfBounds { index: *key, len }),
                 };
             }
+            (_, serde_json::Value::Object(_)) => {
+                return Err(KeyPathError::MustMutateMapWithStringKey)
+            }
+            (_, serde_json::Value::Array(_)) => {
+                return Err(KeyPathError::MustMutateVectorWithIndex)
+            }
+            (_, _) => {
+                return Err(KeyPathError::CannotMutatePrimitiveChildren {
+                    type_name: type_name::<Self>(),
+                })
+            }
+        };
+
+        if rest.is_empty() {
+            match patch {
+                Patch::Update { value, .. } => {
+                    map.insert(key.to_string(), value);
+                    return Ok(());
+                }
+                Patch::Delete { .. } => {
+                    map.remove(key);
+                    return Ok(());
+                }
+                Patch::Splice { .. } => {}
+            }
+        }
+
+        match map.get_mut(key) {
+            Some(value) => value.patch_keypath(rest, patch),
+            None => Err(KeyPathError::UnknownStringKey {
+                key: key.to_string(),
+            }),
+        }
+    }
+
+    // Which members and items exist depends on the value, but there are never any variants
+    fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        if keys
+            .iter()
+            .any(|key| matches!(key, KeyPathElement::Variant { .. }))
+        {
+            return Err(KeyPathError::MustMutateMapWithStringKey);
+        }
+
+        Ok(())
+    }
+
+    // Any item might be an array
+    fn validate_splice(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+        Self::validate_path(keys)
+    }
+}
+
+macro_rules! keypath_mutable_impl {
+    ($($t:ty)*) => ($(
+        impl FromPatchValue for $t {
+            fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+                serde_json::from_value(value)
+            }
+        }
+
+        impl KeyPathMutable for $t {
+            fn patch_keypath(&mut self, keys: &[KeyPathElement], patch: Patch) -> Result<(), KeyPathError> {
+
+                if !keys.is_empty() {
+                    return Err(KeyPathError::CannotMutatePrimitiveChildren { type_name: type_name::<$t>() });
+                }
+
+                let value = match patch {
+                    Patch::Update { value, .. } => value,
+                    Patch::Splice { .. } => return Err(KeyPathError::splice_on_non_list::<$t>()),
+                    Patch::Delete { .. } => return Err(KeyPathError::cannot_delete::<$t>()),
+                };
+
+                *self = <$t>::from_patch_value(value)
+                    .map_err(KeyPathError::from_deserialization_error::<$t>)?;
+                Ok(())
+            }
+
+            fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+                if !keys.is_empty() {
+                    return Err(KeyPathError::CannotMutatePrimitiveChildren { type_name: type_name::<$t>() });
+                }
+
+                Ok(())
+            }
+        }
+    )*);
+}
+
+keypath_mutable_impl! {
+    bool char String
+    usize u8 u16 u32 u64 u128
+    isize i8 i16 i32 i64 i128
+    f32 f64 DateTime<Utc> Uuid
+}
+
+// Tuples are serialized like tuple structs, so their items are addressed by position with
+// `Field` elements
+macro_rules! keypath_mutable_tuple_impl {
+    ($(($($item:ident $index:tt),+))*) => ($(
+        impl<$($item: FromPatchValue),+> FromPatchValue for ($($item,)+) {
+            fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+                let items = from_patch_value::items(value, "a tuple")?;
+                let len = [$(stringify!($index)),+].len();
+                if items.len() != len {
+                    return Err(serde::de::Error::invalid_length(
+                        items.len(),
+                        &format!("a tuple of size {len}").as_str(),
+                    ));
+                }
+
+                let mut items = items.into_iter();
+                Ok(($($item::from_patch_value(items.next().expect("checked length"))?,)+))
+            }
+        }
+
+        impl<$($item: KeyPathMutable),+> KeyPathMutable for ($($item,)+) {
+            fn patch_keypath(&mut self, keys: &[KeyPathElement], patch: Patch) -> Result<(), KeyPathError> {
+                if keys.is_empty() {
+                    let value = match patch {
+                        Patch::Update { value, .. } => value,
+                        Patch::Splice { .. } => return Err(KeyPathError::cannot_splice_type::<Self>()),
+                        Patch::Delete { .. } => return Err(KeyPathError::cannot_delete::<Self>()),
+                    };
+
+                    *self = Self::from_patch_value(value)
+                        .map_err(KeyPathError::from_deserialization_error::<Self>)?;
+                    return Ok(());
+                }
+
+                let KeyPathElement::Field { key } = &keys[0] else {
+                    return Err(KeyPathError::must_mutate_struct_with_field::<Self>());
+                };
+
+                match key.as_ref() {
+                    $(stringify!($index) => self.$index.patch_keypath(&keys[1..], patch),)+
+                    _ => Err(KeyPathError::unknown_field::<Self>(key)),
+                }
+            }
+
+            fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+                if keys.is_empty() {
+                    return Ok(());
+                }
+
+                let KeyPathElement::Field { key } = &keys[0] else {
+                    return Err(KeyPathError::must_mutate_struct_with_field::<Self>());
+                };
+
+                match key.as_ref() {
+                    $(stringify!($index) => $item::validate_path(&keys[1..]),)+
+                    _ => Err(KeyPathError::unknown_field::<Self>(key)),
+                }
+            }
+
+            fn validate_splice(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+                if keys.is_empty() {
+                    return Err(KeyPathError::cannot_splice_type::<Self>());
+                }
+
+                let KeyPathElement::Field { key } = &keys[0] else {
+                    return Err(KeyPathError::must_mutate_struct_with_field::<Self>());
+                };
+
+                match key.as_ref() {
+                    $(stringify!($index) => $item::validate_splice(&keys[1..]),)+
+                    _ => Err(KeyPathError::unknown_field::<Self>(key)),
+                }
+            }
+        }
+    )*);
+}
+
+keypath_mutable_tuple_impl! {
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::macros::{KeyPathGet, KeyPathMutable, Navigable, Pathogen};
+    use pretty_assertions::assert_eq;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::{
+        key_path_mutable::KeyPathError, keypath, testing::assert_changed_only, Change, KeyPath,
+        KeyPathFrom, Navigable,
+    };
+
+    #[test]
+    fn updates_a_vector_element() {
+        let mut data = vec![1, 2, 3];
+        let change = Change::update(keypath![Vec<usize>: [1]], 5);
+
+        data.apply_change(&change);
+
+        assert_eq!(data, vec![1, 5, 3]);
+    }
+
+    #[test]
+    fn refuses_to_update_a_vector_out_of_bounds() {
+        let mut data = vec![vec![1], vec![2, 3]];
+
+        let result = data.try_apply_change(&Change::update(keypath![Vec<Vec<usize>>: [2]], vec![]));
+        assert!(matches!(
+            result,
+            Err(KeyPathError::IndexOutOfBounds { index: 2, len: 2 })
+        ));
+
+        let result = data.try_apply_change(&Change::update(keypath![Vec<Vec<usize>>: [5][0]], 4));
+        assert!(matches!(
+            result,
+            Err(KeyPathError::IndexOutOfBounds { index: 5, len: 2 })
+        ));
+
+        let result = data.try_apply_change(&Change::update(keypath![Vec<Vec<usize>>: [0][1]], 4));
+        assert!(matches!(
+            result,
+            Err(KeyPathError::IndexOutOfBounds { index: 1, len: 1 })
+        ));
+
+        assert_eq!(data, vec![vec![1], vec![2, 3]]);
+    }
+
+    #[test]
+    fn refuses_to_splice_a_vector_out_of_bounds() {
+        let mut data = vec![1, 2, 3];
+
+        let result = data.try_apply_change(&Change::splice(KeyPath::unit(), vec![4], 4, 0));
+        assert!(matches!(
+            result,
+            Err(KeyPathError::IndexOutOfBounds { index: 4, len: 3 })
+        ));
+
+        let result = data.try_apply_change(&Change::splice(KeyPath::unit(), vec![4], 2, 2));
+        assert!(matches!(
+            result,
+            Err(KeyPathError::IndexOutOfBounds { index: 3, len: 3 })
+        ));
+
+        let result = data.try_apply_change(&Change::splice(
+            KeyPath::unit(),
+            Vec::<usize>::new(),
+            1,
+            usize::MAX,
+        ));
+        assert!(matches!(
+            result,
+            Err(KeyPathError::IndexOutOfBounds { index: 3, len: 3 })
+        ));
+
+        assert_eq!(data, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn classifies_not_found_errors() {
+        let mut data = vec![1, 2];
+        let out_of_bounds = data
+            .try_apply_change(&Change::update(keypath![Vec<usize>: [2]], 3))
+            .unwrap_err();
+        let deserialization = data
+            .try_apply_change(&ChangeOf::Update {
+                key_path: keypath![Vec<usize>: [1]].into(),
+                value: serde_json::json!("two"),
+            })
+            .unwrap_err();
+
+        assert!(out_of_bounds.is_not_found());
+        assert!(KeyPathError::unknown_field::<Directory>("name").is_not_found());
+        assert!(KeyPathError::unknown_variant_or_field::<Shape>("Square", "side").is_not_found());
+        assert!(KeyPathError::UnknownStringKey {
+            key: "a".to_string()
+        }
+        .is_not_found());
+
+        assert!(!deserialization.is_not_found());
+        assert!(!KeyPathError::cannot_delete::<usize>().is_not_found());
+        assert!(!KeyPathError::read_only_field::<Directory>("count").is_not_found());
+        assert!(!KeyPathError::MustMutateVectorWithIndex.is_not_found());
+    }
+
+    #[test]
+    fn splices_a_vector() {
+        let mut data = vec![1, 2, 3];
+        let change = Change::splice(KeyPath::unit(), vec![5, 6], 1, 0);
+
+        data.apply_change(&change);
+
+        assert_eq!(data, vec![1, 5, 6, 2, 3]);
+    }
+
+    #[test]
+    fn infers_the_root_of_unit_keypaths_from_values() {
+        // Nothing else names the root, which `KeyPath::unit` would need a turbofish for
+        let patch = Change::splice(KeyPath::unit_vec(), vec![5, 6], 1, 0).as_patch();
+        let mut data = vec![1, 2, 3];
+
+        data.patch_keypath(&[], patch).unwrap();
+
+        assert_eq!(data, vec![1, 5, 6, 2, 3]);
+
+        let patch = Change::update(KeyPath::identity(), vec![7]).as_patch();
+
+        data.patch_keypath(&[], patch).unwrap();
+
+        assert_eq!(data, vec![7]);
+    }
+
+    #[test]
+    fn applies_a_change_within_the_size_budget() {
+        let mut data = vec!["a".to_string(), "b".to_string()];
+        let change = Change::update(keypath![Vec<String>: [1]], "hello".to_string());
+
+        assert_eq!(change.value_size(), 7);
+
+        data.apply_change_bounded(&change, 7)
+            .expect("Failed to apply change");
+
+        assert_eq!(data, vec!["a".to_string(), "hello".to_string()]);
+    }
+
+    #[test]
+    fn rejects_a_change_over_the_size_budget() {
+        let mut data = vec!["a".to_string(), "b".to_string()];
+        let change = Change::splice(KeyPath::unit(), vec!["hello".to_string(); 2], 0, 0);
+
+        let result = data.apply_change_bounded(&change, 8);
+
+        assert!(matches!(
+            result,
+            Err(KeyPathError::ValueTooLarge { size: 17, max: 8 })
+        ));
+        assert_eq!(data, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[derive(PartialEq, Debug, Clone, Serialize, Deserialize, Navigable, KeyPathGet)]
+    struct SimpleStruct {
+        first_field: usize,
+        second_field: String,
+        third_field: Vec<String>,
+    }
+
+    impl FromPatchValue for SimpleStruct {
+        fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+            serde_json::from_value(value)
+        }
+    }
+
+    // This impl will be generated by a derive macro
+    impl KeyPathMutable for SimpleStruct {
+        fn patch_keypath(
+            &mut self,
+            keys: &[KeyPathElement],
+            patch: Patch,
+        ) -> Result<(), KeyPathError> {
+            if keys.is_empty() {
+                return if let Patch::Update { value, .. } = patch {
+                    *self = serde_json::from_value(value)
+                        .map_err(KeyPathError::from_deserialization_error::<SimpleStruct>)?;
+                    Ok(())
+                } else {
+                    Err(KeyPathError::splice_on_non_list::<SimpleStruct>())
+                };
+            }
+
+            let KeyPathElement::Field { key } = &keys[0] else {
+                return Err(KeyPathError::must_mutate_struct_with_field::<SimpleStruct>());
+            };
+
+            match key.as_ref() {
+                // The match arms will be generated by the macro based on the struct fields
+                "first_field" => self.first_field.patch_keypath(&keys[1..], patch),
+                "different_field" => self.second_field.patch_keypath(&keys[1..], patch),
+                "third_field" => self.third_field.patch_keypath(&keys[1..], patch),
+                _ => Err(KeyPathError::unknown_field::<SimpleStruct>(key)),
+            }
+        }
+
+        fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+
+            let KeyPathElement::Field { key } = &keys[0] else {
+                return Err(KeyPathError::must_mutate_struct_with_field::<SimpleStruct>());
+            };
+
+            match key.as_ref() {
+                "first_field" => usize::validate_path(&keys[1..]),
+                "different_field" => String::validate_path(&keys[1..]),
+                "third_field" => Vec::<String>::validate_path(&keys[1..]),
+                _ => Err(KeyPathError::unknown_field::<SimpleStruct>(key)),
+            }
+        }
+    }
+
+    #[test]
+    fn updates_a_struct_field() {
+        let mut data = SimpleStruct {
+            first_field: 1,
+            second_field: "hello".to_string(),
+            third_field: vec![],
+        };
+        let change = Change::update(keypath![SimpleStruct: first_field], 5);
+
+        data.apply_change(&change);
+
+        assert_eq!(
+            data,
+            SimpleStruct {
+                first_field: 5,
+                second_field: "hello".to_string(),
+                third_field: vec![],
+            }
+        );
+    }
+
+    // e.g. a change received from a binding built against a different version of the type
+    fn mismatched_change() -> ChangeOf<SimpleStruct> {
+        let path = KeyPathFrom::<SimpleStruct>::dangerously_construct_from_path(vec![
+            KeyPathElement::Field {
+                key: "first_field".into(),
+            },
+        ]);
+
+        Change::update(path.downcast::<String>(), "five".to_string())
+    }
+
+    #[test]
+    fn try_apply_change_returns_errors_instead_of_panicking() {
+        let original = SimpleStruct {
+            first_field: 1,
+            second_field: "hello".to_string(),
+            third_field: vec![],
+        };
+        let mut data = original.clone();
+
+        let result = data.try_apply_change(&mismatched_change());
+
+        assert!(matches!(
+            result,
+            Err(KeyPathError::DeserializationError { .. })
+        ));
+        assert_eq!(data, original);
+    }
+
+    #[test]
+    #[should_panic(expected = "patch failure at .first_field")]
+    fn apply_change_panics_on_errors() {
+        let mut data = SimpleStruct {
+            first_field: 1,
+            second_field: "hello".to_string(),
+            third_field: vec![],
+        };
+
+        data.apply_change(&mismatched_change());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn updates_through_a_locked_arc() {
+        let data = Arc::new(Mutex::new(SimpleStruct {
+            first_field: 1,
+            second_field: "hello".to_string(),
+            third_field: vec![],
+        }));
+
+        let shared = Arc::clone(&data);
+        std::thread::spawn(move || {
+            let change = Change::update(keypath![SimpleStruct: first_field], 5);
+            apply_change_locked(&shared, &change)
+        })
+        .join()
+        .unwrap()
+        .expect("Failed to apply change");
+
+        let shared = Arc::clone(&data);
+        std::thread::spawn(move || {
+            let change = Change::splice(
+                keypath![SimpleStruct: third_field],
+                vec!["bye".to_string()],
+                0,
+                0,
+            );
+            apply_change_locked(&shared, &change)
+        })
+        .join()
+        .unwrap()
+        .expect("Failed to apply change");
+
+        assert_eq!(
+            *data.lock().unwrap(),
+            SimpleStruct {
+                first_field: 5,
+                second_field: "hello".to_string(),
+                third_field: vec!["bye".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn applies_a_change_set() {
+        let mut data = vec![1, 2, 3];
+        let set: ChangeSet<Vec<usize>> = vec![
+            Change::update(keypath![Vec<usize>: [0]], 5),
+            Change::splice(KeyPath::unit(), vec![4], 3, 0),
+        ]
+        .into();
+
+        data.apply_change_set(&set)
+            .expect("Failed to apply change set");
+
+        assert_eq!(data, vec![5, 2, 3, 4]);
+    }
+
+    #[test]
+    fn skips_redundant_updates_in_a_change_set() {
+        let mut data = vec![1, 2, 3];
+        let set: ChangeSet<Vec<usize>> = vec![
+            Change::update(keypath![Vec<usize>: [0]], 5),
+            Change::update(keypath![Vec<usize>: [1]], 2),
+        ]
+        .into();
+
+        let effective = data
+            .apply_change_set_dedup(&set)
+            .expect("Failed to apply change set");
+
+        assert_eq!(effective, 1);
+        assert_eq!(data, vec![5, 2, 3]);
+    }
+
+    #[test]
+    fn compares_only_the_value_at_the_keypath() {
+        let mut data = Extracted {
+            items: vec![Extractable::Pair(1, Unserializable)],
+            other: Unserializable,
+        };
+        let pair = |value| ChangeOf::Update {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                KeyPathElement::Field {
+                    key: "items".into(),
+                },
+                KeyPathElement::Index { key: 0 },
+                KeyPathElement::Variant {
+                    key: "Pair".into(),
+                    tag: crate::VariantTagType::External,
+                },
+                KeyPathElement::Field { key: "0".into() },
+            ]),
+            value: serde_json::json!(value),
+        };
+
+        assert!(!data.apply_change_if_changed(&pair(1)).unwrap());
+        assert!(data.apply_change_if_changed(&pair(2)).unwrap());
+        assert_eq!(data.items, vec![Extractable::Pair(2, Unserializable)]);
+    }
+
+    #[test]
+    fn deleting_a_missing_entry_changes_nothing() {
+        let mut data = directory();
+        let delete = |name: &str| Change::delete(keypath![Directory: by_name[name.to_string()]]);
+
+        assert!(!data.apply_change_if_changed(&delete("Cid")).unwrap());
+        assert!(data.apply_change_if_changed(&delete("Bob")).unwrap());
+    }
+
+    #[test]
+    fn updates_through_a_refcell() {
+        let data = RefCell::new(vec![1, 2, 3]);
+        let change = Change::update(keypath![Vec<usize>: [0]], 5);
+
+        apply_change_refcell(&data, &change).expect("Failed to apply change");
+
+        assert_eq!(*data.borrow(), vec![5, 2, 3]);
+    }
+
+    #[test]
+    fn refuses_to_update_a_borrowed_refcell() {
+        let data = RefCell::new(vec![1, 2, 3]);
+        let change = Change::update(keypath![Vec<usize>: [0]], 5);
+
+        let borrowed = data.borrow();
+        let result = apply_change_refcell(&data, &change);
+
+        assert!(matches!(result, Err(KeyPathError::AlreadyBorrowed)));
+        assert_eq!(*borrowed, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn splices_through_the_fluent_keypath_api() {
+        let mut data = SimpleStruct {
+            first_field: 1,
+            second_field: "hello".to_string(),
+            third_field: vec!["a".to_string()],
+        };
+
+        let change = keypath![SimpleStruct: third_field].splice(vec!["b".to_string()], 0, 0);
+
+        assert_eq!(
+            change,
+            Change::splice(
+                keypath![SimpleStruct: third_field],
+                vec!["b".to_string()],
+                0,
+                0
+            )
+        );
+
+        data.apply_change(&change);
+
+        assert_eq!(data.third_field, vec!["b".to_string(), "a".to_string()]);
+    }
+
+    #[test]
+    fn panics_with_the_error_and_path() {
+        let mut data = SimpleStruct {
+            first_field: 1,
+            second_field: "hello".to_string(),
+            third_field: vec![],
+        };
+        let change = Change::update(keypath![SimpleStruct: second_field], "bye".to_string());
+        let error = data.try_apply_change(&change).unwrap_err();
+        assert!(matches!(error, KeyPathError::UnknownField { .. }));
+
+        let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            data.apply_change(&change);
+        }))
+        .expect_err("Expected the change to panic");
+        let message = panic.downcast_ref::<String>().expect("Expected a message");
+
+        assert_eq!(*message, format!("patch failure at .second_field: {error}"));
+    }
+
+    #[test]
+    fn updates_inside_a_struct_field() {
+        let mut data = SimpleStruct {
+            first_field: 1,
+            second_field: "hello".to_string(),
+            third_field: vec!["one".to_string(), "two".to_string()],
+        };
+        let change = Change::update(keypath![SimpleStruct: third_field[1]], "three".to_string());
+
+        data.apply_change(&change);
+
+        assert!(keypath![SimpleStruct: third_field[1]].value_equals(&data, &"three".to_string()));
+        assert!(!keypath![SimpleStruct: third_field[0]].value_equals(&data, &"three".to_string()));
+
+        assert_eq!(
+            data,
+            SimpleStruct {
+                first_field: 1,
+                second_field: "hello".to_string(),
+                third_field: vec!["one".to_string(), "three".to_string()],
+            }
+        );
+    }
+
+    #[derive(PartialEq, Debug, Serialize, Deserialize, KeyPathMutable)]
+    struct Pair(#[keypath_mutable(skip)] usize, String);
+
+    #[test]
+    fn skipping_keeps_tuple_struct_positions() {
+        let mut data = Pair(1, "hello".to_string());
+        let change = Change::update(KeyPath::<Pair, String>::field("1"), "world".to_string());
+
+        data.apply_change(&change);
+
+        assert_eq!(data, Pair(1, "world".to_string()));
+
+        let change = Change::update(KeyPath::<Pair, usize>::field("0"), 2);
+        let result = data.try_apply_change(&change);
+
+        assert!(matches!(
+            result,
+            Err(KeyPathError::UnknownField { field, .. }) if field == "0"
+        ));
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, KeyPathMutable)]
+    #[serde(transparent)]
+    struct Celsius(f64);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Fahrenheit(f64);
+
+    #[test]
+    fn replaces_a_transparent_newtype_from_a_bare_value() {
+        let mut data = Celsius(20.0);
+        let change = ChangeOf::Update {
+            key_path: KeyPath::<Celsius, Celsius>::unit().into(),
+            value: serde_json::json!(21.5),
+        };
+
+        data.apply_change(&change);
+
+        assert_eq!(data, Celsius(21.5));
+
+        let change = Change::update(KeyPath::<Celsius, Celsius>::unit(), Celsius(19.0));
+        assert_eq!(change.value_size(), "19.0".len());
+
+        data.apply_change(&change);
+
+        assert_eq!(data, Celsius(19.0));
+    }
+
+    #[test]
+    fn updates_the_content_of_a_newtype() {
+        let mut data = Celsius(20.0);
+        let change = Change::update(KeyPath::<Celsius, f64>::field("0"), 21.5);
+
+        data.apply_change(&change);
+
+        assert_eq!(data, Celsius(21.5));
+
+        let mut data = Fahrenheit(68.0);
+        let change = Change::update(Fahrenheit::keypaths().0, 70.5);
+
+        data.apply_change(&change);
+
+        assert_eq!(data, Fahrenheit(70.5));
+
+        let change = ChangeOf::Update {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                KeyPathElement::Field { key: "0".into() },
+                KeyPathElement::Field {
+                    key: "degrees".into(),
+                },
+            ]),
+            value: serde_json::json!(1.0),
+        };
+
+        assert!(matches!(
+            data.try_apply_change(&change),
+            Err(KeyPathError::CannotMutatePrimitiveChildren { .. })
+        ));
+    }
+
+    #[derive(PartialEq, Debug, Serialize, Deserialize, Navigable, KeyPathGet)]
+    enum ExhaustingEnum {
+        First(usize),
+        Second { field: String },
+        Third(usize, String),
+        Fourth { field1: usize, field2: String },
+        Fifth(SimpleStruct),
+        Sixth { field: SimpleStruct },
+    }
+
+    impl FromPatchValue for ExhaustingEnum {
+        fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+            serde_json::from_value(value)
+        }
+    }
+
+    // This impl will be generated by a derive macro
+    impl KeyPathMutable for ExhaustingEnum {
+        fn patch_keypath(
+            &mut self,
+            keys: &[KeyPathElement],
+            patch: Patch,
+        ) -> Result<(), KeyPathError> {
+            if keys.is_empty() {
+                return if let Patch::Update { value, .. } = patch {
+                    *self = serde_json::from_value(value)
+                        .map_err(KeyPathError::from_deserialization_error::<ExhaustingEnum>)?;
+                    Ok(())
+                } else {
+                    Err(KeyPathError::splice_on_non_list::<ExhaustingEnum>())
+                };
+            }
+
+            let KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(KeyPathError::must_mutate_enum_with_variant::<ExhaustingEnum>());
+            };
+
+            let KeyPathElement::Field { key: field_name } = &keys[1] else {
+                return Err(KeyPathError::must_mutate_enum_variant_with_field::<
+                    ExhaustingEnum,
+                >(variant));
+            };
+
+            match self {
+                ExhaustingEnum::First(value) if variant == "First" && field_name == "0" => {
+                    value.patch_keypath(&keys[2..], patch)
+                }
+                ExhaustingEnum::Second { field }
+                    if variant == "Second" && field_name == "field" =>
+                {
+                    field.patch_keypath(&keys[2..], patch)
+                }
+                ExhaustingEnum::Third(value1, value2) if variant == "Third" => {
+                    match field_name.as_ref() {
+                        "0" => value1.patch_keypath(&keys[2..], patch),
+                        "1" => value2.patch_keypath(&keys[2..], patch),
+                        _ => Err(KeyPathError::unknown_field::<ExhaustingEnum>(field_name)),
+                    }
+                }
+                ExhaustingEnum::Fourth { field1, field2 } if variant == "Fourth" => {
+                    match field_name.as_ref() {
+                        "field1" => field1.patch_keypath(&keys[2..], patch),
+                        "field2" => field2.patch_keypath(&keys[2..], patch),
+                        _ => Err(KeyPathError::unknown_field::<ExhaustingEnum>(field_name)),
+                    }
+                }
+                ExhaustingEnum::Fifth(value) if variant == "Fifth" && field_name == "0" => {
+                    value.patch_keypath(&keys[1..], patch)
+                }
+                ExhaustingEnum::Sixth { field } if variant == "Sixth" && field_name == "field" => {
+                    field.patch_keypath(&keys[1..], patch)
+                }
+                _ => Err(KeyPathError::unknown_variant_or_field::<ExhaustingEnum>(
+                    variant, field_name,
+                )),
+            }
+        }
+
+        fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+
+            let KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(KeyPathError::must_mutate_enum_with_variant::<ExhaustingEnum>());
+            };
+
+            let Some(KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(KeyPathError::must_mutate_enum_variant_with_field::<
+                    ExhaustingEnum,
+                >(variant));
+            };
+
+            match (variant.as_ref(), field_name.as_ref()) {
+                ("First", "0") | ("Third", "0") | ("Fourth", "field1") => {
+                    usize::validate_path(&keys[2..])
+                }
+                ("Second", "field") | ("Third", "1") | ("Fourth", "field2") => {
+                    String::validate_path(&keys[2..])
+                }
+                ("Fifth", "0") | ("Sixth", "field") => SimpleStruct::validate_path(&keys[2..]),
+                _ => Err(KeyPathError::unknown_variant_or_field::<ExhaustingEnum>(
+                    variant, field_name,
+                )),
+            }
+        }
+    }
+
+    #[test]
+    fn updates_in_an_enum_variant_tuple() {
+        let mut data = ExhaustingEnum::First(1);
+        let change = Change::update(keypath![ExhaustingEnum: First.0], 5);
+
+        data.apply_change(&change);
+
+        assert!(keypath![ExhaustingEnum: First.0].value_equals(&data, &5));
+
+        assert_eq!(data, ExhaustingEnum::First(5));
+    }
+
+    #[test]
+    fn updates_in_an_enum_variant_struct() {
+        let mut data = ExhaustingEnum::Second {
+            field: "hello".to_string(),
+        };
+        let change = Change::update(keypath![ExhaustingEnum: Second.field], "world".to_string());
+
+        data.apply_change(&change);
+
+        assert!(keypath![ExhaustingEnum: Second.field].value_equals(&data, &"world".to_string()));
+        assert!(!keypath![ExhaustingEnum: Fourth.field2].value_equals(&data, &"world".to_string()));
+
+        assert_eq!(
+            data,
+            ExhaustingEnum::Second {
+                field: "world".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn updates_inside_of_a_some() {
+        let mut data = Some(SimpleStruct {
+            first_field: 1,
+            second_field: "Hello".to_string(),
+            third_field: vec![],
+        });
+        let change = Change::update(keypath![Option<SimpleStruct>: Some.first_field], 2);
+
+        data.apply_change(&change);
+
+        assert_eq!(
+            data,
+            Some(SimpleStruct {
+                first_field: 2,
+                second_field: "Hello".to_string(),
+                third_field: vec![]
+            })
+        );
+    }
+
+    #[test]
+    fn replaces_a_some_with_a_some() {
+        let mut data = Some(SimpleStruct {
+            first_field: 1,
+            second_field: "Hello".to_string(),
+            third_field: vec![],
+        });
+
+        let new_value = SimpleStruct {
+            first_field: 2,
+            second_field: "Bye!".to_string(),
+            third_field: vec!["What".to_string()],
+        };
+
+        let change = Change::update(keypath![Option<SimpleStruct>: Some], new_value.clone());
+
+        data.apply_change(&change);
+
+        assert_eq!(data, Some(new_value));
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Navigable)]
+    struct StructWithOption {
+        field: Option<usize>,
+    }
+
+    impl FromPatchValue for StructWithOption {
+        fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+            serde_json::from_value(value)
+        }
+    }
+
+    // This impl will be generated by a derive macro
+    impl KeyPathMutable for StructWithOption {
+        fn patch_keypath(
+            &mut self,
+            keys: &[KeyPathElement],
+            patch: Patch,
+        ) -> Result<(), KeyPathError> {
+            if keys.is_empty() {
+                return if let Patch::Update { value, .. } = patch {
+                    *self = serde_json::from_value(value)
+                        .map_err(KeyPathError::from_deserialization_error::<StructWithOption>)?;
+                    Ok(())
+                } else {
+                    Err(KeyPathError::splice_on_non_list::<StructWithOption>())
+                };
+            }
+
+            let KeyPathElement::Field { key } = &keys[0] else {
+                return Err(KeyPathError::must_mutate_struct_with_field::<
+                    StructWithOption,
+                >());
+            };
+
+            match key.as_ref() {
+                // The match arms will be generated by the macro based on the struct fields
+                "field" => self.field.patch_keypath(&keys[1..], patch),
+                _ => Err(KeyPathError::unknown_field::<StructWithOption>(key)),
+            }
+        }
+
+        fn validate_path(keys: &[KeyPathElement]) -> Result<(), KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+
+            let KeyPathElement::Field { key } = &keys[0] else {
+                return Err(KeyPathError::must_mutate_struct_with_field::<
+                    StructWithOption,
+                >());
+            };
+
+            match key.as_ref() {
+                "field" => Option::<usize>::validate_path(&keys[1..]),
+                _ => Err(KeyPathError::unknown_field::<StructWithOption>(key)),
+            }
+        }
+    }
+
+    #[test]
+    fn replaces_a_some_with_a_none() {
+        let mut data = StructWithOption { field: Some(3) };
+
+        let change = Change::update(keypath![StructWithOption: field], None);
+
+        data.apply_change(&change);
+
+        assert!(data.field.is_none());
+    }
+
+    #[test]
+    fn replaces_a_none_with_a_none() {
+        let mut data = StructWithOption { field: None };
+
+        let change = Change::update(keypath![StructWithOption: field], None);
+
+        data.apply_change(&change);
+
+        assert!(data.field.is_none());
+    }
+
+    #[test]
+    fn replaces_a_none_with_a_some() {
+        let mut data = StructWithOption { field: None };
+
+        let change = Change::update(keypath![StructWithOption: field], Some(3));
+
+        data.apply_change(&change);
+
+        assert_eq!(data.field, Some(3));
+    }
+
+    #[test]
+    fn updates_a_nested_json_object_key() {
+        let mut data = serde_json::json!({ "outer": { "inner": 1, "other": [1, 2] } });
+        let change = ChangeOf::Update {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                KeyPathElement::Field {
+                    key: "outer".into(),
+                },
+                KeyPathElement::StringKey {
+                    key: "inner".into(),
+                },
+            ]),
+            value: serde_json::json!({ "replaced": true }),
+        };
+
+        data.apply_change(&change);
+
+        assert_eq!(
+            data,
+            serde_json::json!({ "outer": { "inner": { "replaced": true }, "other": [1, 2] } })
+        );
+
+        let change = ChangeOf::Update {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                KeyPathElement::Field {
+                    key: "outer".into(),
+                },
+                KeyPathElement::Field {
+                    key: "added".into(),
+                },
+            ]),
+            value: serde_json::json!("new"),
+        };
+
+        data.apply_change(&change);
+
+        assert_eq!(data["outer"]["added"], serde_json::json!("new"));
+    }
+
+    #[test]
+    fn validates_json_paths_without_variants() {
+        let members = [
+            KeyPathElement::StringKey { key: "a".into() },
+            KeyPathElement::Index { key: 3 },
+            KeyPathElement::Field { key: "b".into() },
+        ];
+        let variant = [KeyPathElement::Variant {
+            key: "Some".into(),
+            tag: crate::VariantTagType::External,
+        }];
+
+        assert!(serde_json::Value::validate_path(&members).is_ok());
+        assert!(matches!(
+            serde_json::Value::validate_path(&variant),
+            Err(KeyPathError::MustMutateMapWithStringKey)
+        ));
+    }
+
+    #[test]
+    fn splices_a_json_array() {
+        let mut data = serde_json::json!({ "items": [{ "tags": ["a", "b", "c"] }] });
+        let change = ChangeOf::Splice {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                KeyPathElement::Field {
+                    key: "items".into(),
+                },
+                KeyPathElement::Index { key: 0 },
+                KeyPathElement::Field { key: "tags".into() },
+            ]),
+            value: vec![serde_json::json!("x"), serde_json::json!("y")],
+            start: 1,
+            replace: 1,
+        };
+
+        data.apply_change(&change);
+
+        assert_eq!(
+            data,
+            serde_json::json!({ "items": [{ "tags": ["a", "x", "y", "c"] }] })
+        );
+    }
+
+    #[test]
+    fn refuses_to_mutate_a_json_array_out_of_bounds() {
+        let mut data = serde_json::json!({ "tags": ["a", "b"] });
+        let splice = |start, replace| ChangeOf::Splice {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![KeyPathElement::Field {
+                key: "tags".into(),
+            }]),
+            value: vec![serde_json::json!("x")],
+            start,
+            replace,
+        };
+        let update = ChangeOf::Update {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                KeyPathElement::Field { key: "tags".into() },
+                KeyPathElement::Index { key: 7 },
+            ]),
+            value: serde_json::json!("x"),
+        };
+
+        assert!(matches!(
+            data.try_apply_change(&splice(5, 0)),
+            Err(KeyPathError::IndexOutOfBounds { index: 5, len: 2 })
+        ));
+        assert!(matches!(
+            data.try_apply_change(&splice(1, 2)),
+            Err(KeyPathError::IndexOutOfBounds { index: 2, len: 2 })
+        ));
+        assert!(matches!(
+            data.try_apply_change(&splice(1, usize::MAX)),
+            Err(KeyPathError::IndexOutOfBounds { index: 2, len: 2 })
+        ));
+        assert!(matches!(
+            data.try_apply_change(&update),
+            Err(KeyPathError::IndexOutOfBounds { index: 7, len: 2 })
+        ));
+        assert_eq!(data, serde_json::json!({ "tags": ["a", "b"] }));
+
+        data.apply_change(&splice(2, 0));
+
+        assert_eq!(data, serde_json::json!({ "tags": ["a", "b", "x"] }));
+    }
+
+    #[test]
+    fn refuses_to_mutate_inside_a_json_scalar() {
+        let mut data = serde_json::json!({ "count": 1 });
+        let change = ChangeOf::Update {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                KeyPathElement::Field {
+                    key: "count".into(),
+                },
+                KeyPathElement::Index { key: 0 },
+            ]),
+            value: serde_json::json!(2),
+        };
+
+        let result = data.try_apply_change(&change);
+
+        assert!(matches!(
+            result,
+            Err(KeyPathError::CannotMutatePrimitiveChildren { .. })
+        ));
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct EventLog {
+        events: VecDeque<usize>,
+    }
+
+    fn event_log() -> EventLog {
+        let mut events = VecDeque::with_capacity(4);
+        // Wrap around so the contents aren't contiguous
+        events.extend([0, 0, 1, 2]);
+        events.drain(..2);
+        events.extend([3, 4]);
+
+        EventLog { events }
+    }
+
+    #[test]
+    fn updates_a_vec_deque_element() {
+        let mut data = event_log();
+        let change = Change::update(keypath![EventLog: events[2]], 7);
+
+        data.apply_change(&change);
+
+        assert_eq!(data.events, [1, 2, 7, 4]);
+    }
+
+    #[test]
+    fn refuses_to_update_a_vec_deque_out_of_bounds() {
+        let mut data = event_log();
+        let change = Change::update(keypath![EventLog: events[4]], 7);
+
+        let result = data.try_apply_change(&change);
+
+        assert!(matches!(
+            result,
+            Err(KeyPathError::IndexOutOfBounds { index: 4, len: 4 })
+        ));
+        assert_eq!(data, event_log());
+    }
+
+    #[test]
+    fn splices_a_vec_deque() {
+        let mut data = event_log();
+        let change = ChangeOf::Splice {
+            key_path: keypath![EventLog: events].into(),
+            value: vec![serde_json::json!(5), serde_json::json!(6)],
+            start: 1,
+            replace: 2,
+        };
+
+        data.apply_change(&change);
+
+        assert_eq!(data.events, [1, 5, 6, 4]);
+
+        let change = ChangeOf::Splice {
+            key_path: keypath![EventLog: events].into(),
+            value: vec![serde_json::json!(8)],
+            start: 4,
+            replace: 0,
+        };
+
+        data.apply_change(&change);
+
+        assert_eq!(data.events, [1, 5, 6, 4, 8]);
+    }
+
+    #[test]
+    fn refuses_to_splice_a_vec_deque_out_of_bounds() {
+        let mut data = event_log();
+        let splice = |start, replace| ChangeOf::Splice {
+            key_path: keypath![EventLog: events].into(),
+            value: vec![serde_json::json!(5)],
+            start,
+            replace,
+        };
+
+        assert!(matches!(
+            data.try_apply_change(&splice(5, 0)),
+            Err(KeyPathError::IndexOutOfBounds { index: 5, len: 4 })
+        ));
+        assert!(matches!(
+            data.try_apply_change(&splice(3, 2)),
+            Err(KeyPathError::IndexOutOfBounds { index: 4, len: 4 })
+        ));
+        assert!(matches!(
+            data.try_apply_change(&splice(2, usize::MAX)),
+            Err(KeyPathError::IndexOutOfBounds { index: 4, len: 4 })
+        ));
+        assert_eq!(data, event_log());
+    }
+
+    #[test]
+    fn replaces_a_vec_deque() {
+        let mut data = event_log();
+        let change = Change::update(keypath![EventLog: events], VecDeque::from([9]));
+
+        data.apply_change(&change);
+
+        assert_eq!(data.events, [9]);
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    #[serde(rename_all = "camelCase")]
+    struct AutoStruct {
+        number: f32,
+        word: String,
+    }
+
+    #[test]
+    fn macro_derived_on_struct() {
+        let mut data = AutoStruct {
+            number: 3.0,
+            word: "Hello".to_string(),
+        };
+
+        let change = Change::update(keypath![AutoStruct: number], 5.0);
+        data.apply_change(&change);
+
+        let change = Change::update(keypath![AutoStruct: word], "Goodbye!".to_string());
+        data.apply_change(&change);
+
+        assert_eq!(data.number, 5.0);
+        assert_eq!(data.word, "Goodbye!".to_string());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Buckets {
+        buckets: HashMap<String, Vec<usize>>,
+    }
+
+    #[test]
+    fn splices_a_vector_inside_a_hash_map() {
+        let mut data = Buckets {
+            buckets: HashMap::from([("a".to_string(), vec![1, 2, 3])]),
+        };
+        let change = Change::splice(
+            keypath![Buckets: buckets["a".to_string()]],
+            vec![4, 5],
+            1,
+            1,
+        );
+
+        data.apply_change(&change);
+
+        assert_eq!(data.buckets["a"], vec![1, 4, 5, 3]);
+    }
+
+    #[test]
+    fn reports_which_spliced_value_fails_to_deserialize() {
+        let mut data = Buckets {
+            buckets: HashMap::from([("a".to_string(), vec![1, 2, 3])]),
+        };
+        let change = ChangeOf::Splice {
+            key_path: keypath![Buckets: buckets["a".to_string()]].into(),
+            value: vec![serde_json::json!(4), serde_json::json!("five")],
+            start: 0,
+            replace: 0,
+        };
+
+        let result = data.try_apply_change(&change);
+
+        assert!(matches!(
+            result,
+            Err(KeyPathError::SpliceDeserializationError { index: 1, .. })
+        ));
+        assert_eq!(data.buckets["a"], vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn refuses_to_splice_a_missing_hash_map_key() {
+        let mut data = Buckets {
+            buckets: HashMap::from([("a".to_string(), vec![1, 2, 3])]),
+        };
+        let change = Change::splice(
+            keypath![Buckets: buckets["b".to_string()]],
+            vec![4, 5],
+            0,
+            0,
+        );
+
+        let result = data.try_apply_change(&change);
+
+        assert!(matches!(
+            result,
+            Err(KeyPathError::UnknownStringKey { key }) if key == "b"
+        ));
+    }
+
+    #[test]
+    fn refuses_to_splice_maps_with_their_full_type() {
+        let mut data = directory();
+        let splice = |key_path: KeyPathFrom<Directory>| ChangeOf::Splice {
+            key_path,
+            value: vec![],
+            start: 0,
+            replace: 0,
+        };
+
+        let result = data.try_apply_change(&splice(keypath![Directory: by_id].into()));
+
+        assert!(matches!(
+            result,
+            Err(KeyPathError::CannotSpliceType { type_name: name })
+                if name == type_name::<BTreeMap<usize, String>>()
+        ));
+
+        let result = data.try_apply_change(&splice(keypath![Directory: by_name].into()));
+
+        assert!(matches!(
+            result,
+            Err(KeyPathError::CannotSpliceType { type_name: name })
+                if name == type_name::<HashMap<String, usize>>()
+        ));
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Directory {
+        by_id: BTreeMap<usize, String>,
+        by_name: HashMap<String, usize>,
+        nickname: Option<String>,
+        count: usize,
+    }
+
+    fn directory() -> Directory {
+        Directory {
+            by_id: BTreeMap::from([(1, "Ann".to_string()), (2, "Bob".to_string())]),
+            by_name: HashMap::from([("Ann".to_string(), 1), ("Bob".to_string(), 2)]),
+            nickname: Some("dir".to_string()),
+            count: 2,
+        }
+    }
+
+    #[test]
+    fn deletes_map_entries() {
+        let mut data = directory();
+
+        data.apply_change(&Change::delete(keypath![Directory: by_id[1]]));
+        data.apply_change(&Change::delete(
+            keypath![Directory: by_name["Bob".to_string()]],
+        ));
+
+        assert_eq!(data.by_id, BTreeMap::from([(2, "Bob".to_string())]));
+        assert_eq!(data.by_name, HashMap::from([("Ann".to_string(), 1)]));
+    }
+
+    #[test]
+    fn deleting_a_missing_map_entry_does_nothing() {
+        let mut data = directory();
+
+        data.apply_change(&Change::delete(keypath![Directory: by_id[3]]));
+
+        assert_eq!(data, directory());
+    }
+
+    #[test]
+    fn deleting_an_option_sets_it_to_none() {
+        let mut data = directory();
+
+        data.apply_change(&Change::delete(keypath![Directory: nickname]));
+
+        assert_eq!(data.nickname, None);
+    }
+
+    #[test]
+    fn refuses_to_delete_structs_and_primitives() {
+        let mut data = directory();
+
+        let result =
+            data.try_apply_change(&Change::delete(KeyPath::<Directory, Directory>::unit()));
+        assert!(matches!(result, Err(KeyPathError::CannotDelete { .. })));
+
+        let result = data.try_apply_change(&Change::delete(keypath![Directory: count]));
+        assert!(matches!(result, Err(KeyPathError::CannotDelete { .. })));
+
+        let result = data.try_apply_change(&Change::delete(keypath![Directory: by_id]));
+        assert!(matches!(result, Err(KeyPathError::CannotDelete { .. })));
+
+        assert_eq!(data, directory());
+    }
+
+    /// An amount of cents, which patches carry as a decimal string like `"12.34"`
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, KeyPathMutable)]
+    #[keypath_mutable(custom_from_patch_value)]
+    struct Cents(u64);
+
+    impl FromPatchValue for Cents {
+        fn from_patch_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+            use serde::de::Error;
+
+            let decimal = String::deserialize(value)?;
+            let (units, cents) = decimal
+                .split_once('.')
+                .ok_or_else(|| Error::custom("expected a decimal"))?;
+            let parse = |digits: &str| digits.parse::<u64>().map_err(Error::custom);
+
+            Ok(Cents(parse(units)? * 100 + parse(cents)?))
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Invoice {
+        total: Cents,
+    }
+
+    #[test]
+    fn converts_patch_values_with_a_custom_from_patch_value() {
+        let mut data = Invoice { total: Cents(0) };
+
+        let batch = PatchBatch {
+            patches: vec![Patch::Update {
+                key_path: serde_json::json!([{ "type": "field", "key": "total" }]),
+                value: serde_json::json!("12.34"),
+            }],
+        };
+
+        data.apply_patch_batch(&batch).unwrap();
+
+        assert_eq!(data.total, Cents(1234));
+    }
+
+    #[test]
+    fn reports_errors_from_a_custom_from_patch_value() {
+        let mut data = Invoice { total: Cents(0) };
+
+        let result = data.try_apply_change(&Change::update(keypath![Invoice: total], Cents(5)));
+
+        assert!(matches!(
+            result,
+            Err(KeyPathError::DeserializationError { type_name, .. }) if type_name.ends_with("Cents")
+        ));
+        assert_eq!(data.total, Cents(0));
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, KeyPathMutable)]
+    struct Ledger {
+        entries: Vec<Cents>,
+        by_name: HashMap<String, Cents>,
+        tip: Option<Cents>,
+        shared: Arc<Cents>,
+        invoice: Invoice,
+    }
+
+    #[test]
+    fn converts_with_a_custom_from_patch_value_wherever_values_are_created() {
+        let mut data = Ledger {
+            entries: vec![Cents(0)],
+            by_name: HashMap::from([("a".to_string(), Cents(0))]),
+            tip: None,
+            shared: Arc::new(Cents(0)),
+            invoice: Invoice { total: Cents(0) },
+        };
+        let update = |key_path: serde_json::Value, value: serde_json::Value| Patch::Update {
+            key_path,
+            value,
+        };
+        let field = |key: &str| serde_json::json!({ "type": "field", "key": key });
+
+        let batch = PatchBatch {
+            patches: vec![
+                Patch::Splice {
+                    key_path: serde_json::json!([field("entries")]),
+                    value: vec![serde_json::json!("0.01")],
+                    start: 1,
+                    replace: 0,
+                },
+                update(
+                    serde_json::json!([field("by_name"), { "type": "stringKey", "key": "b" }]),
+                    serde_json::json!("0.02"),
+                ),
+                update(serde_json::json!([field("tip")]), serde_json::json!("0.03")),
+                update(
+                    serde_json::json!([field("shared")]),
+                    serde_json::json!("0.04"),
+                ),
+                update(
+                    serde_json::json!([field("invoice")]),
+                    serde_json::json!({ "total": "0.05" }),
+                ),
+            ],
+        };
+
+        data.apply_patch_batch(&batch).unwrap();
+
+        assert_eq!(data.entries, vec![Cents(0), Cents(1)]);
+        assert_eq!(data.by_name["b"], Cents(2));
+        assert_eq!(data.tip, Some(Cents(3)));
+        assert_eq!(*data.shared, Cents(4));
+        assert_eq!(data.invoice, Invoice { total: Cents(5) });
+
+        let whole = serde_json::json!({
+            "entries": ["1.00"],
+            "by_name": { "c": "2.00" },
+            "tip": null,
+            "shared": "3.00",
+            "invoice": { "total": "4.00" },
+        });
+        data.apply_patch_batch(&PatchBatch {
+            patches: vec![update(serde_json::json!([]), whole)],
+        })
+        .unwrap();
+
+        assert_eq!(
+            data,
+            Ledger {
+                entries: vec![Cents(100)],
+                by_name: HashMap::from([("c".to_string(), Cents(200))]),
+                tip: None,
+                shared: Arc::new(Cents(300)),
+                invoice: Invoice { total: Cents(400) },
+            }
+        );
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, KeyPathMutable)]
+    #[serde(rename_all = "camelCase", deny_unknown_fields)]
+    struct Profile {
+        display_name: String,
+        #[serde(alias = "mail")]
+        email: Option<String>,
+        #[serde(default = "Profile::default_age")]
+        age: u8,
+        #[serde(default)]
+        tags: Vec<String>,
+        #[serde(skip_deserializing)]
+        cached: bool,
+        status: Status,
+        ids: Vec<Id>,
+    }
+
+    impl Profile {
+        fn default_age() -> u8 {
+            18
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, KeyPathMutable)]
+    #[serde(tag = "kind", content = "data")]
+    enum Status {
+        Active {
+            since: u32,
+        },
+        Away(String),
+        #[serde(other)]
+        Unknown,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, KeyPathMutable)]
+    #[serde(untagged)]
+    enum Id {
+        Number(u64),
+        Name(String),
+        Pair(u8, u8),
+    }
+
+    #[test]
+    fn derived_from_patch_value_converts_like_serde() {
+        let valid = [
+            serde_json::json!({
+                "displayName": "Ann",
+                "mail": "ann@example.com",
+                "age": 30,
+                "tags": ["a"],
+                "status": { "kind": "Active", "data": { "since": 3 } },
+                "ids": [1, "one", [1, 2]],
+            }),
+            serde_json::json!({
+                "displayName": "Bob",
+                "status": { "kind": "Away", "data": "lunch" },
+                "ids": [],
+            }),
+            serde_json::json!({ "displayName": "Cy", "status": { "kind": "Gone" }, "ids": [] }),
+        ];
+        let invalid = [
+            serde_json::json!({ "status": { "kind": "Gone" }, "ids": [] }),
+            serde_json::json!({ "displayName": "Di", "status": { "kind": "Gone" }, "ids": [], "extra": 1 }),
+            serde_json::json!({ "displayName": "Ed", "status": { "kind": "Gone" }, "ids": [], "cached": true }),
+            serde_json::json!({ "displayName": "Flo", "status": { "kind": "Active" }, "ids": [] }),
+            serde_json::json!({ "displayName": "Gus", "status": { "kind": "Gone" }, "ids": [true] }),
+            serde_json::json!(["Hal"]),
+        ];
+
+        for value in valid {
+            assert_eq!(
+                Profile::from_patch_value(value.clone()).unwrap(),
+                serde_json::from_value::<Profile>(value).unwrap()
+            );
+        }
+
+        for value in invalid {
+            assert!(Profile::from_patch_value(value.clone()).is_err(), "{value}");
+            assert!(
+                serde_json::from_value::<Profile>(value.clone()).is_err(),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn applies_a_sequence_of_changes() {
+        let mut data = directory();
+
+        let result = data.apply_changes(vec![
+            Change::update(keypath![Directory: by_id[3]], "Cat".to_string()),
+            Change::delete(keypath![Directory: by_id[1]]),
+            Change::update(keypath![Directory: count], 3),
+        ]);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            data.by_id,
+            BTreeMap::from([(2, "Bob".to_string()), (3, "Cat".to_string())])
+        );
+        assert_eq!(data.count, 3);
+    }
+
+    #[test]
+    fn stops_applying_a_sequence_at_the_first_error() {
+        let mut data = directory();
+
+        let result = data.apply_changes([
+            Change::update(keypath![Directory: count], 3),
+            Change::delete(keypath![Directory: count]),
+            Change::delete(keypath![Directory: nickname]),
+        ]);
+
+        assert!(matches!(result, Err(KeyPathError::CannotDelete { .. })));
+        assert_eq!(data.count, 3);
+        assert_eq!(data.nickname, Some("dir".to_string()));
+    }
+
+    #[test]
+    fn deletes_a_json_object_member() {
+        let mut data = serde_json::json!({ "a": 1, "b": 2 });
+        let change = ChangeOf::Delete {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                KeyPathElement::StringKey { key: "b".into() },
+            ]),
+        };
+
+        data.apply_change(&change);
+
+        assert_eq!(data, serde_json::json!({ "a": 1 }));
+    }
+
+    #[test]
+    fn extracts_the_updated_value() {
+        let mut data = AutoStruct {
+            number: 3.0,
+            word: "Hello".to_string(),
+        };
+        let change = Change::update(keypath![AutoStruct: word], "Goodbye!".to_string());
+
+        let extracted = data
+            .apply_and_extract(&change)
+            .expect("Failed to apply change");
+
+        assert_eq!(extracted, Some(serde_json::json!("Goodbye!")));
+        assert_eq!(data.word, "Goodbye!".to_string());
+    }
+
+    #[test]
+    fn extracts_the_spliced_list() {
+        let mut data = vec![1, 2, 3];
+        let change = Change::splice(KeyPath::unit(), vec![5, 6], 1, 1);
+
+        let extracted = data
+            .apply_and_extract(&change)
+            .expect("Failed to apply change");
+
+        assert_eq!(extracted, Some(serde_json::json!([1, 5, 6, 3])));
+        assert_eq!(data, vec![1, 5, 6, 3]);
+    }
+
+    #[test]
+    fn extracts_nothing_after_deleting_an_entry() {
+        let mut data = HashMap::from([("a".to_string(), 1), ("b".to_string(), 2)]);
+        let change = ChangeOf::Delete {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                KeyPathElement::StringKey { key: "b".into() },
+            ]),
+        };
+
+        let extracted = data
+            .apply_and_extract(&change)
+            .expect("Failed to apply change");
+
+        assert_eq!(extracted, None);
+        assert_eq!(data, HashMap::from([("a".to_string(), 1)]));
+    }
+
+    #[test]
+    fn extracts_map_entries_with_non_string_keys() {
+        let mut data = directory();
+        let change = Change::update(keypath![Directory: by_id[2]], "Cid".to_string());
+
+        let extracted = data
+            .apply_and_extract(&change)
+            .expect("Failed to apply change");
+
+        assert_eq!(extracted, Some(serde_json::json!("Cid")));
+    }
+
+    #[derive(Debug, Clone, PartialEq, Deserialize, Navigable, KeyPathMutable)]
+    struct Rejected {
+        reason: String,
+    }
+
+    impl Serialize for Rejected {
+        fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("rejected"))
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct WithRejected {
+        count: usize,
+        rejected: Rejected,
+    }
+
+    #[test]
+    fn fails_to_extract_a_value_which_cant_be_serialized() {
+        let mut data = WithRejected {
+            count: 1,
+            rejected: Rejected {
+                reason: "private".to_string(),
+            },
+        };
+        let change = ChangeOf::Update {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![KeyPathElement::Field {
+                key: "rejected".into(),
+            }]),
+            value: serde_json::json!({ "reason": "secret" }),
+        };
+
+        assert!(matches!(
+            data.apply_and_extract(&change),
+            Err(KeyPathError::SerializationError { .. })
+        ));
+    }
+
+    #[test]
+    fn fails_to_compare_a_value_which_cant_be_serialized() {
+        let mut data = WithRejected {
+            count: 1,
+            rejected: Rejected {
+                reason: "private".to_string(),
+            },
+        };
+        let change = ChangeOf::Update {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![KeyPathElement::Field {
+                key: "rejected".into(),
+            }]),
+            value: serde_json::json!({ "reason": "secret" }),
+        };
+
+        assert!(matches!(
+            data.apply_change_if_changed(&change),
+            Err(KeyPathError::SerializationError { .. })
+        ));
+        assert_eq!(data.rejected.reason, "private");
+    }
+
+    #[derive(Debug, Clone, PartialEq, Deserialize, KeyPathMutable)]
+    struct Unserializable;
+
+    impl Serialize for Unserializable {
+        fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+            panic!("serialized a value which isn't at the keypath")
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, KeyPathMutable)]
+    enum Extractable {
+        Pair(usize, Unserializable),
+        Named { value: usize, other: Unserializable },
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, KeyPathMutable)]
+    struct Extracted {
+        items: Vec<Extractable>,
+        other: Unserializable,
+    }
+
+    #[test]
+    fn extracts_only_the_changed_value() {
+        let mut data = Extracted {
+            items: vec![
+                Extractable::Pair(1, Unserializable),
+                Extractable::Named {
+                    value: 2,
+                    other: Unserializable,
+                },
+            ],
+            other: Unserializable,
+        };
+        let pair = ChangeOf::Update {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                KeyPathElement::Field {
+                    key: "items".into(),
+                },
+                KeyPathElement::Index { key: 0 },
+                KeyPathElement::Variant {
+                    key: "Pair".into(),
+                    tag: crate::VariantTagType::External,
+                },
+                KeyPathElement::Field { key: "0".into() },
+            ]),
+            value: serde_json::json!(3),
+        };
+        let named = ChangeOf::Update {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                KeyPathElement::Field {
+                    key: "items".into(),
+                },
+                KeyPathElement::Index { key: 1 },
+                KeyPathElement::Variant {
+                    key: "Named".into(),
+                    tag: crate::VariantTagType::External,
+                },
+                KeyPathElement::Field {
+                    key: "value".into(),
+                },
+            ]),
+            value: serde_json::json!(4),
+        };
+
+        assert_eq!(
+            data.apply_and_extract(&pair).unwrap(),
+            Some(serde_json::json!(3))
+        );
+        assert_eq!(
+            data.apply_and_extract(&named).unwrap(),
+            Some(serde_json::json!(4))
+        );
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Node {
+        value: usize,
+        next: Option<Box<Node>>,
+    }
+
+    fn linked_list() -> Node {
+        Node {
+            value: 1,
+            next: Some(Box::new(Node {
+                value: 2,
+                next: Some(Box::new(Node {
+                    value: 3,
+                    next: None,
+                })),
+            })),
+        }
+    }
+
+    #[test]
+    fn updates_inside_a_boxed_option_two_nodes_deep() {
+        let mut data = linked_list();
+        let change = Change::update(keypath![Node: next.Some.next.Some.value], 5);
+
+        data.apply_change(&change);
+
+        let third = data.next.as_ref().unwrap().next.as_ref().unwrap();
+        assert_eq!(third.value, 5);
+        assert_changed_only(&linked_list(), &data, &[change.key_path().clone()]);
+    }
+
+    #[test]
+    fn replaces_a_boxed_option_with_none() {
+        let mut data = linked_list();
+        let change = Change::update(keypath![Node: next.Some.next], None);
+
+        data.apply_change(&change);
+
+        assert_eq!(data.next.as_ref().unwrap().next, None);
+    }
+
+    #[test]
+    fn replaces_a_boxed_option_with_some() {
+        let mut data = linked_list();
+        let new_node = Some(Box::new(Node {
+            value: 4,
+            next: None,
+        }));
+        let change = Change::update(keypath![Node: next.Some.next.Some.next], new_node.clone());
+
+        data.apply_change(&change);
+
+        let third = data.next.as_ref().unwrap().next.as_ref().unwrap();
+        assert_eq!(third.next, new_node);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Transform {
+        matrix: [f64; 16],
+    }
+
+    fn identity() -> Transform {
+        let mut matrix = [0.0; 16];
+        for i in 0..4 {
+            matrix[i * 5] = 1.0;
+        }
+
+        Transform { matrix }
+    }
+
+    #[test]
+    fn updates_an_array_cell() {
+        let mut data = identity();
+        let change = Change::update(keypath![Transform: matrix[3]], 2.5);
+
+        data.apply_change(&change);
+
+        assert_eq!(data.matrix[3], 2.5);
+        assert_eq!(data.matrix[0], 1.0);
+    }
+
+    #[test]
+    fn replaces_a_whole_array() {
+        let mut data = identity();
+        let change = Change::update(keypath![Transform: matrix], [2.0; 16]);
+
+        data.apply_change(&change);
+
+        assert_eq!(data.matrix, [2.0; 16]);
+
+        let change = ChangeOf::Update {
+            key_path: keypath![Transform: matrix].into(),
+            value: serde_json::json!([1.0, 2.0]),
+        };
+
+        let result = data.try_apply_change(&change);
+
+        assert!(matches!(
+            result,
+            Err(KeyPathError::DeserializationError { .. })
+        ));
+        assert_eq!(data.matrix, [2.0; 16]);
+    }
+
+    #[test]
+    fn refuses_to_update_an_array_out_of_bounds() {
+        let mut data = identity();
+        let change = Change::update(keypath![Transform: matrix[16]], 2.5);
+
+        let result = data.try_apply_change(&change);
+
+        assert!(matches!(
+            result,
+            Err(KeyPathError::IndexOutOfBounds { index: 16, len: 16 })
+        ));
+        assert!(matches!(
+            Transform::validate_path(&change.key_path().path),
+            Err(KeyPathError::IndexOutOfBounds { index: 16, len: 16 })
+        ));
+    }
+
+    #[test]
+    fn refuses_to_splice_an_array() {
+        let mut data = identity();
+        let change = ChangeOf::Splice {
+            key_path: keypath![Transform: matrix].into(),
+            value: vec![serde_json::json!(2.0)],
+            start: 0,
+            replace: 0,
+        };
+
+        let result = data.try_apply_change(&change);
+
+        assert!(matches!(result, Err(KeyPathError::CannotSpliceType { .. })));
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct SharedConfig {
+        arc: Arc<AutoStruct>,
+        rc: Rc<AutoStruct>,
+    }
+
+    #[test]
+    fn updates_shared_values_copy_on_write() {
+        let config = AutoStruct {
+            number: 1.0,
+            word: "one".to_string(),
+        };
+        let arc = Arc::new(config.clone());
+        let rc = Rc::new(config.clone());
+        let mut data = SharedConfig {
+            arc: arc.clone(),
+            rc: rc.clone(),
+        };
+
+        data.apply_change(&Change::update(
+            keypath![SharedConfig: arc.word],
+            "two".to_string(),
+        ));
+        data.apply_change(&Change::update(keypath![SharedConfig: rc.number], 2.0));
+
+        assert_eq!(data.arc.word, "two");
+        assert_eq!(data.rc.number, 2.0);
+        // The other owners still see the old value
+        assert_eq!(*arc, config);
+        assert_eq!(*rc, config);
+    }
+
+    #[test]
+    fn replaces_shared_values() {
+        let config = AutoStruct {
+            number: 1.0,
+            word: "one".to_string(),
+        };
+        let mut data = SharedConfig {
+            arc: Arc::new(config.clone()),
+            rc: Rc::new(config),
+        };
+        let replacement = AutoStruct {
+            number: 3.0,
+            word: "three".to_string(),
+        };
 
-            let KeyPathElement::Field { key } = keys[0] else {
-                return Err(KeyPathError::must_mutate_struct_with_field::<SimpleStruct>());
-            };
+        data.apply_change(&Change::update(
+            keypath![SharedConfig: arc],
+            Arc::new(replacement.clone()),
+        ));
+        data.apply_change(&Change::update(
+            keypath![SharedConfig: rc],
+            Rc::new(replacement.clone()),
+        ));
 
-            match key {
-                // The match arms will be generated by the macro based on the struct fields
-                "first_field" => self.first_field.patch_keypath(&keys[1..], patch),
-                "different_field" => self.second_field.patch_keypath(&keys[1..], patch),
-                "third_field" => self.third_field.patch_keypath(&keys[1..], patch),
-                _ => Err(KeyPathError::unknown_field::<SimpleStruct>(key)),
-            }
+        assert_eq!(*data.arc, replacement);
+        assert_eq!(*data.rc, replacement);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Palette {
+        colors: Box<[String]>,
+    }
+
+    fn palette() -> Palette {
+        Palette {
+            colors: vec!["red".to_string(), "green".to_string()].into_boxed_slice(),
         }
     }
 
     #[test]
-    fn updates_a_struct_field() {
-        let mut data = SimpleStruct {
-            first_field: 1,
-            second_field: "hello".to_string(),
-            third_field: vec![],
-        };
-        let change = Change::update(keypath![SimpleStruct: first_field], 5);
+    fn updates_a_boxed_slice_element() {
+        let mut data = palette();
+        let change = Change::update(keypath![Palette: colors[1]], "blue".to_string());
 
         data.apply_change(&change);
 
+        assert_eq!(&*data.colors, ["red", "blue"]);
+
+        let change = Change::update(keypath![Palette: colors[2]], "blue".to_string());
+
+        assert!(matches!(
+            data.try_apply_change(&change),
+            Err(KeyPathError::IndexOutOfBounds { index: 2, len: 2 })
+        ));
+    }
+
+    #[test]
+    fn refuses_to_splice_a_boxed_slice() {
+        let mut data = palette();
+        let change = ChangeOf::Splice {
+            key_path: keypath![Palette: colors].into(),
+            value: vec![serde_json::json!("blue")],
+            start: 0,
+            replace: 0,
+        };
+
+        let result = data.try_apply_change(&change);
+
+        assert!(matches!(result, Err(KeyPathError::CannotSpliceType { .. })));
+        assert_eq!(data, palette());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Boxed {
+        boxed: Box<AutoStruct>,
+        unboxed: AutoStruct,
+    }
+
+    #[test]
+    fn boxed_fields_behave_like_unboxed_ones() {
+        let auto_struct = || AutoStruct {
+            number: 1.0,
+            word: "one".to_string(),
+        };
+        let mut data = Boxed {
+            boxed: Box::new(auto_struct()),
+            unboxed: auto_struct(),
+        };
+        let replacement = AutoStruct {
+            number: 2.0,
+            word: "two".to_string(),
+        };
+
+        data.apply_change(&Change::update(
+            keypath![Boxed: boxed.word],
+            "three".to_string(),
+        ));
+        data.apply_change(&Change::update(
+            keypath![Boxed: unboxed.word],
+            "three".to_string(),
+        ));
+
+        assert_eq!(*data.boxed, data.unboxed);
+
+        data.apply_change(&Change::update(
+            keypath![Boxed: boxed],
+            Box::new(replacement.clone()),
+        ));
+        data.apply_change(&Change::update(keypath![Boxed: unboxed], replacement));
+
+        assert_eq!(*data.boxed, data.unboxed);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Page<T> {
+        items: Vec<T>,
+        total: usize,
+    }
+
+    #[test]
+    fn updates_a_field_of_a_generic_struct() {
+        let mut data = Page {
+            items: vec![AutoStruct {
+                number: 1.0,
+                word: "one".to_string(),
+            }],
+            total: 1,
+        };
+
+        data.apply_change(&Change::update(
+            keypath![Page<AutoStruct>: items[0].word],
+            "two".to_string(),
+        ));
+        data.apply_change(&Change::update(keypath![Page<AutoStruct>: total], 2));
+
+        assert_eq!(data.items[0].word, "two");
+        assert_eq!(data.total, 2);
+    }
+
+    #[test]
+    fn validates_paths_into_a_generic_struct() {
+        let items = keypath![Page<usize>: items[0]];
+        let missing = KeyPath::<Page<usize>, usize>::field("missing");
+
+        assert!(Page::<usize>::validate_path(&items.path).is_ok());
+        assert!(matches!(
+            Page::<usize>::validate_path(&missing.path),
+            Err(KeyPathError::UnknownField { .. })
+        ));
+    }
+
+    #[test]
+    fn validates_typed_keypaths() {
+        let into_map = keypath![Directory: by_name["Zed".to_string()]];
+        let past_a_leaf = KeyPath::<Directory, usize>::dangerously_construct_from_path(vec![
+            KeyPathElement::Field {
+                key: "count".into(),
+            },
+            KeyPathElement::Index { key: 0 },
+        ]);
+        let missing = KeyPath::<Directory, usize>::field("size");
+
+        assert!(Directory::validate_keypath(&into_map).is_ok());
+        assert!(matches!(
+            Directory::validate_keypath(&past_a_leaf),
+            Err(KeyPathError::CannotMutatePrimitiveChildren { .. })
+        ));
+        assert!(matches!(
+            Directory::validate_keypath(&missing),
+            Err(KeyPathError::UnknownField { field, .. }) if field == "size"
+        ));
+        assert!(matches!(
+            Directory::validate_keypath(&keypath![Directory: by_id]),
+            Ok(())
+        ));
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Pathogen)]
+    #[serde(rename_all = "camelCase")]
+    struct Account {
+        #[keypath_mutable(read_only)]
+        account_id: usize,
+        display_name: String,
+        plan: Plan,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Pathogen)]
+    #[serde(tag = "kind")]
+    enum Plan {
+        Free { trial_days: usize },
+        Paid { seats: usize },
+    }
+
+    #[test]
+    fn derives_navigable_and_mutable_together() {
+        let mut data = Account {
+            account_id: 1,
+            display_name: "Ann".to_string(),
+            plan: Plan::Free { trial_days: 30 },
+        };
+
+        data.apply_change(&Change::update(
+            keypath![Account: display_name],
+            "Bob".to_string(),
+        ));
+        data.apply_change(&Change::update(
+            keypath![Account: plan],
+            Plan::Paid { seats: 2 },
+        ));
+        data.apply_change(&Change::update(keypath![Account: plan.Paid.seats], 3));
+        let result = data.try_apply_change(&Change::update(keypath![Account: account_id], 2));
+
+        assert_eq!(
+            keypath![Account: plan.Paid.seats].path,
+            vec![
+                KeyPathElement::Field { key: "plan".into() },
+                KeyPathElement::Variant {
+                    key: "Paid".into(),
+                    tag: crate::VariantTagType::Internal
+                },
+                KeyPathElement::Field {
+                    key: "seats".into()
+                },
+            ]
+        );
+        assert!(matches!(result, Err(KeyPathError::ReadOnlyField { .. })));
         assert_eq!(
             data,
-            SimpleStruct {
-                first_field: 5,
-                second_field: "hello".to_string(),
-                third_field: vec![],
+            Account {
+                account_id: 1,
+                display_name: "Bob".to_string(),
+                plan: Plan::Paid { seats: 3 },
             }
         );
     }
 
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Layers {
+        outer: Outer,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    enum Outer {
+        Wrapped { inner: Inner },
+        Boxed(Inner),
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    enum Inner {
+        Leaf { field: usize },
+        Named { name: String },
+    }
+
     #[test]
-    fn updates_inside_a_struct_field() {
-        let mut data = SimpleStruct {
-            first_field: 1,
-            second_field: "hello".to_string(),
-            third_field: vec!["one".to_string(), "two".to_string()],
+    fn updates_through_two_chained_enum_variants() {
+        let mut data = Layers {
+            outer: Outer::Wrapped {
+                inner: Inner::Leaf { field: 1 },
+            },
         };
-        let change = Change::update(keypath![SimpleStruct: third_field[1]], "three".to_string());
+        let keypath = keypath![Layers: outer.Wrapped.inner.Leaf.field];
+        let fluent = Layers::keypaths()
+            .outer
+            .fields()
+            .Wrapped
+            .fields()
+            .inner
+            .fields()
+            .Leaf
+            .fields()
+            .field;
 
-        data.apply_change(&change);
+        assert_eq!(keypath.path, fluent.path);
+        assert_eq!(keypath.to_string(), ".outer.Wrapped.inner.Leaf.field");
+
+        data.apply_change(&Change::update(keypath, 2));
 
         assert_eq!(
-            data,
-            SimpleStruct {
-                first_field: 1,
-                second_field: "hello".to_string(),
-                third_field: vec!["one".to_string(), "three".to_string()],
+            data.outer,
+            Outer::Wrapped {
+                inner: Inner::Leaf { field: 2 }
             }
         );
+
+        let result = data.try_apply_change(&Change::update(
+            keypath![Layers: outer.Wrapped.inner.Named.name],
+            "x".to_string(),
+        ));
+
+        assert!(matches!(
+            result,
+            Err(KeyPathError::UnknownVariantOrField { variant, .. }) if variant == "Named"
+        ));
     }
 
-    #[derive(PartialEq, Debug, Serialize, Deserialize, Navigable)]
-    enum ExhaustingEnum {
-        First(usize),
-        Second { field: String },
-        Third(usize, String),
-        Fourth { field1: usize, field2: String },
-        Fifth(SimpleStruct),
-        Sixth { field: SimpleStruct },
+    #[test]
+    fn updates_through_a_tuple_variant_chained_to_a_struct_variant() {
+        let mut data = Layers {
+            outer: Outer::Boxed(Inner::Named {
+                name: "a".to_string(),
+            }),
+        };
+
+        data.apply_change(&Change::update(
+            keypath![Layers: outer.Boxed.0.Named.name],
+            "b".to_string(),
+        ));
+
+        assert_eq!(
+            data.outer,
+            Outer::Boxed(Inner::Named {
+                name: "b".to_string()
+            })
+        );
+
+        data.apply_change(&Change::update(
+            keypath![Layers: outer.Boxed.0],
+            Inner::Leaf { field: 3 },
+        ));
+        data.apply_change(&Change::update(
+            keypath![Layers: outer.Boxed.0.Leaf.field],
+            4,
+        ));
+
+        assert_eq!(data.outer, Outer::Boxed(Inner::Leaf { field: 4 }));
     }
 
-    // This impl will be generated by a derive macro
-    impl KeyPathMutable for ExhaustingEnum {
-        fn patch_keypath(
-            &mut self,
-            keys: &[KeyPathElement],
-            patch: Patch,
-        ) -> Result<(), KeyPathError> {
-            if keys.is_empty() {
-                return if let Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(KeyPathError::from_deserialization_error::<ExhaustingEnum>)?;
-                    Ok(())
-                } else {
-                    Err(KeyPathError::cannot_splice_type::<ExhaustingEnum>())
-                };
-            }
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    enum Outcome<T> {
+        Done { value: T },
+        Failed { reason: String },
+    }
 
-            let KeyPathElement::Variant { key: variant, .. } = keys[0] else {
-                return Err(KeyPathError::must_mutate_enum_with_variant::<ExhaustingEnum>());
-            };
+    #[test]
+    fn updates_a_field_of_a_generic_enum_variant() {
+        let mut data = Outcome::Done { value: 1 };
 
-            let KeyPathElement::Field { key: field_name } = keys[1] else {
-                return Err(KeyPathError::must_mutate_enum_variant_with_field::<
-                    ExhaustingEnum,
-                >(variant));
-            };
+        data.apply_change(&Change::update(keypath![Outcome<usize>: Done.value], 2));
+        assert_eq!(data, Outcome::Done { value: 2 });
+    }
 
-            match self {
-                ExhaustingEnum::First(value) if variant == "First" && field_name == "0" => {
-                    value.patch_keypath(&keys[2..], patch)
-                }
-                ExhaustingEnum::Second { field }
-                    if variant == "Second" && field_name == "field" =>
-                {
-                    field.patch_keypath(&keys[2..], patch)
-                }
-                ExhaustingEnum::Third(value1, value2) if variant == "Third" => match field_name {
-                    "0" => value1.patch_keypath(&keys[2..], patch),
-                    "1" => value2.patch_keypath(&keys[2..], patch),
-                    _ => Err(KeyPathError::unknown_field::<ExhaustingEnum>(field_name)),
-                },
-                ExhaustingEnum::Fourth { field1, field2 } if variant == "Fourth" => {
-                    match field_name {
-                        "field1" => field1.patch_keypath(&keys[2..], patch),
-                        "field2" => field2.patch_keypath(&keys[2..], patch),
-                        _ => Err(KeyPathError::unknown_field::<ExhaustingEnum>(field_name)),
-                    }
-                }
-                ExhaustingEnum::Fifth(value) if variant == "Fifth" && field_name == "0" => {
-                    value.patch_keypath(&keys[1..], patch)
-                }
-                ExhaustingEnum::Sixth { field } if variant == "Sixth" && field_name == "field" => {
-                    field.patch_keypath(&keys[1..], patch)
-                }
-                _ => Err(KeyPathError::unknown_variant_or_field::<ExhaustingEnum>(
-                    variant, field_name,
-                )),
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    #[serde(tag = "type")]
+    enum Shape {
+        Circle { radius: f64 },
+        Rect { width: f64, height: f64 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    #[serde(tag = "t", content = "c")]
+    enum Fill {
+        Solid { color: String },
+        Gradient { from: String, to: String },
+    }
+
+    #[test]
+    fn updates_a_field_of_an_internally_tagged_enum_variant() {
+        let mut data = Shape::Circle { radius: 1.0 };
+
+        data.apply_change(&Change::update(keypath![Shape: Circle.radius], 2.5));
+        assert_eq!(data, Shape::Circle { radius: 2.5 });
+    }
+
+    #[test]
+    fn replaces_an_internally_tagged_enum() {
+        let mut data = Shape::Circle { radius: 1.0 };
+        let change = Change::update(
+            KeyPath::<Shape, Shape>::unit(),
+            Shape::Rect {
+                width: 2.0,
+                height: 3.0,
+            },
+        );
+
+        data.apply_change(&change);
+        assert_eq!(
+            data,
+            Shape::Rect {
+                width: 2.0,
+                height: 3.0
             }
-        }
+        );
     }
 
     #[test]
-    fn updates_in_an_enum_variant_tuple() {
-        let mut data = ExhaustingEnum::First(1);
-        let change = Change::update(keypath![ExhaustingEnum: First.0], 5);
+    fn replaces_an_internally_tagged_enum_variant() {
+        let mut data = Shape::Circle { radius: 1.0 };
+        let rect = Shape::variant_path("Rect").unwrap();
+        let change = Change::update(
+            rect.downcast::<serde_json::Value>(),
+            serde_json::json!({ "width": 2.0, "height": 3.0 }),
+        );
+
+        assert!(Shape::validate_path(&rect.path).is_ok());
 
         data.apply_change(&change);
+        assert_eq!(
+            data,
+            Shape::Rect {
+                width: 2.0,
+                height: 3.0
+            }
+        );
+    }
 
-        assert_eq!(data, ExhaustingEnum::First(5));
+    #[derive(Debug, PartialEq, Serialize, Deserialize, KeyPathMutable)]
+    #[serde(tag = "type")]
+    enum Marker {
+        Empty,
+        Labelled { label: String },
     }
 
     #[test]
-    fn updates_in_an_enum_variant_struct() {
-        let mut data = ExhaustingEnum::Second {
-            field: "hello".to_string(),
+    fn replaces_an_internally_tagged_enum_with_a_variant_without_content() {
+        let mut data = Marker::Labelled {
+            label: "a".to_string(),
         };
-        let change = Change::update(keypath![ExhaustingEnum: Second.field], "world".to_string());
+        let empty =
+            KeyPathFrom::<Marker>::dangerously_construct_from_path(vec![KeyPathElement::Variant {
+                key: "Empty".into(),
+                tag: crate::VariantTagType::Internal,
+            }]);
+
+        data.apply_change(&Change::update(
+            empty.downcast::<serde_json::Value>(),
+            serde_json::Value::Null,
+        ));
+        assert_eq!(data, Marker::Empty);
+    }
+
+    #[test]
+    fn refuses_to_replace_an_unknown_enum_variant() {
+        let unknown =
+            KeyPathFrom::<Shape>::dangerously_construct_from_path(vec![KeyPathElement::Variant {
+                key: "Triangle".into(),
+                tag: crate::VariantTagType::Internal,
+            }]);
+
+        assert!(matches!(
+            Shape::validate_path(&unknown.path),
+            Err(KeyPathError::UnknownVariantOrField { variant, .. }) if variant == "Triangle"
+        ));
+    }
+
+    #[test]
+    fn extracts_fields_of_tagged_enum_variants() {
+        let mut shape = Shape::Circle { radius: 1.0 };
+        let change = Change::update(keypath![Shape: Circle.radius], 2.5);
+
+        assert_eq!(
+            shape.apply_and_extract(&change).unwrap(),
+            Some(serde_json::json!(2.5))
+        );
+
+        let mut fill = Fill::Gradient {
+            from: "red".to_string(),
+            to: "blue".to_string(),
+        };
+        let change = Change::update(keypath![Fill: Gradient.to], "green".to_string());
+
+        assert_eq!(
+            fill.apply_and_extract(&change).unwrap(),
+            Some(serde_json::json!("green"))
+        );
+
+        let change = Change::update(
+            Fill::variant_path("Solid")
+                .unwrap()
+                .downcast::<serde_json::Value>(),
+            serde_json::json!({ "color": "red" }),
+        );
+
+        assert_eq!(
+            fill.apply_and_extract(&change).unwrap(),
+            Some(serde_json::json!({ "color": "red" }))
+        );
+    }
+
+    #[test]
+    fn replaces_variants_of_other_enum_representations() {
+        let mut fill = Fill::Solid {
+            color: "red".to_string(),
+        };
+        let change = Change::update(
+            Fill::variant_path("Gradient")
+                .unwrap()
+                .downcast::<serde_json::Value>(),
+            serde_json::json!({ "from": "red", "to": "blue" }),
+        );
+
+        fill.apply_change(&change);
+        assert_eq!(
+            fill,
+            Fill::Gradient {
+                from: "red".to_string(),
+                to: "blue".to_string()
+            }
+        );
+
+        let mut outcome = Outcome::Done { value: 1 };
+        let change = Change::update(
+            Outcome::<usize>::variant_path("Failed")
+                .unwrap()
+                .downcast::<serde_json::Value>(),
+            serde_json::json!({ "reason": "timeout" }),
+        );
+
+        outcome.apply_change(&change);
+        assert_eq!(
+            outcome,
+            Outcome::Failed {
+                reason: "timeout".to_string()
+            }
+        );
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Geometry {
+        position: (f64, f64),
+        extent: (f64, f64, f64),
+    }
+
+    fn geometry() -> Geometry {
+        Geometry {
+            position: (1.0, 2.0),
+            extent: (3.0, 4.0, 5.0),
+        }
+    }
+
+    #[test]
+    fn updates_a_tuple_item() {
+        let mut data = geometry();
+        let change = Change::update(keypath![Geometry: extent].fields().2, 6.0);
 
         data.apply_change(&change);
 
-        assert_eq!(
-            data,
-            ExhaustingEnum::Second {
-                field: "world".to_string()
-            }
-        );
+        assert_eq!(data.extent, (3.0, 4.0, 6.0));
+        assert_changed_only(&geometry(), &data, &[change.key_path().clone()]);
     }
 
     #[test]
-    fn updates_inside_of_a_some() {
-        let mut data = Some(SimpleStruct {
-            first_field: 1,
-            second_field: "Hello".to_string(),
-            third_field: vec![],
-        });
-        let change = Change::update(keypath![Option<SimpleStruct>: Some.first_field], 2);
+    fn replaces_a_whole_tuple() {
+        let mut data = geometry();
+        let change = Change::update(keypath![Geometry: position], (7.0, 8.0));
 
         data.apply_change(&change);
 
-        assert_eq!(
-            data,
-            Some(SimpleStruct {
-                first_field: 2,
-                second_field: "Hello".to_string(),
-                third_field: vec![]
-            })
-        );
+        assert_eq!(data.position, (7.0, 8.0));
     }
 
     #[test]
-    fn replaces_a_some_with_a_some() {
-        let mut data = Some(SimpleStruct {
-            first_field: 1,
-            second_field: "Hello".to_string(),
-            third_field: vec![],
-        });
-
-        let new_value = SimpleStruct {
-            first_field: 2,
-            second_field: "Bye!".to_string(),
-            third_field: vec!["What".to_string()],
+    fn refuses_to_update_a_tuple_out_of_range() {
+        let mut data = geometry();
+        let change = ChangeOf::Update {
+            key_path: KeyPathFrom::dangerously_construct_from_path(vec![
+                KeyPathElement::Field {
+                    key: "position".into(),
+                },
+                KeyPathElement::Field { key: "2".into() },
+            ]),
+            value: serde_json::json!(1.0),
         };
 
-        let change = Change::update(keypath![Option<SimpleStruct>: Some], new_value.clone());
-
-        data.apply_change(&change);
+        let result = data.try_apply_change(&change);
 
-        assert_eq!(data, Some(new_value));
+        assert!(matches!(
+            result,
+            Err(KeyPathError::UnknownField { field, .. }) if field == "2"
+        ));
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Navigable)]
-    struct StructWithOption {
-        field: Option<usize>,
-    }
+    #[test]
+    fn refuses_to_splice_a_tuple() {
+        let mut data = geometry();
+        let change = ChangeOf::Splice {
+            key_path: keypath![Geometry: position].into(),
+            value: vec![serde_json::json!(1.0)],
+            start: 0,
+            replace: 0,
+        };
 
-    // This impl will be generated by a derive macro
-    impl KeyPathMutable for StructWithOption {
-        fn patch_keypath(
-            &mut self,
-            keys: &[KeyPathElement],
-            patch: Patch,
-        ) -> Result<(), KeyPathError> {
-            if keys.is_empty() {
-                return if let Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(KeyPathError::from_deserialization_error::<StructWithOption>)?;
-                    Ok(())
-                } else {
-                    Err(KeyPathError::cannot_splice_type::<StructWithOption>())
-                };
-            }
+        let result = data.try_apply_change(&change);
 
-            let KeyPathElement::Field { key } = keys[0] else {
-                return Err(KeyPathError::must_mutate_struct_with_field::<
-                    StructWithOption,
-                >());
-            };
+        assert!(matches!(result, Err(KeyPathError::CannotSpliceType { .. })));
+    }
 
-            match key {
-                // The match arms will be generated by the macro based on the struct fields
-                "field" => self.field.patch_keypath(&keys[1..], patch),
-                _ => Err(KeyPathError::unknown_field::<StructWithOption>(key)),
-            }
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct MaybeMaybe {
+        inner: Option<Option<AutoStruct>>,
+    }
+
+    fn maybe_maybe() -> MaybeMaybe {
+        MaybeMaybe {
+            inner: Some(Some(AutoStruct {
+                number: 1.0,
+                word: "one".to_string(),
+            })),
         }
     }
 
     #[test]
-    fn replaces_a_some_with_a_none() {
-        let mut data = StructWithOption { field: Some(3) };
-
-        let change = Change::update(keypath![StructWithOption: field], None);
+    fn updates_a_field_two_options_deep() {
+        let mut data = maybe_maybe();
+        let change = Change::update(
+            keypath![MaybeMaybe: inner.Some.Some.word],
+            "two".to_string(),
+        );
 
         data.apply_change(&change);
 
-        assert!(data.field.is_none());
+        assert_eq!(
+            data.inner,
+            Some(Some(AutoStruct {
+                number: 1.0,
+                word: "two".to_string(),
+            }))
+        );
     }
 
     #[test]
-    fn replaces_a_none_with_a_none() {
-        let mut data = StructWithOption { field: None };
-
-        let change = Change::update(keypath![StructWithOption: field], None);
+    fn replaces_an_inner_option_with_none() {
+        let mut data = maybe_maybe();
+        let change = Change::update(keypath![MaybeMaybe: inner.Some], None::<AutoStruct>);
 
         data.apply_change(&change);
 
-        assert!(data.field.is_none());
+        // `Some` doesn't add a keypath element and `Some(None)` serializes to `null` just like
+        // `None`, so the outer option is replaced. Either way the inner value is gone.
+        assert_eq!(data.inner, None);
+        assert_eq!(
+            serde_json::to_value(&data).unwrap(),
+            serde_json::to_value(MaybeMaybe { inner: Some(None) }).unwrap()
+        );
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Record {
+        #[keypath_mutable(read_only)]
+        id: usize,
+        name: String,
     }
 
     #[test]
-    fn replaces_a_none_with_a_some() {
-        let mut data = StructWithOption { field: None };
+    fn refuses_to_update_a_read_only_field() {
+        let mut data = Record {
+            id: 1,
+            name: "first".to_string(),
+        };
 
-        let change = Change::update(keypath![StructWithOption: field], Some(3));
+        let change = Change::update(keypath![Record: id], 2);
+        let result = data.try_apply_change(&change);
 
-        data.apply_change(&change);
+        assert!(matches!(
+            result,
+            Err(KeyPathError::ReadOnlyField { field: "id", .. })
+        ));
 
-        assert_eq!(data.field, Some(3));
-    }
+        let change = Change::update(keypath![Record: name], "second".to_string());
+        data.apply_change(&change);
 
-    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
-    #[serde(rename_all = "camelCase")]
-    struct AutoStruct {
-        number: f32,
-        word: String,
+        assert_eq!(
+            data,
+            Record {
+                id: 1,
+                name: "second".to_string(),
+            }
+        );
     }
 
     #[test]
-    fn macro_derived_on_struct() {
+    fn refuses_to_splice_a_scalar_field() {
         let mut data = AutoStruct {
             number: 3.0,
             word: "Hello".to_string(),
         };
+        let change = ChangeOf::Splice {
+            key_path: keypath![AutoStruct: number].into(),
+            value: vec![serde_json::json!(5.0)],
+            start: 0,
+            replace: 0,
+        };
 
-        let change = Change::update(keypath![AutoStruct: number], 5.0);
-        data.apply_change(&change);
+        let result = data.try_apply_change(&change);
 
-        let change = Change::update(keypath![AutoStruct: word], "Goodbye!".to_string());
-        data.apply_change(&change);
+        assert!(matches!(
+            result,
+            Err(KeyPathError::SpliceOnNonList { type_name: "f32" })
+        ));
 
-        assert_eq!(data.number, 5.0);
-        assert_eq!(data.word, "Goodbye!".to_string());
+        let change = ChangeOf::Splice {
+            key_path: KeyPath::<AutoStruct, AutoStruct>::unit().into(),
+            value: vec![],
+            start: 0,
+            replace: 0,
+        };
+
+        let result = data.try_apply_change(&change);
+
+        assert!(matches!(
+            result,
+            Err(KeyPathError::SpliceOnNonList { type_name }) if type_name.ends_with("AutoStruct")
+        ));
     }
 
     #[derive(PartialEq, Debug, Clone, Serialize, Deserialize, Navigable, KeyPathMutable)]
@@ -727,6 +3953,34 @@ mod tests {
         assert_eq!(third_field[0], "no".to_string());
     }
 
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, KeyPathMutable)]
+    enum Mode {
+        Light,
+        Dark,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+    struct Settings {
+        mode: Mode,
+    }
+
+    #[test]
+    fn replaces_a_unit_only_enum() {
+        let mut data = Mode::Light;
+        let change = Change::update(KeyPath::<Mode, Mode>::unit(), Mode::Dark);
+
+        data.apply_change(&change);
+
+        assert_eq!(data, Mode::Dark);
+
+        let mut data = Settings { mode: Mode::Dark };
+        let change = Change::update(keypath![Settings: mode], Mode::Light);
+
+        data.apply_change(&change);
+
+        assert_eq!(data, Settings { mode: Mode::Light });
+    }
+
     // Integration test that handles a complex combination of keypath elements.
     // This catches an edge case that we had with encoding concepts, causing `Change::Update` to
     // fail when the concept variant changed. The edge case was triggered because we previously
@@ -772,6 +4026,7 @@ mod tests {
             second: String,
         }
 
+        #[allow(clippy::infallible_try_from)]
         impl TryFrom<CodedEnum> for MyEnum {
             type Error = Infallible;
 
@@ -834,4 +4089,157 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn update_coded_enum_with_direct_dispatch_in_a_hash_map() {
+        #[derive(Clone, Debug, Deserialize, KeyPathMutable, PartialEq, Serialize)]
+        #[serde(from = "CodedEnum", into = "CodedEnum")]
+        #[keypath_mutable(direct_dispatch)]
+        enum MyEnum {
+            First(First),
+            Second(Second),
+        }
+
+        #[allow(non_snake_case, dead_code)]
+        pub struct MyEnumKeyPathReflection<Root> {
+            pub First: (KeyPath<Root, CodedEnum>,),
+            pub Second: (KeyPath<Root, CodedEnum>,),
+        }
+
+        impl Navigable for MyEnum {
+            type Reflection<Root> = MyEnumKeyPathReflection<Root>;
+            fn append_to_keypath<Root>(path: &KeyPath<Root, Self>) -> Self::Reflection<Root>
+            where
+                Root: Sized,
+            {
+                MyEnumKeyPathReflection {
+                    First: (path.appending(&KeyPath::unit()),),
+                    Second: (path.appending(&KeyPath::unit()),),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug, Deserialize, KeyPathMutable, PartialEq, Serialize)]
+        struct First {
+            first: usize,
+        }
+
+        #[derive(Clone, Debug, Deserialize, KeyPathMutable, PartialEq, Serialize)]
+        struct Second {
+            second: String,
+        }
+
+        impl From<CodedEnum> for MyEnum {
+            fn from(value: CodedEnum) -> Self {
+                if value.second.is_empty() {
+                    Self::First(First { first: value.first })
+                } else {
+                    Self::Second(Second {
+                        second: value.second,
+                    })
+                }
+            }
+        }
+
+        #[derive(Clone, Debug, Default, Deserialize, KeyPathMutable, PartialEq, Serialize)]
+        struct CodedEnum {
+            #[serde(default)]
+            first: usize,
+            #[serde(default)]
+            second: String,
+        }
+
+        impl From<MyEnum> for CodedEnum {
+            fn from(value: MyEnum) -> Self {
+                match value {
+                    MyEnum::First(First { first }) => Self {
+                        first,
+                        ..Default::default()
+                    },
+                    MyEnum::Second(Second { second }) => Self {
+                        second,
+                        ..Default::default()
+                    },
+                }
+            }
+        }
+
+        #[derive(Deserialize, KeyPathMutable, Navigable, Serialize)]
+        struct State {
+            enums: HashMap<String, MyEnum>,
+        }
+
+        let mut state = State {
+            enums: HashMap::from([("k".to_string(), MyEnum::First(First { first: 1 }))]),
+        };
+
+        let change = Change::update(
+            keypath![State: enums["k".to_string()]],
+            MyEnum::Second(Second {
+                second: "2".to_owned(),
+            }),
+        );
+
+        state.apply_change(&change);
+
+        assert_eq!(
+            state.enums["k"],
+            MyEnum::Second(Second {
+                second: "2".to_owned(),
+            })
+        );
+    }
+
+    // The same variant switch as above, but through the regular (not direct dispatch) derive
+    #[test]
+    fn switch_enum_variant_with_empty_keypath() {
+        #[derive(Deserialize, KeyPathMutable, Navigable, Serialize)]
+        struct State {
+            enums: Vec<AnotherBigEnum>,
+        }
+
+        let mut data = AnotherBigEnum::OneTuple(1);
+        let change = Change::update(
+            KeyPath::<AnotherBigEnum, AnotherBigEnum>::unit(),
+            AnotherBigEnum::OneStruct {
+                text: "hello".to_string(),
+            },
+        );
+
+        data.apply_change(&change);
+
+        assert_eq!(
+            data,
+            AnotherBigEnum::OneStruct {
+                text: "hello".to_string()
+            }
+        );
+
+        let mut state = State {
+            enums: vec![AnotherBigEnum::TwoTuple(1, "hi".to_string())],
+        };
+        let change = Change::update(
+            keypath![State: enums[0]],
+            AnotherBigEnum::StructWithStruct {
+                field: SimpleStruct {
+                    first_field: 2,
+                    second_field: "there".to_string(),
+                    third_field: vec![],
+                },
+            },
+        );
+
+        state.apply_change(&change);
+
+        assert_eq!(
+            state.enums[0],
+            AnotherBigEnum::StructWithStruct {
+                field: SimpleStruct {
+                    first_field: 2,
+                    second_field: "there".to_string(),
+                    third_field: vec![],
+                },
+            }
+        );
+    }
 }
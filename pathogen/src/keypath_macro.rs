@@ -1,3 +1,8 @@
+/// Build a typed keypath, e.g. `keypath![State: items[0].name]`.
+///
+/// Index access into a `Vec` produces an `Index` element. Index access into a map produces a
+/// `StringKey` element with the key formatted via `Display`, whatever the key type, so
+/// `["Hello"]`, `[42]` and `['a']` become the string keys `Hello`, `42` and `a`.
 #[macro_export]
 macro_rules! keypath {
     // Start of path for Vec<T>
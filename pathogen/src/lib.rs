@@ -1,24 +1,54 @@
+pub mod change_set;
 pub mod key_path;
+pub mod key_path_get;
 pub mod key_path_mutable;
 
+mod diff;
+mod from_patch_value;
+mod json;
 mod keypath_macro;
 mod navigable;
 
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
 pub mod macros {
-    pub use pathogen_macros::{KeyPathMutable, Navigable};
+    pub use pathogen_macros::{KeyPathGet, KeyPathMutable, Navigable, Pathogen};
 }
 
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
+// Not public API, used by the derived impls so they don't depend on the deriving crate's imports
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+    pub use serde_json;
+
+    pub mod from_patch_value {
+        pub use crate::from_patch_value::*;
+    }
+}
 
-pub use key_path::{KeyPath, KeyPathElement, KeyPathFrom, VariantTagType};
-pub use key_path_mutable::{KeyPathError, KeyPathMutable};
-pub use navigable::{IndexNavigable, Navigable};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+pub use change_set::ChangeSet;
+pub use key_path::{
+    HashedKeyPath, KeyPath, KeyPathElement, KeyPathFrom, MapKey, ParseJsonPointerError,
+    ParseKeyPathError, ParseVariantTagTypeError, VariantTagType,
+};
+pub use key_path_get::KeyPathGet;
+#[cfg(feature = "std")]
+pub use key_path_mutable::apply_change_locked;
+pub use key_path_mutable::{apply_change_refcell, FromPatchValue, KeyPathError, KeyPathMutable};
+pub use navigable::{IndexNavigable, Navigable, ValueNavigator};
 
 pub trait AsPatch {
     fn as_patch(&self) -> Patch;
 }
 
 /// Represents a command to the bindings to update their state
+///
+/// Serialized as an object tagged with its `type`, `"splice"`, `"update"` or `"delete"`, with camelCase
+/// fields, and the keypath serialized as an array of `KeyPathElement`s.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(rename_all = "camelCase", tag = "type")]
 pub enum Patch {
@@ -40,6 +70,159 @@ pub enum Patch {
         /// the new value
         value: serde_json::Value,
     },
+    #[serde(rename_all = "camelCase")]
+    Delete {
+        /// the keypath to the map entry to remove or the option to set to `None`
+        key_path: serde_json::Value,
+    },
+}
+
+/// Several patches sent to the bindings in a single message, to be applied in order
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct PatchBatch {
+    pub patches: Vec<Patch>,
+}
+
+impl<Root> From<&ChangeSet<Root>> for PatchBatch {
+    fn from(set: &ChangeSet<Root>) -> Self {
+        Self {
+            patches: set.iter().map(AsPatch::as_patch).collect(),
+        }
+    }
+}
+
+/// The content of a `Patch`, without its keypath
+#[derive(Clone, Debug, PartialEq)]
+pub enum PatchPayload {
+    Update(serde_json::Value),
+    Splice {
+        value: Vec<serde_json::Value>,
+        start: usize,
+        replace: usize,
+    },
+    Delete,
+}
+
+impl Patch {
+    /// Decode the keypath elements and split off the payload, e.g. for FFI code which receives
+    /// a `Patch` without knowing the root type. Without it there are no known names to check the
+    /// path against, so the elements own the names read from the patch.
+    pub fn decode(&self) -> Result<(Vec<KeyPathElement>, PatchPayload), serde_json::Error> {
+        match self {
+            Patch::Update { key_path, value } => Ok((
+                Vec::deserialize(key_path)?,
+                PatchPayload::Update(value.clone()),
+            )),
+            Patch::Splice {
+                key_path,
+                value,
+                start,
+                replace,
+            } => Ok((
+                Vec::deserialize(key_path)?,
+                PatchPayload::Splice {
+                    value: value.clone(),
+                    start: *start,
+                    replace: *replace,
+                },
+            )),
+            Patch::Delete { key_path } => Ok((Vec::deserialize(key_path)?, PatchPayload::Delete)),
+        }
+    }
+
+    /// The equivalent JSON Patch (RFC 6902), an array of operations, e.g. for bindings using a
+    /// JSON Patch library.
+    ///
+    /// An update replaces the value, or adds it to a map. A splice removes the replaced items one
+    /// by one and then adds the new ones. A delete removes a map entry, or replaces an option
+    /// with `null`, which is how serde serializes `None`.
+    ///
+    /// Fails if the keypath doesn't decode, or if it goes through an adjacently tagged variant,
+    /// which has no JSON Pointer without the key of its content.
+    pub fn to_json_patch(&self) -> Result<serde_json::Value, serde_json::Error> {
+        let (keys, payload) = self.decode()?;
+        let path = json::json_pointer(&keys).ok_or_else(|| {
+            serde::de::Error::custom("no JSON Pointer through an adjacently tagged variant")
+        })?;
+        let is_map_entry = matches!(keys.last(), Some(KeyPathElement::StringKey { .. }));
+
+        let operations = match payload {
+            PatchPayload::Update(value) => {
+                let op = if is_map_entry { "add" } else { "replace" };
+
+                vec![serde_json::json!({ "op": op, "path": path, "value": value })]
+            }
+            PatchPayload::Splice {
+                value,
+                start,
+                replace,
+            } => {
+                let item = |index: usize| format!("{path}/{index}");
+                let removes = (0..replace)
+                    .map(|_| serde_json::json!({ "op": "remove", "path": item(start) }));
+                let adds = value.into_iter().enumerate().map(|(offset, value)| {
+                    serde_json::json!({ "op": "add", "path": item(start + offset), "value": value })
+                });
+
+                removes.chain(adds).collect()
+            }
+            PatchPayload::Delete if is_map_entry => {
+                vec![serde_json::json!({ "op": "remove", "path": path })]
+            }
+            PatchPayload::Delete => {
+                vec![serde_json::json!({ "op": "replace", "path": path, "value": null })]
+            }
+        };
+
+        Ok(serde_json::Value::Array(operations))
+    }
+}
+
+/// Why a JSON value isn't a valid `Patch`, more specific than serde's error for bindings which
+/// need to report what they sent wrong
+#[derive(Debug, Error)]
+pub enum PatchDecodeError {
+    #[error("patch is not an object")]
+    NotAnObject,
+    #[error("patch is missing its type")]
+    MissingType,
+    #[error("unknown patch type: {tag}")]
+    UnknownType { tag: serde_json::Value },
+    #[error("{patch_type} patch is missing field {field}")]
+    MissingField {
+        patch_type: &'static str,
+        field: &'static str,
+    },
+    #[error("invalid {patch_type} patch: {error}")]
+    InvalidField {
+        patch_type: &'static str,
+        error: serde_json::Error,
+    },
+}
+
+impl TryFrom<serde_json::Value> for Patch {
+    type Error = PatchDecodeError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        let object = value.as_object().ok_or(PatchDecodeError::NotAnObject)?;
+
+        let (patch_type, fields): (_, &[_]) = match object.get("type") {
+            None => return Err(PatchDecodeError::MissingType),
+            Some(tag) => match tag.as_str() {
+                Some("update") => ("update", &["keyPath", "value"]),
+                Some("splice") => ("splice", &["keyPath", "value", "start", "replace"]),
+                Some("delete") => ("delete", &["keyPath"]),
+                _ => return Err(PatchDecodeError::UnknownType { tag: tag.clone() }),
+            },
+        };
+
+        if let Some(field) = fields.iter().find(|field| !object.contains_key(**field)) {
+            return Err(PatchDecodeError::MissingField { patch_type, field });
+        }
+
+        serde_json::from_value(value)
+            .map_err(|error| PatchDecodeError::InvalidField { patch_type, error })
+    }
 }
 
 /// Represents a change to the state in the core
@@ -62,6 +245,10 @@ pub enum Change<Root, T: Serialize> {
         /// the new value
         value: T,
     },
+    Delete {
+        /// the keypath to the map entry to remove or the option to set to `None`
+        key_path: KeyPath<Root, T>,
+    },
 }
 
 impl<Root, T> Change<Root, T>
@@ -87,6 +274,10 @@ where
         }
         .into()
     }
+
+    pub fn delete(key_path: KeyPath<Root, T>) -> ChangeOf<Root> {
+        Change::Delete { key_path }.into()
+    }
 }
 
 impl<Root, T: Serialize + 'static> AsPatch for Change<Root, T> {
@@ -112,6 +303,10 @@ impl<Root, T: Serialize + 'static> AsPatch for Change<Root, T> {
                 start: *start,
                 replace: *replace,
             },
+            Change::Delete { key_path } => Patch::Delete {
+                key_path: serde_json::to_value(key_path.path.clone())
+                    .expect("Failed to serialize keypath"),
+            },
         }
     }
 }
@@ -128,7 +323,7 @@ impl std::fmt::Debug for dyn AsPatch {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq)]
 pub enum ChangeOf<Root> {
     Splice {
         key_path: KeyPathFrom<Root>,
@@ -140,13 +335,73 @@ pub enum ChangeOf<Root> {
         key_path: KeyPathFrom<Root>,
         value: serde_json::Value,
     },
+    Delete {
+        key_path: KeyPathFrom<Root>,
+    },
 }
 
-impl<Root: 'static> ChangeOf<Root> {
-    pub fn rebase<Base>(&self, base: &KeyPath<Base, Root>) -> ChangeOf<Base> {
+/// Which kind of change a `ChangeOf` is, without its keypath and value
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    Update,
+    Splice,
+    Delete,
+}
+
+// Implement clone manualy in order to not require `Root` to also be Clone
+impl<Root> Clone for ChangeOf<Root> {
+    fn clone(&self) -> Self {
+        match self {
+            ChangeOf::Update { key_path, value } => ChangeOf::Update {
+                key_path: key_path.clone(),
+                value: value.clone(),
+            },
+            ChangeOf::Splice {
+                key_path,
+                value,
+                start,
+                replace,
+            } => ChangeOf::Splice {
+                key_path: key_path.clone(),
+                value: value.clone(),
+                start: *start,
+                replace: *replace,
+            },
+            ChangeOf::Delete { key_path } => ChangeOf::Delete {
+                key_path: key_path.clone(),
+            },
+        }
+    }
+}
+
+impl<Root> ChangeOf<Root> {
+    /// This change with its keypath replaced by `key_path`, keeping the rest
+    fn with_key_path<Other>(&self, key_path: KeyPathFrom<Other>) -> ChangeOf<Other> {
+        match self {
+            ChangeOf::Update { value, .. } => ChangeOf::Update {
+                key_path,
+                value: value.clone(),
+            },
+            ChangeOf::Splice {
+                value,
+                start,
+                replace,
+                ..
+            } => ChangeOf::Splice {
+                key_path,
+                value: value.clone(),
+                start: *start,
+                replace: *replace,
+            },
+            ChangeOf::Delete { .. } => ChangeOf::Delete { key_path },
+        }
+    }
+
+    /// This change with `base` prepended to its keypath, which is assumed to lead to a `Root`
+    pub(crate) fn prepend_elements<Base>(&self, base: &[KeyPathElement]) -> ChangeOf<Base> {
         match self {
             ChangeOf::Update { key_path, value } => ChangeOf::Update {
-                key_path: key_path.prepending(base),
+                key_path: key_path.prepend_elements(base),
                 value: value.clone(),
             },
             ChangeOf::Splice {
@@ -155,13 +410,39 @@ impl<Root: 'static> ChangeOf<Root> {
                 start,
                 replace,
             } => ChangeOf::Splice {
-                key_path: key_path.prepending(base),
+                key_path: key_path.prepend_elements(base),
                 value: value.clone(),
                 start: *start,
                 replace: *replace,
             },
+            ChangeOf::Delete { key_path } => ChangeOf::Delete {
+                key_path: key_path.prepend_elements(base),
+            },
         }
     }
+}
+
+impl<Root: 'static> ChangeOf<Root> {
+    pub fn rebase<Base>(&self, base: &KeyPath<Base, Root>) -> ChangeOf<Base> {
+        self.prepend_elements(&base.path)
+    }
+
+    /// Rebase onto an erased `base`, which is assumed to point at a `Root`.
+    ///
+    /// Unlike `rebase`, this skips the type-level guarantee that `base` actually points at a
+    /// `Root`, so a wrong base produces a change which fails to apply.
+    pub fn rebase_erased<Base>(&self, base: &KeyPathFrom<Base>) -> ChangeOf<Base> {
+        self.prepend_elements(&base.path)
+    }
+
+    /// The inverse of `rebase`: this change relative to the subtree at `prefix`, e.g. to delegate
+    /// it to a reducer for that subtree, or `None` if it doesn't target the subtree. A change at
+    /// `prefix` itself becomes a change at the unit keypath.
+    pub fn strip_prefix<Sub>(&self, prefix: &KeyPath<Root, Sub>) -> Option<ChangeOf<Sub>> {
+        let rest = self.key_path().path.strip_prefix(prefix.path.as_slice())?;
+
+        Some(self.with_key_path(KeyPathFrom::dangerously_construct_from_path(rest.to_vec())))
+    }
 
     pub fn downcast<T: Serialize + DeserializeOwned>(&self) -> Option<Change<Root, T>> {
         match self {
@@ -190,6 +471,9 @@ impl<Root: 'static> ChangeOf<Root> {
                     replace: *replace,
                 })
             }
+            ChangeOf::Delete { key_path } => Some(Change::Delete {
+                key_path: key_path.downcast(),
+            }),
         }
     }
 
@@ -197,6 +481,94 @@ impl<Root: 'static> ChangeOf<Root> {
         match self {
             ChangeOf::Update { key_path, .. } => key_path,
             ChangeOf::Splice { key_path, .. } => key_path,
+            ChangeOf::Delete { key_path } => key_path,
+        }
+    }
+
+    pub fn kind(&self) -> ChangeKind {
+        match self {
+            ChangeOf::Update { .. } => ChangeKind::Update,
+            ChangeOf::Splice { .. } => ChangeKind::Splice,
+            ChangeOf::Delete { .. } => ChangeKind::Delete,
+        }
+    }
+
+    /// The number of values a splice inserts, or `None` if this is not a splice
+    pub fn splice_len(&self) -> Option<usize> {
+        match self {
+            ChangeOf::Splice { value, .. } => Some(value.len()),
+            ChangeOf::Update { .. } | ChangeOf::Delete { .. } => None,
+        }
+    }
+
+    /// The number of existing items a splice replaces, or `None` if this is not a splice
+    pub fn replace_count(&self) -> Option<usize> {
+        match self {
+            ChangeOf::Splice { replace, .. } => Some(*replace),
+            ChangeOf::Update { .. } | ChangeOf::Delete { .. } => None,
+        }
+    }
+
+    /// The length in bytes of the change's value serialized as JSON, 0 for a delete which has
+    /// no value
+    pub fn value_size(&self) -> usize {
+        let serialized = match self {
+            ChangeOf::Update { value, .. } => serde_json::to_vec(value),
+            ChangeOf::Splice { value, .. } => serde_json::to_vec(value),
+            ChangeOf::Delete { .. } => return 0,
+        };
+
+        serialized.expect("Failed to serialize value").len()
+    }
+
+    /// The change which reverts this one, e.g. for undo, given `root` before this change is
+    /// applied to it.
+    ///
+    /// An update or delete is reverted by updating to the old value, or by deleting a map entry
+    /// which the update inserts. A splice is reverted by splicing the items it replaces back in
+    /// place of the ones it inserts. Only the value at the keypath is serialized. Returns `None` if
+    /// the keypath doesn't resolve in `root`, or the value at it can't be serialized.
+    pub fn invert(&self, root: &Root) -> Option<ChangeOf<Root>>
+    where
+        Root: Serialize,
+    {
+        let at = |path: &[KeyPathElement]| json::serialize_at(root, path).ok().flatten();
+        let key_path = self.key_path().clone();
+
+        match self {
+            ChangeOf::Update { .. } | ChangeOf::Delete { .. } => {
+                if let Some(old) = at(&key_path.path) {
+                    return Some(ChangeOf::Update {
+                        key_path,
+                        value: old,
+                    });
+                }
+
+                // A missing map entry, which an update inserts and a delete leaves missing
+                let (KeyPathElement::StringKey { .. }, parent) = key_path.path.split_last()? else {
+                    return None;
+                };
+                at(parent)?.as_object()?;
+
+                Some(ChangeOf::Delete { key_path })
+            }
+            ChangeOf::Splice {
+                value,
+                start,
+                replace,
+                ..
+            } => {
+                let items = at(&key_path.path)?;
+                let items = items.as_array()?;
+                let replaced = items.get(*start..start.checked_add(*replace)?)?;
+
+                Some(ChangeOf::Splice {
+                    key_path,
+                    value: replaced.to_vec(),
+                    start: *start,
+                    replace: value.len(),
+                })
+            }
         }
     }
 }
@@ -221,6 +593,10 @@ impl<Root> AsPatch for ChangeOf<Root> {
                 start: *start,
                 replace: *replace,
             },
+            ChangeOf::Delete { key_path } => Patch::Delete {
+                key_path: serde_json::to_value(key_path.path.clone())
+                    .expect("Failed to serialize keypath"),
+            },
         }
     }
 }
@@ -246,6 +622,9 @@ impl<Root, T: Serialize> From<Change<Root, T>> for ChangeOf<Root> {
                 start,
                 replace,
             },
+            Change::Delete { key_path } => ChangeOf::Delete {
+                key_path: key_path.into(),
+            },
         }
     }
 }
@@ -302,6 +681,67 @@ mod poc {
         assert_eq!(key_path, keypath![Other: different_thing.a]);
     }
 
+    #[test]
+    fn rebasing_changes_onto_an_erased_base() {
+        let change = change_thing(Edit::A(2));
+        let base: KeyPathFrom<Other> = keypath![Other: different_thing].into();
+
+        let rebased = change.rebase_erased(&base);
+
+        assert_eq!(rebased, change.rebase(&keypath![Other: different_thing]));
+        assert_eq!(rebased.key_path(), &keypath![Other: different_thing.a]);
+    }
+
+    #[test]
+    fn stripping_a_prefix_from_changes() {
+        let change = change_thing(Edit::A(2)).rebase(&keypath![Other: thing]);
+
+        let stripped = change.strip_prefix(&keypath![Other: thing]).unwrap();
+
+        assert_eq!(stripped, change_thing(Edit::A(2)));
+        assert_eq!(
+            change.strip_prefix(&KeyPath::unit()).unwrap(),
+            change.clone()
+        );
+        assert!(change
+            .strip_prefix(&keypath![Other: different_thing])
+            .is_none());
+    }
+
+    #[test]
+    fn stripping_a_prefix_from_changes_at_the_prefix() {
+        let splice = Change::splice(keypath![Vec<Vec<usize>>: [1]], vec![3], 0, 1);
+
+        let stripped = splice
+            .strip_prefix(&keypath![Vec<Vec<usize>>: [1]])
+            .unwrap();
+
+        assert_eq!(stripped, Change::splice(KeyPath::unit_vec(), vec![3], 0, 1));
+        assert!(splice
+            .strip_prefix(&keypath![Vec<Vec<usize>>: [1][0]])
+            .is_none());
+    }
+
+    #[test]
+    fn kinds_of_changes() {
+        let update = change_thing(Edit::A(2));
+        let splice = Change::splice(KeyPath::unit_vec(), vec![1], 0, 0);
+
+        assert_eq!(update.kind(), ChangeKind::Update);
+        assert_eq!(splice.kind(), ChangeKind::Splice);
+    }
+
+    #[test]
+    fn splice_lengths() {
+        let update = change_thing(Edit::A(2));
+        let splice = Change::splice(KeyPath::unit_vec(), vec![1, 2, 3], 1, 2);
+
+        assert_eq!(splice.splice_len(), Some(3));
+        assert_eq!(splice.replace_count(), Some(2));
+        assert_eq!(update.splice_len(), None);
+        assert_eq!(update.replace_count(), None);
+    }
+
     #[test]
     fn downcasting_changes() {
         let change = change_thing(Edit::A(2));
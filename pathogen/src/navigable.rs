@@ -1,9 +1,11 @@
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashMap, VecDeque},
     fmt::Display,
+    rc::Rc,
+    sync::Arc,
 };
 
-use crate::KeyPath;
+use crate::{KeyPath, KeyPathFrom};
 
 /// Trait for types that can be navigated with key paths
 pub trait Navigable
@@ -20,6 +22,18 @@ where
     fn append_to_keypath<R>(path: &KeyPath<R, Self>) -> Self::Reflection<R>
     where
         R: Sized;
+
+    /// The declared Rust type name of the field with the (serialized) name `key`, if there is one,
+    /// e.g. for showing `my_vector: Vec<usize>` in a debugging inspector
+    fn field_type_name(_key: &str) -> Option<&'static str> {
+        None
+    }
+
+    /// The keypath to the variant with the (serialized) name `variant_key`, if Self is an enum with
+    /// such a variant, e.g. for switching an editor to the variant a user picked
+    fn variant_path(_variant_key: &str) -> Option<KeyPathFrom<Self>> {
+        None
+    }
 }
 
 /// Trait for types that can be indexed with key paths
@@ -36,6 +50,24 @@ impl<T> IndexNavigable<usize, T> for Vec<T> {
     }
 }
 
+impl<T> IndexNavigable<usize, T> for VecDeque<T> {
+    fn index_keypath_segment(index: usize) -> KeyPath<VecDeque<T>, T> {
+        KeyPath::index(index)
+    }
+}
+
+impl<T, const N: usize> IndexNavigable<usize, T> for [T; N] {
+    fn index_keypath_segment(index: usize) -> KeyPath<[T; N], T> {
+        KeyPath::index(index)
+    }
+}
+
+impl<T> IndexNavigable<usize, T> for Box<[T]> {
+    fn index_keypath_segment(index: usize) -> KeyPath<Box<[T]>, T> {
+        KeyPath::index(index)
+    }
+}
+
 impl<K: Display, V> IndexNavigable<K, V> for HashMap<K, V> {
     fn index_keypath_segment(index: K) -> KeyPath<Self, V> {
         KeyPath::string_key(format!("{index}"))
@@ -48,6 +80,53 @@ impl<K: Display, V> IndexNavigable<K, V> for BTreeMap<K, V> {
     }
 }
 
+// A box is transparent in the serialized form, so its reflection is the reflection of its content
+impl<T: Navigable> Navigable for Box<T> {
+    type Reflection<Root> = T::Reflection<Root>;
+
+    fn append_to_keypath<R>(path: &KeyPath<R, Self>) -> Self::Reflection<R>
+    where
+        R: Sized,
+    {
+        T::append_to_keypath(&path.appending(&KeyPath::unit()))
+    }
+}
+
+// Shared pointers are transparent in the serialized form too (with serde's `rc` feature)
+impl<T: Navigable> Navigable for Arc<T> {
+    type Reflection<Root> = T::Reflection<Root>;
+
+    fn append_to_keypath<R>(path: &KeyPath<R, Self>) -> Self::Reflection<R>
+    where
+        R: Sized,
+    {
+        T::append_to_keypath(&path.appending(&KeyPath::unit()))
+    }
+}
+
+impl<T: Navigable> Navigable for Rc<T> {
+    type Reflection<Root> = T::Reflection<Root>;
+
+    fn append_to_keypath<R>(path: &KeyPath<R, Self>) -> Self::Reflection<R>
+    where
+        R: Sized,
+    {
+        T::append_to_keypath(&path.appending(&KeyPath::unit()))
+    }
+}
+
+// Static data can be navigated for reading like owned data, it only can't be mutated
+impl<T: Navigable> Navigable for &'static T {
+    type Reflection<Root> = T::Reflection<Root>;
+
+    fn append_to_keypath<R>(path: &KeyPath<R, Self>) -> Self::Reflection<R>
+    where
+        R: Sized,
+    {
+        T::append_to_keypath(&path.appending(&KeyPath::unit()))
+    }
+}
+
 impl<T: Navigable> Navigable for Option<T> {
     type Reflection<Root> = SomeReflection<Root, T>;
 
@@ -76,3 +155,59 @@ impl<PreviousRoot, T: Navigable> Navigable for SomeReflection<PreviousRoot, T> {
         T::append_to_keypath(&path.appending(&KeyPath::unit()))
     }
 }
+
+// Tuples are serialized like tuple structs, so their reflection is a keypath per position
+macro_rules! navigable_tuple_impl {
+    ($(($($item:ident $index:tt),+))*) => ($(
+        impl<$($item),+> Navigable for ($($item,)+) {
+            type Reflection<Root> = ($(KeyPath<Root, $item>,)+);
+
+            fn append_to_keypath<R>(path: &KeyPath<R, Self>) -> Self::Reflection<R>
+            where
+                R: Sized,
+            {
+                ($(path.appending(&KeyPath::field(stringify!($index))),)+)
+            }
+        }
+    )*);
+}
+
+navigable_tuple_impl! {
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+}
+
+/// JSON has no static shape, so its reflection builds keypaths at runtime instead
+impl Navigable for serde_json::Value {
+    type Reflection<Root> = ValueNavigator<Root>;
+
+    fn append_to_keypath<R>(path: &KeyPath<R, Self>) -> Self::Reflection<R>
+    where
+        R: Sized,
+    {
+        ValueNavigator { path: path.clone() }
+    }
+}
+
+/// Builds keypaths into a `serde_json::Value`, the dynamic analog of a derived reflection.
+///
+/// Each step returns a `KeyPath`, so longer paths are built by going through `fields()` again,
+/// e.g. `Value::keypaths().key("items").fields().index(0)`.
+pub struct ValueNavigator<Root> {
+    path: KeyPath<Root, serde_json::Value>,
+}
+
+impl<Root> ValueNavigator<Root> {
+    /// The member `name` of a JSON object
+    pub fn key<K: Display>(&self, name: K) -> KeyPath<Root, serde_json::Value> {
+        self.path.appending(&KeyPath::string_key(name))
+    }
+
+    /// The item at `index` of a JSON array
+    pub fn index(&self, index: usize) -> KeyPath<Root, serde_json::Value> {
+        self.path.appending(&KeyPath::index(index))
+    }
+}
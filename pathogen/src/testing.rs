@@ -0,0 +1,165 @@
+//! Assertions for tests of change application, enabled with the `testing` feature.
+
+use std::collections::HashSet;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    json::{push_pointer_segment, value_at},
+    KeyPathFrom,
+};
+
+/// Assert that `before` and `after` are equal everywhere except at (and below) `paths`.
+///
+/// Both values are serialized to JSON and compared structurally, skipping the values the
+/// paths point at. A path which doesn't exist on one side, e.g. in a variant which was
+/// switched, is only skipped on the side where it does. Panics listing the JSON pointers of
+/// every unexpected difference.
+pub fn assert_changed_only<T: Serialize>(before: &T, after: &T, paths: &[KeyPathFrom<T>]) {
+    let before = serde_json::to_value(before).expect("Failed to serialize value");
+    let after = serde_json::to_value(after).expect("Failed to serialize value");
+
+    // Skipped values are recognised by address, since the same keypath can point at different
+    // JSON locations on the two sides
+    let skipped = |root: &Value| -> HashSet<*const Value> {
+        paths
+            .iter()
+            .filter_map(|path| value_at(root, &path.path))
+            .map(|value| value as *const Value)
+            .collect()
+    };
+
+    let mut differences = vec![];
+    collect_differences(
+        &mut String::new(),
+        (&before, &skipped(&before)),
+        (&after, &skipped(&after)),
+        &mut differences,
+    );
+
+    assert!(
+        differences.is_empty(),
+        "values differ outside of the allowed paths at {}",
+        differences.join(", ")
+    );
+}
+
+fn collect_differences(
+    pointer: &mut String,
+    (before, skipped_before): (&Value, &HashSet<*const Value>),
+    (after, skipped_after): (&Value, &HashSet<*const Value>),
+    differences: &mut Vec<String>,
+) {
+    if skipped_before.contains(&(before as *const Value))
+        || skipped_after.contains(&(after as *const Value))
+    {
+        return;
+    }
+
+    let mut descend = |segment: &str, before: &Value, after: &Value| {
+        let length = pointer.len();
+        push_pointer_segment(pointer, segment);
+
+        collect_differences(
+            pointer,
+            (before, skipped_before),
+            (after, skipped_after),
+            differences,
+        );
+
+        pointer.truncate(length);
+    };
+
+    match (before, after) {
+        (Value::Object(before_map), Value::Object(after_map))
+            if before_map.len() == after_map.len()
+                && before_map.keys().all(|k| after_map.contains_key(k)) =>
+        {
+            for (key, before_value) in before_map {
+                descend(key, before_value, &after_map[key]);
+            }
+        }
+        (Value::Array(before_items), Value::Array(after_items))
+            if before_items.len() == after_items.len() =>
+        {
+            for (ix, (before_item, after_item)) in before_items.iter().zip(after_items).enumerate()
+            {
+                descend(&ix.to_string(), before_item, after_item);
+            }
+        }
+        _ if before == after => {}
+        _ => differences.push(if pointer.is_empty() {
+            "/".to_string()
+        } else {
+            pointer.clone()
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+
+    use super::*;
+    use crate::{keypath, macros::Navigable, Navigable};
+
+    #[derive(Clone, Serialize, Navigable)]
+    struct Inventory {
+        owner: String,
+        items: Vec<Item>,
+    }
+
+    #[derive(Clone, Serialize, Navigable)]
+    struct Item {
+        name: String,
+        count: usize,
+    }
+
+    fn inventory() -> Inventory {
+        Inventory {
+            owner: "Alice".to_string(),
+            items: vec![
+                Item {
+                    name: "apple".to_string(),
+                    count: 1,
+                },
+                Item {
+                    name: "pear".to_string(),
+                    count: 2,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn allows_differences_at_the_given_paths() {
+        let before = inventory();
+        let mut after = inventory();
+        after.items[1].count = 5;
+        after.owner = "Bob".to_string();
+
+        assert_changed_only(
+            &before,
+            &after,
+            &[
+                keypath![Inventory: items[1].count].into(),
+                keypath![Inventory: owner].into(),
+            ],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "values differ outside of the allowed paths at /items/0/count")]
+    fn reports_differences_outside_the_given_paths() {
+        let before = inventory();
+        let mut after = inventory();
+        after.items[0].count = 5;
+
+        assert_changed_only(
+            &before,
+            &after,
+            &[keypath![Inventory: items[1].count].into()],
+        );
+    }
+}
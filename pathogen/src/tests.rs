@@ -1,11 +1,14 @@
 use std::collections::{BTreeMap, HashMap};
 
 use pretty_assertions::assert_eq;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use super::*;
-use crate::{macros::Navigable, navigable::Navigable};
+use crate::{
+    macros::{KeyPathMutable, Navigable},
+    navigable::Navigable,
+};
 
 #[derive(Navigable)]
 #[allow(dead_code)] // Only reflection is tested
@@ -29,7 +32,9 @@ fn one_step_keypath() {
 
     assert_eq!(
         keypath.path,
-        vec![KeyPathElement::Field { key: "my_scalar" }]
+        vec![KeyPathElement::Field {
+            key: "my_scalar".into()
+        }]
     );
 }
 
@@ -40,8 +45,12 @@ fn two_step_keypath() {
     assert_eq!(
         keypath.path,
         vec![
-            KeyPathElement::Field { key: "my_nested" },
-            KeyPathElement::Field { key: "my_string" }
+            KeyPathElement::Field {
+                key: "my_nested".into()
+            },
+            KeyPathElement::Field {
+                key: "my_string".into()
+            }
         ]
     );
 }
@@ -53,12 +62,114 @@ fn two_step_keypath_with_index() {
     assert_eq!(
         keypath.path,
         vec![
-            KeyPathElement::Field { key: "my_vector" },
+            KeyPathElement::Field {
+                key: "my_vector".into()
+            },
             KeyPathElement::Index { key: 0 }
         ]
     );
 }
 
+#[test]
+fn field_type_names() {
+    // `type_name` doesn't promise an exact format, only the type's own name is stable
+    assert!(Test::field_type_name("my_scalar").is_some_and(|name| name.ends_with("usize")));
+    assert!(Test::field_type_name("my_vector").is_some_and(|name| name.ends_with("Vec<usize>")));
+    assert_eq!(Test::field_type_name("unknown"), None);
+
+    // keys are the serialized field names
+    assert!(RenamedStruct::field_type_name("myField").is_some_and(|name| name.ends_with("usize")));
+    assert_eq!(RenamedStruct::field_type_name("my_field"), None);
+}
+
+// `cfg` and `cfg_attr` are expanded before the derives see the struct, so they only see the
+// active fields, and the attributes which `cfg_attr` applies
+#[derive(Serialize, Deserialize, Navigable, KeyPathMutable)]
+struct ConfiguredStruct {
+    /// Documented, which doesn't matter to the derives either
+    #[cfg_attr(test, serde(rename = "activeName"))]
+    renamed_when_active: usize,
+    #[cfg_attr(not(test), serde(rename = "inactiveName"))]
+    renamed_when_inactive: usize,
+    #[cfg(not(test))]
+    excluded: usize,
+}
+
+#[test]
+fn derives_see_fields_after_cfg_expansion() {
+    let mut value = ConfiguredStruct {
+        renamed_when_active: 1,
+        renamed_when_inactive: 2,
+    };
+    let renamed: KeyPath<ConfiguredStruct, usize> = keypath![ConfiguredStruct: renamed_when_active];
+
+    assert_eq!(
+        renamed.path,
+        vec![KeyPathElement::Field {
+            key: "activeName".into()
+        }]
+    );
+    assert_eq!(
+        serde_json::to_value(&value).unwrap(),
+        json!({ "activeName": 1, "renamed_when_inactive": 2 })
+    );
+    assert_eq!(
+        ConfiguredStruct::field_type_name("renamed_when_inactive"),
+        Some("usize")
+    );
+    assert_eq!(ConfiguredStruct::field_type_name("inactiveName"), None);
+    assert_eq!(ConfiguredStruct::field_type_name("excluded"), None);
+
+    value.apply_change(&Change::update(renamed, 3));
+    assert_eq!(value.renamed_when_active, 3);
+}
+
+#[test]
+fn reflections_are_clone_and_debug() {
+    // Neither `Test` nor `Nested` are Clone or Debug
+    let keypaths = Test::keypaths();
+    let cloned = keypaths.clone();
+
+    assert_eq!(cloned.my_nested.path, keypaths.my_nested.path);
+    assert!(format!("{cloned:?}").starts_with("TestKeyPathReflection { my_scalar: KeyPath {"));
+
+    let variant = keypath![EnumTest: TestVariant];
+    assert!(format!("{:?}", variant.fields().clone()).contains("test: KeyPath"));
+}
+
+#[derive(Navigable)]
+#[allow(dead_code)] // Only reflection is tested
+struct Labelled(usize, Nested);
+
+#[derive(Navigable)]
+#[allow(dead_code)] // Only reflection is tested
+struct WithLabelled {
+    labelled: Labelled,
+}
+
+#[test]
+fn tuple_struct_keypaths() {
+    let keypath: KeyPath<WithLabelled, String> = keypath![WithLabelled: labelled]
+        .fields()
+        .1
+        .fields()
+        .my_string;
+
+    assert_eq!(
+        keypath.path,
+        vec![
+            KeyPathElement::Field {
+                key: "labelled".into()
+            },
+            KeyPathElement::Field { key: "1".into() },
+            KeyPathElement::Field {
+                key: "my_string".into()
+            }
+        ]
+    );
+    assert_eq!(Labelled::field_type_name("0"), Some("usize"));
+}
+
 #[test]
 fn deeper_keypath() {
     let keypath: KeyPath<Test, String> = Test::keypaths()
@@ -71,10 +182,12 @@ fn deeper_keypath() {
         keypath.path,
         vec![
             KeyPathElement::Field {
-                key: "my_vector_of_nested"
+                key: "my_vector_of_nested".into()
             },
             KeyPathElement::Index { key: 0 },
-            KeyPathElement::Field { key: "my_string" }
+            KeyPathElement::Field {
+                key: "my_string".into()
+            }
         ]
     );
 }
@@ -92,10 +205,12 @@ fn keypath_with_multiple_vectors() {
         keypath.path,
         vec![
             KeyPathElement::Field {
-                key: "my_vector_of_nested"
+                key: "my_vector_of_nested".into()
             },
             KeyPathElement::Index { key: 0 },
-            KeyPathElement::Field { key: "my_vector" },
+            KeyPathElement::Field {
+                key: "my_vector".into()
+            },
             KeyPathElement::Index { key: 0 }
         ]
     );
@@ -109,10 +224,12 @@ fn keypath_macro() {
         keypath.path,
         vec![
             KeyPathElement::Field {
-                key: "my_vector_of_nested"
+                key: "my_vector_of_nested".into()
             },
             KeyPathElement::Index { key: 0 },
-            KeyPathElement::Field { key: "my_vector" },
+            KeyPathElement::Field {
+                key: "my_vector".into()
+            },
             KeyPathElement::Index { key: 0 }
         ]
     );
@@ -126,7 +243,9 @@ fn keypath_macro_on_vector() {
         keypath.path,
         vec![
             KeyPathElement::Index { key: 0 },
-            KeyPathElement::Field { key: "my_vector" },
+            KeyPathElement::Field {
+                key: "my_vector".into()
+            },
             KeyPathElement::Index { key: 0 }
         ]
     );
@@ -141,7 +260,9 @@ fn keypath_macro_on_vector_dyn() {
         keypath.path,
         vec![
             KeyPathElement::Index { key: 5 },
-            KeyPathElement::Field { key: "my_vector" },
+            KeyPathElement::Field {
+                key: "my_vector".into()
+            },
             KeyPathElement::Index { key: 0 }
         ]
     );
@@ -170,22 +291,28 @@ fn enum_keypaths() {
         test_keypath.path,
         vec![
             KeyPathElement::Variant {
-                key: "TestVariant",
+                key: "TestVariant".into(),
                 tag: VariantTagType::External
             },
-            KeyPathElement::Field { key: "test" },
-            KeyPathElement::Field { key: "my_scalar" }
+            KeyPathElement::Field { key: "test".into() },
+            KeyPathElement::Field {
+                key: "my_scalar".into()
+            }
         ]
     );
     assert_eq!(
         nested_keypath.path,
         vec![
             KeyPathElement::Variant {
-                key: "NestedVariant",
+                key: "NestedVariant".into(),
                 tag: VariantTagType::External
             },
-            KeyPathElement::Field { key: "nested" },
-            KeyPathElement::Field { key: "my_string" }
+            KeyPathElement::Field {
+                key: "nested".into()
+            },
+            KeyPathElement::Field {
+                key: "my_string".into()
+            }
         ]
     );
 }
@@ -200,27 +327,340 @@ fn nested_enum_keypaths() {
     assert_eq!(
         test_keypath.path,
         vec![
-            KeyPathElement::Field { key: "my_enum" },
+            KeyPathElement::Field {
+                key: "my_enum".into()
+            },
             KeyPathElement::Variant {
-                key: "TestVariant",
+                key: "TestVariant".into(),
                 tag: VariantTagType::External
             },
-            KeyPathElement::Field { key: "test" },
-            KeyPathElement::Field { key: "my_scalar" }
+            KeyPathElement::Field { key: "test".into() },
+            KeyPathElement::Field {
+                key: "my_scalar".into()
+            }
         ]
     );
     assert_eq!(
         nested_keypath.path,
         vec![
-            KeyPathElement::Field { key: "my_enum" },
+            KeyPathElement::Field {
+                key: "my_enum".into()
+            },
+            KeyPathElement::Variant {
+                key: "NestedVariant".into(),
+                tag: VariantTagType::External
+            },
+            KeyPathElement::Field {
+                key: "nested".into()
+            },
+            KeyPathElement::Field {
+                key: "my_string".into()
+            }
+        ]
+    );
+}
+
+#[test]
+fn deeply_chained_enum_keypaths() {
+    let keypath: KeyPath<StructWithEnum, f64> =
+        keypath![StructWithEnum: my_enum.TestVariant.test.my_vector_of_nested[0].my_vector[0]];
+
+    assert_eq!(
+        keypath.path,
+        vec![
+            KeyPathElement::Field {
+                key: "my_enum".into()
+            },
             KeyPathElement::Variant {
-                key: "NestedVariant",
+                key: "TestVariant".into(),
                 tag: VariantTagType::External
             },
-            KeyPathElement::Field { key: "nested" },
-            KeyPathElement::Field { key: "my_string" }
+            KeyPathElement::Field { key: "test".into() },
+            KeyPathElement::Field {
+                key: "my_vector_of_nested".into()
+            },
+            KeyPathElement::Index { key: 0 },
+            KeyPathElement::Field {
+                key: "my_vector".into()
+            },
+            KeyPathElement::Index { key: 0 },
+        ]
+    );
+}
+
+#[test]
+fn variant_path_of_a_known_variant() {
+    let keypath = EnumTest::variant_path("NestedVariant").unwrap();
+
+    assert_eq!(
+        keypath.path,
+        vec![KeyPathElement::Variant {
+            key: "NestedVariant".into(),
+            tag: VariantTagType::External
+        }]
+    );
+    assert!(keypath.is_subpath_of(&keypath![EnumTest: NestedVariant.nested].into()));
+}
+
+#[test]
+fn variant_path_uses_serialized_variant_names() {
+    let keypath = RenamedEnum::variant_path("variantOne").unwrap();
+
+    assert_eq!(
+        keypath.path,
+        vec![KeyPathElement::Variant {
+            key: "variantOne".into(),
+            tag: VariantTagType::External
+        }]
+    );
+    assert!(RenamedEnum::variant_path("VariantOne").is_none());
+}
+
+#[test]
+fn variant_path_of_an_unknown_variant() {
+    assert!(EnumTest::variant_path("MissingVariant").is_none());
+    assert!(Test::variant_path("TestVariant").is_none());
+}
+
+#[test]
+fn elements_at_each_depth() {
+    let keypath: KeyPathFrom<Test> = keypath![Test: my_vector_of_nested[2].my_string].into();
+
+    assert_eq!(
+        keypath.element_at(0),
+        Some(&KeyPathElement::Field {
+            key: "my_vector_of_nested".into()
+        })
+    );
+    assert_eq!(
+        keypath.element_at(1),
+        Some(&KeyPathElement::Index { key: 2 })
+    );
+    assert_eq!(
+        keypath.element_at(2),
+        Some(&KeyPathElement::Field {
+            key: "my_string".into()
+        })
+    );
+    assert_eq!(keypath.element_at(3), None);
+}
+
+#[test]
+fn leading_variants() {
+    let variant_headed: KeyPathFrom<EnumTest> = keypath![EnumTest: NestedVariant.nested].into();
+    let field_headed: KeyPathFrom<Test> = keypath![Test: my_nested.my_string].into();
+
+    assert_eq!(
+        variant_headed.leading_variant(),
+        Some(("NestedVariant", &VariantTagType::External))
+    );
+    assert_eq!(field_headed.leading_variant(), None);
+}
+
+#[test]
+fn parses_displayed_keypaths() {
+    let nested: KeyPathFrom<Test> = keypath![Test: my_nested.my_vector[0]].into();
+    let map_key: KeyPathFrom<ThingWithMaps> =
+        keypath![ThingWithMaps: string_counts["some key"]].into();
+
+    for keypath in [nested.to_string(), "my_nested.my_vector[0]".to_string()] {
+        assert_eq!(
+            KeyPathFrom::<Test>::parse(&keypath).unwrap().path,
+            nested.path
+        );
+    }
+    assert_eq!(
+        map_key
+            .to_string()
+            .parse::<KeyPathFrom<ThingWithMaps>>()
+            .unwrap()
+            .path,
+        map_key.path
+    );
+    assert_eq!(
+        KeyPathFrom::<Test>::parse("[2].a-b[\"x.y\"]").unwrap().path,
+        vec![
+            KeyPathElement::Index { key: 2 },
+            KeyPathElement::Field { key: "a-b".into() },
+            KeyPathElement::StringKey { key: "x.y".into() },
+        ]
+    );
+    for empty in ["", "."] {
+        assert_eq!(KeyPathFrom::<Test>::parse(empty).unwrap().path, vec![]);
+    }
+    assert!(matches!(
+        KeyPathFrom::<Test>::parse("not_a_field")
+            .unwrap()
+            .path
+            .as_slice(),
+        [KeyPathElement::Field {
+            key: std::borrow::Cow::Owned(_)
+        }]
+    ));
+}
+
+#[test]
+fn reports_where_a_keypath_is_malformed() {
+    let error_at = |s: &str| {
+        let Err(error) = KeyPathFrom::<Test>::parse(s) else {
+            panic!("{s} parsed");
+        };
+        (error.position, error.reason)
+    };
+
+    assert_eq!(error_at("a..b"), (2, "expected a name"));
+    assert_eq!(error_at("a."), (2, "expected a name"));
+    assert_eq!(error_at("a.[0]"), (2, "expected a name after `.`"));
+    assert_eq!(error_at("a[x]"), (2, "expected an index"));
+    assert_eq!(error_at("a[+1]"), (2, "expected an index"));
+    assert_eq!(error_at("a[1"), (1, "unterminated index"));
+    assert_eq!(error_at("a[\"b]"), (1, "unterminated string key"));
+    assert_eq!(error_at("a[0]b"), (4, "expected `.` or `[`"));
+    assert_eq!(error_at("a]"), (1, "expected `.` or `[`"));
+}
+
+#[test]
+fn keypaths_to_json_pointers() {
+    let nested: KeyPathFrom<Test> = keypath![Test: my_vector_of_nested[2].my_string].into();
+    let external: KeyPathFrom<Workspace> = keypath![Workspace: layout.Split.ratio].into();
+    let internal: KeyPathFrom<Workspace> = keypath![Workspace: panel.Sidebar.width].into();
+    let map_key: KeyPathFrom<Workspace> =
+        keypath![Workspace: files["src/~main.rs".to_string()].name].into();
+
+    let adjacent: KeyPathFrom<Adjacent> = keypath![Adjacent: Content.value].into();
+
+    assert_eq!(
+        nested.to_json_pointer().unwrap(),
+        "/my_vector_of_nested/2/my_string"
+    );
+    assert_eq!(external.to_json_pointer().unwrap(), "/layout/Split/ratio");
+    assert_eq!(internal.to_json_pointer().unwrap(), "/panel/width");
+    assert_eq!(
+        map_key.to_json_pointer().unwrap(),
+        "/files/src~1~0main.rs/name"
+    );
+    assert_eq!(
+        KeyPathFrom::<Test>::from(KeyPath::<Test, Test>::unit())
+            .to_json_pointer()
+            .unwrap(),
+        ""
+    );
+    assert_eq!(adjacent.to_json_pointer(), None);
+}
+
+#[test]
+fn keypaths_from_json_pointers() {
+    let nested =
+        KeyPathFrom::<Test>::from_json_pointer("/my_vector_of_nested/2/my_string").unwrap();
+    let escaped = KeyPathFrom::<Test>::from_json_pointer("/a~1b~0c/01/+1/").unwrap();
+
+    assert_eq!(
+        nested.path,
+        keypath![Test: my_vector_of_nested[2].my_string].path
+    );
+    assert_eq!(
+        escaped.path,
+        vec![
+            KeyPathElement::Field {
+                key: "a/b~c".into()
+            },
+            KeyPathElement::Field { key: "01".into() },
+            KeyPathElement::Field { key: "+1".into() },
+            KeyPathElement::Field { key: "".into() },
         ]
     );
+    assert_eq!(
+        KeyPathFrom::<Test>::from_json_pointer("").unwrap().path,
+        vec![]
+    );
+    assert!(matches!(
+        KeyPathFrom::<Test>::from_json_pointer("my_scalar"),
+        Err(ParseJsonPointerError(pointer)) if pointer == "my_scalar"
+    ));
+    assert!(KeyPathFrom::<Test>::from_json_pointer("/a~2b").is_err());
+    assert!(KeyPathFrom::<Test>::from_json_pointer("/a~").is_err());
+}
+
+#[test]
+fn parents_and_last_elements() {
+    let keypath = keypath![Test: my_nested.my_vector[2]];
+    let erased: KeyPathFrom<Test> = keypath.as_from();
+    let unit = KeyPath::<Test, Test>::unit();
+
+    assert!(keypath.parent() == keypath![Test: my_nested.my_vector]);
+    assert!(erased.parent() == keypath![Test: my_nested.my_vector]);
+    assert!(keypath![Test: my_scalar].parent() == unit);
+    assert!(unit.parent() == unit);
+
+    assert_eq!(
+        keypath.last_element(),
+        Some(&KeyPathElement::Index { key: 2 })
+    );
+    assert_eq!(
+        erased.parent().last_element(),
+        Some(&KeyPathElement::Field {
+            key: "my_vector".into()
+        })
+    );
+    assert_eq!(unit.last_element(), None);
+}
+
+#[test]
+fn long_paths_look_suspicious() {
+    let keypath: KeyPathFrom<Test> = keypath![Test: my_nested.my_vector[2]].into();
+    let repeated = KeyPathFrom::<Test>::dangerously_construct_from_path(vec![
+            KeyPathElement::Field {
+                key: "my_nested".into()
+            };
+            1000
+        ]);
+
+    assert!(!keypath.looks_suspicious(3));
+    assert!(keypath.looks_suspicious(2));
+    assert!(repeated.looks_suspicious(64));
+    assert!(!repeated.looks_suspicious(1000));
+}
+
+#[test]
+fn sub_and_superpaths() {
+    let outer: KeyPathFrom<Test> = keypath![Test: my_nested].into();
+    let inner: KeyPathFrom<Test> = keypath![Test: my_nested.my_vector[1]].into();
+    let disjoint: KeyPathFrom<Test> = keypath![Test: my_vector[1]].into();
+
+    assert!(outer.is_subpath_of(&inner));
+    assert!(inner.is_superpath_of(&outer));
+    assert!(!outer.is_superpath_of(&inner));
+    assert!(!inner.is_subpath_of(&outer));
+
+    assert!(!inner.is_subpath_of(&disjoint));
+    assert!(!inner.is_superpath_of(&disjoint));
+
+    assert!(!inner.is_subpath_of(&inner));
+    assert!(!inner.is_superpath_of(&inner));
+}
+
+#[test]
+fn common_prefixes() {
+    let strings: KeyPathFrom<Test> = keypath![Test: my_vector_of_nested[0].my_string].into();
+    let vector: KeyPathFrom<Test> = keypath![Test: my_vector_of_nested[0].my_vector[3]].into();
+    let other_item: KeyPathFrom<Test> = keypath![Test: my_vector_of_nested[1].my_string].into();
+    let disjoint: KeyPathFrom<Test> = keypath![Test: my_scalar].into();
+
+    assert!(strings.common_prefix(&vector) == keypath![Test: my_vector_of_nested[0]]);
+    assert!(strings.common_prefix(&other_item) == keypath![Test: my_vector_of_nested]);
+    assert!(strings.common_prefix(&strings.prefix_to(1)) == keypath![Test: my_vector_of_nested]);
+    assert!(strings.common_prefix(&disjoint) == KeyPath::<Test, Test>::unit());
+    assert_eq!(strings.common_prefix(&strings).path, strings.path);
+}
+
+#[test]
+fn prefixes_to_each_depth() {
+    let keypath: KeyPathFrom<Test> = keypath![Test: my_vector_of_nested[2].my_string].into();
+
+    assert!(keypath.prefix_to(0) == KeyPath::<Test, Test>::unit());
+    assert!(keypath.prefix_to(2) == keypath![Test: my_vector_of_nested[2]]);
+    assert_eq!(keypath.prefix_to(3).path, keypath.path);
+    assert_eq!(keypath.prefix_to(10).path, keypath.path);
 }
 
 #[test]
@@ -253,6 +693,126 @@ fn complex_serialization() {
     );
 }
 
+// The wire format bindings are generated against. A failure here is a breaking change for
+// them, so update the expectations only deliberately.
+#[test]
+fn wire_format_of_keypath_elements() {
+    let cases = [
+        (
+            KeyPathElement::Field { key: "name".into() },
+            json!({ "type": "field", "key": "name" }),
+        ),
+        (
+            KeyPathElement::Variant {
+                key: "Some".into(),
+                tag: VariantTagType::External,
+            },
+            json!({ "type": "variant", "key": "Some", "tag": "external" }),
+        ),
+        (
+            KeyPathElement::Index { key: 3 },
+            json!({ "type": "index", "key": 3 }),
+        ),
+        (
+            KeyPathElement::StringKey { key: "id".into() },
+            json!({ "type": "stringKey", "key": "id" }),
+        ),
+    ];
+
+    for (element, expected) in cases {
+        assert_eq!(serde_json::to_value(&element).unwrap(), expected);
+        assert_eq!(
+            serde_json::from_value::<KeyPathElement>(expected).unwrap(),
+            element
+        );
+    }
+}
+
+#[test]
+fn wire_format_of_variant_tag_types() {
+    let cases = [
+        (VariantTagType::External, json!("external")),
+        (VariantTagType::Internal, json!("internal")),
+        (VariantTagType::Adjacent, json!("adjacent")),
+        (VariantTagType::Untagged, json!("untagged")),
+    ];
+
+    for (tag, expected) in cases {
+        assert_eq!(serde_json::to_value(&tag).unwrap(), expected);
+        assert_eq!(
+            serde_json::from_value::<VariantTagType>(expected).unwrap(),
+            tag
+        );
+    }
+}
+
+#[test]
+fn wire_format_of_patches() {
+    let key_path = json!([{ "type": "field", "key": "items" }]);
+    let cases = [
+        (
+            Patch::Update {
+                key_path: key_path.clone(),
+                value: json!(1),
+            },
+            json!({
+                "type": "update",
+                "keyPath": [{ "type": "field", "key": "items" }],
+                "value": 1
+            }),
+        ),
+        (
+            Patch::Splice {
+                key_path,
+                value: vec![json!(1), json!(2)],
+                start: 0,
+                replace: 1,
+            },
+            json!({
+                "type": "splice",
+                "keyPath": [{ "type": "field", "key": "items" }],
+                "value": [1, 2],
+                "start": 0,
+                "replace": 1
+            }),
+        ),
+        (
+            Patch::Delete {
+                key_path: json!([{ "type": "field", "key": "items" }]),
+            },
+            json!({
+                "type": "delete",
+                "keyPath": [{ "type": "field", "key": "items" }]
+            }),
+        ),
+    ];
+
+    for (patch, expected) in cases {
+        assert_eq!(serde_json::to_value(&patch).unwrap(), expected);
+        assert_eq!(serde_json::from_value::<Patch>(expected).unwrap(), patch);
+    }
+}
+
+#[test]
+fn variant_tag_type_string_round_trip() {
+    for tag in [
+        VariantTagType::External,
+        VariantTagType::Internal,
+        VariantTagType::Adjacent,
+        VariantTagType::Untagged,
+    ] {
+        let string = tag.to_string();
+
+        assert_eq!(serde_json::to_value(&tag).unwrap(), json!(string));
+        assert_eq!(string.parse::<VariantTagType>(), Ok(tag));
+    }
+
+    assert_eq!(
+        "External".parse::<VariantTagType>(),
+        Err(ParseVariantTagTypeError("External".to_string()))
+    );
+}
+
 #[derive(Serialize, Navigable)]
 #[serde(rename_all = "camelCase")]
 struct RenamedStruct {
@@ -303,61 +863,353 @@ fn serialization_respects_rename_all() {
     );
 }
 
-#[allow(dead_code)] // Only reflection is tested
-#[derive(Navigable)]
-enum TestTupleEnum {
-    VariantOne(usize),
-    VariantTwo(Nested, String),
+#[test]
+fn wire_keys_of_each_element_kind() {
+    let variant: KeyPath<RenamedEnum, usize> = keypath![RenamedEnum: VariantOne.my_field];
+    let index = keypath![Test: my_nested.my_vector[3]];
+    let string_key = keypath![ThingWithMaps: string_counts["Hello"]];
+
+    let wire_keys = |path: &[KeyPathElement]| -> Vec<String> {
+        path.iter().map(|e| e.wire_key().into_owned()).collect()
+    };
+
+    assert_eq!(wire_keys(&variant.path), ["variantOne", "myField"]);
+    assert_eq!(wire_keys(&index.path), ["my_nested", "my_vector", "3"]);
+    assert_eq!(wire_keys(&string_key.path), ["string_counts", "Hello"]);
+}
+
+#[derive(Serialize, Navigable)]
+#[serde(rename_all = "UPPERCASE")]
+struct ShoutingStruct {
+    my_field: usize,
+}
+
+#[derive(Serialize, Navigable)]
+#[serde(rename_all = "lowercase")]
+enum QuietEnum {
+    VariantOne { my_field: ShoutingStruct },
 }
 
 #[test]
-fn enum_keypaths_tuple_variants() {
-    let one_keypath: KeyPath<TestTupleEnum, usize> = keypath![TestTupleEnum: VariantOne.0];
-    let two_keypath: KeyPath<TestTupleEnum, String> = keypath![TestTupleEnum: VariantTwo.1];
-    let two_keypath_deep: KeyPath<TestTupleEnum, f64> =
-        keypath![TestTupleEnum: VariantTwo.0.my_vector[0]];
+fn field_names_match_serde_for_whole_word_cases() {
+    let value = QuietEnum::VariantOne {
+        my_field: ShoutingStruct { my_field: 1 },
+    };
+    let keypath: KeyPath<QuietEnum, usize> = keypath![QuietEnum: VariantOne.my_field.my_field];
 
     assert_eq!(
-        one_keypath.path,
+        keypath.path,
         vec![
             KeyPathElement::Variant {
-                key: "VariantOne",
+                key: "variantone".into(),
                 tag: VariantTagType::External
             },
-            KeyPathElement::Field { key: "0" },
+            KeyPathElement::Field {
+                key: "my_field".into()
+            },
+            KeyPathElement::Field {
+                key: "MY_FIELD".into()
+            },
         ]
     );
-
     assert_eq!(
-        two_keypath.path,
-        vec![
-            KeyPathElement::Variant {
-                key: "VariantTwo",
-                tag: VariantTagType::External
-            },
-            KeyPathElement::Field { key: "1" },
-        ]
+        serde_json::to_value(value).unwrap(),
+        json!({ "variantone": { "my_field": { "MY_FIELD": 1 } } })
+    );
+}
+
+#[derive(Serialize, Deserialize, Navigable, KeyPathMutable)]
+#[serde(rename_all = "snake_case")]
+enum SnakeEnum {
+    PascalCase { my_field: usize },
+}
+
+#[derive(Serialize, Deserialize, Navigable, KeyPathMutable)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum ScreamingSnakeEnum {
+    FirstThing { my_field: usize },
+}
+
+#[derive(Serialize, Deserialize, Navigable, KeyPathMutable)]
+#[serde(rename_all = "PascalCase")]
+struct PascalStruct {
+    my_field: usize,
+    id: usize,
+}
+
+#[derive(Serialize, Deserialize, Navigable, KeyPathMutable)]
+#[serde(rename_all = "kebab-case")]
+struct KebabStruct {
+    _leading: usize,
+    retry_2_times: usize,
+}
+
+#[derive(Serialize, Deserialize, Navigable, KeyPathMutable)]
+#[serde(rename_all = "SCREAMING-KEBAB-CASE")]
+enum ScreamingKebabEnum {
+    PascalCase { my_field: KebabStruct },
+}
+
+// serde renames fields and variants by separate rules, which only agree for idiomatic names, so
+// each rule gets a struct with unidiomatic field names in an enum with an unidiomatic variant
+macro_rules! unidiomatic_types {
+    ($rule:tt, $structure:ident, $enumeration:ident) => {
+        #[allow(non_snake_case)]
+        #[derive(Serialize, Deserialize, Navigable, KeyPathMutable)]
+        #[serde(rename_all = $rule)]
+        struct $structure {
+            myField: usize,
+            _leading_field: usize,
+        }
+
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize, Navigable, KeyPathMutable)]
+        #[serde(rename_all = $rule)]
+        enum $enumeration {
+            My_Variant { inner: $structure },
+        }
+
+        impl $enumeration {
+            fn sample() -> Self {
+                Self::My_Variant {
+                    inner: $structure {
+                        myField: 1,
+                        _leading_field: 2,
+                    },
+                }
+            }
+        }
+    };
+}
+
+unidiomatic_types!("lowercase", LowerStruct, LowerEnum);
+unidiomatic_types!("UPPERCASE", UpperStruct, UpperEnum);
+unidiomatic_types!("camelCase", CamelStruct, CamelEnum);
+unidiomatic_types!("PascalCase", UnidiomaticPascalStruct, UnidiomaticPascalEnum);
+unidiomatic_types!("snake_case", UnidiomaticSnakeStruct, UnidiomaticSnakeEnum);
+unidiomatic_types!(
+    "SCREAMING_SNAKE_CASE",
+    UnidiomaticScreamingSnakeStruct,
+    UnidiomaticScreamingSnakeEnum
+);
+unidiomatic_types!("kebab-case", UnidiomaticKebabStruct, UnidiomaticKebabEnum);
+unidiomatic_types!(
+    "SCREAMING-KEBAB-CASE",
+    UnidiomaticScreamingKebabStruct,
+    UnidiomaticScreamingKebabEnum
+);
+
+/// Checks the keypath names the keys serde writes, and that the derived `KeyPathMutable`
+/// accepts them
+fn assert_renamed_like_serde<Root>(mut value: Root, keypath: KeyPath<Root, usize>, keys: &[&str])
+where
+    Root: Serialize + KeyPathMutable,
+{
+    let wire_keys: Vec<_> = keypath.path.iter().map(|e| e.wire_key()).collect();
+    assert_eq!(wire_keys, keys);
+
+    let pointer: String = keys.iter().map(|key| format!("/{key}")).collect();
+    assert!(serde_json::to_value(&value)
+        .unwrap()
+        .pointer(&pointer)
+        .is_some());
+
+    value
+        .try_apply_change(&Change::update(keypath, 42))
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&value).unwrap().pointer(&pointer),
+        Some(&json!(42))
+    );
+}
+
+#[test]
+fn names_match_serde_for_every_rename_all() {
+    let kebab = || KebabStruct {
+        _leading: 1,
+        retry_2_times: 2,
+    };
+
+    assert_renamed_like_serde(
+        SnakeEnum::PascalCase { my_field: 1 },
+        keypath![SnakeEnum: PascalCase.my_field],
+        &["pascal_case", "my_field"],
+    );
+    assert_renamed_like_serde(
+        ScreamingSnakeEnum::FirstThing { my_field: 1 },
+        keypath![ScreamingSnakeEnum: FirstThing.my_field],
+        &["FIRST_THING", "my_field"],
+    );
+    assert_renamed_like_serde(
+        PascalStruct { my_field: 1, id: 2 },
+        keypath![PascalStruct: my_field],
+        &["MyField"],
+    );
+    assert_renamed_like_serde(
+        PascalStruct { my_field: 1, id: 2 },
+        keypath![PascalStruct: id],
+        &["Id"],
+    );
+    assert_renamed_like_serde(kebab(), keypath![KebabStruct: _leading], &["-leading"]);
+    assert_renamed_like_serde(
+        ScreamingKebabEnum::PascalCase { my_field: kebab() },
+        keypath![ScreamingKebabEnum: PascalCase.my_field.retry_2_times],
+        &["PASCAL-CASE", "my_field", "retry-2-times"],
+    );
+    // The variant, then the `myField` and `_leading_field` keys serde writes for each rule
+    macro_rules! assert_unidiomatic_renamed_like_serde {
+        ($($enumeration:ident => [$variant:literal, $my_field:literal, $leading_field:literal],)*) => {$(
+            assert_renamed_like_serde(
+                $enumeration::sample(),
+                keypath![$enumeration: My_Variant.inner.myField],
+                &[$variant, "inner", $my_field],
+            );
+            assert_renamed_like_serde(
+                $enumeration::sample(),
+                keypath![$enumeration: My_Variant.inner._leading_field],
+                &[$variant, "inner", $leading_field],
+            );
+        )*};
+    }
+
+    assert_unidiomatic_renamed_like_serde! {
+        LowerEnum => ["my_variant", "myField", "_leading_field"],
+        UpperEnum => ["MY_VARIANT", "MYFIELD", "_LEADING_FIELD"],
+        CamelEnum => ["my_Variant", "myField", "leadingField"],
+        UnidiomaticPascalEnum => ["My_Variant", "MyField", "LeadingField"],
+        UnidiomaticSnakeEnum => ["my__variant", "myField", "_leading_field"],
+        UnidiomaticScreamingSnakeEnum => ["MY__VARIANT", "MYFIELD", "_LEADING_FIELD"],
+        UnidiomaticKebabEnum => ["my--variant", "myField", "-leading-field"],
+        UnidiomaticScreamingKebabEnum => ["MY--VARIANT", "MYFIELD", "-LEADING-FIELD"],
+    }
+}
+
+#[allow(dead_code)] // Only reflection is tested
+#[derive(Navigable)]
+enum TestTupleEnum {
+    VariantOne(usize),
+    VariantTwo(Nested, String),
+    Pair(usize, Nested),
+}
+
+#[test]
+fn enum_keypaths_tuple_variants() {
+    let one_keypath: KeyPath<TestTupleEnum, usize> = keypath![TestTupleEnum: VariantOne.0];
+    let two_keypath: KeyPath<TestTupleEnum, String> = keypath![TestTupleEnum: VariantTwo.1];
+    let two_keypath_deep: KeyPath<TestTupleEnum, f64> =
+        keypath![TestTupleEnum: VariantTwo.0.my_vector[0]];
+
+    assert_eq!(
+        one_keypath.path,
+        vec![
+            KeyPathElement::Variant {
+                key: "VariantOne".into(),
+                tag: VariantTagType::External
+            },
+            KeyPathElement::Field { key: "0".into() },
+        ]
+    );
+
+    assert_eq!(
+        two_keypath.path,
+        vec![
+            KeyPathElement::Variant {
+                key: "VariantTwo".into(),
+                tag: VariantTagType::External
+            },
+            KeyPathElement::Field { key: "1".into() },
+        ]
     );
 
     assert_eq!(
         two_keypath_deep.path,
         vec![
             KeyPathElement::Variant {
-                key: "VariantTwo",
+                key: "VariantTwo".into(),
                 tag: VariantTagType::External
             },
-            KeyPathElement::Field { key: "0" },
-            KeyPathElement::Field { key: "my_vector" },
+            KeyPathElement::Field { key: "0".into() },
+            KeyPathElement::Field {
+                key: "my_vector".into()
+            },
             KeyPathElement::Index { key: 0 },
         ]
     );
 }
 
+#[allow(dead_code)] // Only reflection is tested
+#[derive(Navigable)]
+struct ListOfTupleEnums {
+    items: Vec<TestTupleEnum>,
+}
+
+#[test]
+fn enum_keypaths_through_an_index_and_a_tuple_variant() {
+    let keypath: KeyPath<ListOfTupleEnums, f64> =
+        keypath![ListOfTupleEnums: items[1].VariantTwo.0.my_vector[3]];
+
+    assert_eq!(
+        keypath.path,
+        vec![
+            KeyPathElement::Field {
+                key: "items".into()
+            },
+            KeyPathElement::Index { key: 1 },
+            KeyPathElement::Variant {
+                key: "VariantTwo".into(),
+                tag: VariantTagType::External
+            },
+            KeyPathElement::Field { key: "0".into() },
+            KeyPathElement::Field {
+                key: "my_vector".into()
+            },
+            KeyPathElement::Index { key: 3 },
+        ]
+    );
+}
+
+#[test]
+fn enum_keypaths_through_a_later_tuple_variant_element() {
+    let keypath: KeyPath<TestTupleEnum, String> = keypath![TestTupleEnum: Pair.1.my_string];
+    let deeper_keypath: KeyPath<TestTupleEnum, f64> = keypath![TestTupleEnum: Pair.1.my_vector[2]];
+
+    assert_eq!(
+        keypath.path,
+        vec![
+            KeyPathElement::Variant {
+                key: "Pair".into(),
+                tag: VariantTagType::External
+            },
+            KeyPathElement::Field { key: "1".into() },
+            KeyPathElement::Field {
+                key: "my_string".into()
+            },
+        ]
+    );
+
+    assert_eq!(
+        deeper_keypath.path,
+        vec![
+            KeyPathElement::Variant {
+                key: "Pair".into(),
+                tag: VariantTagType::External
+            },
+            KeyPathElement::Field { key: "1".into() },
+            KeyPathElement::Field {
+                key: "my_vector".into()
+            },
+            KeyPathElement::Index { key: 2 },
+        ]
+    );
+}
+
 #[derive(Navigable)]
 #[allow(dead_code)]
 struct ThingWithMaps {
     string_counts: HashMap<&'static str, usize>,
     sparse_strings: BTreeMap<usize, String>,
+    by_initial: HashMap<char, Vec<String>>,
 }
 
 #[test]
@@ -369,10 +1221,10 @@ fn keypath_into_maps() {
         string_count.path,
         vec![
             KeyPathElement::Field {
-                key: "string_counts"
+                key: "string_counts".into()
             },
             KeyPathElement::StringKey {
-                key: "Hello".to_string()
+                key: "Hello".into()
             }
         ]
     );
@@ -381,9 +1233,716 @@ fn keypath_into_maps() {
         sparse_string.path,
         vec![
             KeyPathElement::Field {
-                key: "sparse_strings"
+                key: "sparse_strings".into()
             },
-            KeyPathElement::StringKey { key: 3.to_string() }
+            KeyPathElement::StringKey { key: "3".into() }
         ]
     );
 }
+
+#[test]
+fn typed_keypaths_display_like_untyped_ones() {
+    let variant = keypath![EnumTest: NestedVariant.nested.my_vector[1]];
+    let map_key = keypath![ThingWithMaps: by_initial['a'][0]];
+
+    assert_eq!(variant.to_string(), ".NestedVariant.nested.my_vector[1]");
+    assert_eq!(variant.to_string(), KeyPathFrom::from(variant).to_string());
+    assert_eq!(map_key.to_string(), ".by_initial[\"a\"][0]");
+    assert_eq!(KeyPath::<Test, Test>::unit().to_string(), ".");
+}
+
+#[test]
+fn map_keys_are_stringified_with_display() {
+    let string_key = keypath![ThingWithMaps: string_counts["Hello"]];
+    let integer_key = keypath![ThingWithMaps: sparse_strings[42]];
+    let char_key = keypath![ThingWithMaps: by_initial['a'][0]];
+
+    let string_key: KeyPathFrom<ThingWithMaps> = string_key.into();
+    let integer_key: KeyPathFrom<ThingWithMaps> = integer_key.into();
+    let char_key: KeyPathFrom<ThingWithMaps> = char_key.into();
+
+    assert_eq!(string_key.to_string(), ".string_counts[\"Hello\"]");
+    assert_eq!(integer_key.to_string(), ".sparse_strings[\"42\"]");
+    assert_eq!(char_key.to_string(), ".by_initial[\"a\"][0]");
+
+    assert_eq!(
+        char_key.path[1],
+        KeyPathElement::StringKey { key: "a".into() }
+    );
+}
+
+#[test]
+fn map_keys_serialize_the_same_with_either_representation() {
+    let keypath: KeyPathFrom<ThingWithMaps> = keypath![ThingWithMaps: by_initial['a'][0]].into();
+
+    let json = serde_json::to_value(&keypath.path).unwrap();
+
+    assert_eq!(
+        json,
+        json!([
+            { "type": "field", "key": "by_initial" },
+            { "type": "stringKey", "key": "a" },
+            { "type": "index", "key": 0 },
+        ])
+    );
+    assert_eq!(
+        serde_json::from_value::<Vec<KeyPathElement>>(json).unwrap(),
+        keypath.path
+    );
+}
+
+#[cfg(feature = "interned-keys")]
+#[test]
+fn cloned_map_keys_share_their_allocation() {
+    let keypath: KeyPathFrom<ThingWithMaps> =
+        keypath![ThingWithMaps: string_counts["Hello"]].into();
+    let cloned = keypath.clone();
+
+    let (KeyPathElement::StringKey { key }, KeyPathElement::StringKey { key: cloned_key }) =
+        (&keypath.path[1], &cloned.path[1])
+    else {
+        panic!("Expected string keys");
+    };
+
+    assert_eq!(key.as_ptr(), cloned_key.as_ptr());
+}
+
+#[test]
+fn debug_json_includes_the_root_type() {
+    let keypath: KeyPath<Test, f64> = Test::keypaths().my_nested.fields().my_vector.at(1);
+
+    let debug = keypath.to_debug_json();
+
+    // `type_name` doesn't promise an exact format, only the type's own name is stable
+    assert!(debug["root"]
+        .as_str()
+        .is_some_and(|root| root.ends_with("::Test")));
+    assert_eq!(debug["path"], serde_json::to_value(&keypath).unwrap());
+}
+
+#[derive(Serialize, Navigable)]
+struct Config {
+    name: &'static str,
+    limits: Limits,
+}
+
+#[derive(Serialize, Navigable)]
+struct Limits {
+    max_items: usize,
+}
+
+static CONFIG: Config = Config {
+    name: "default",
+    limits: Limits { max_items: 10 },
+};
+
+type StaticConfig = &'static Config;
+
+#[test]
+fn keypath_into_static_data() {
+    let keypath: KeyPath<StaticConfig, usize> = keypath![StaticConfig: limits.max_items];
+
+    assert_eq!(
+        keypath.path,
+        vec![
+            KeyPathElement::Field {
+                key: "limits".into()
+            },
+            KeyPathElement::Field {
+                key: "max_items".into()
+            },
+        ]
+    );
+    let pointer = keypath.as_from().to_json_pointer().unwrap();
+    assert_eq!(
+        serde_json::to_value(&CONFIG).unwrap().pointer(&pointer),
+        Some(&json!(10))
+    );
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+struct Document {
+    title: String,
+    paragraphs: Vec<String>,
+    tags: BTreeMap<String, usize>,
+}
+
+fn document() -> Document {
+    Document {
+        title: "Draft".to_string(),
+        paragraphs: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        tags: BTreeMap::from([("rust".to_string(), 1)]),
+    }
+}
+
+fn assert_undoes(change: ChangeOf<Document>) {
+    let mut data = document();
+    let inverse = change.invert(&data).unwrap();
+
+    data.apply_change(&change);
+    let changed = data.clone();
+    let redo = inverse.invert(&data).unwrap();
+    data.apply_change(&inverse);
+    assert_eq!(data, document());
+
+    data.apply_change(&redo);
+    assert_eq!(data, changed);
+}
+
+#[test]
+fn inverts_an_update() {
+    let change = Change::update(keypath![Document: title], "Final".to_string());
+
+    assert_eq!(
+        change.invert(&document()),
+        Some(ChangeOf::Update {
+            key_path: keypath![Document: title].into(),
+            value: json!("Draft"),
+        })
+    );
+    assert_undoes(change);
+}
+
+#[test]
+fn inverts_a_splice() {
+    let change = Change::splice(
+        keypath![Document: paragraphs],
+        vec!["x".to_string(), "y".to_string(), "z".to_string()],
+        1,
+        2,
+    );
+
+    assert_eq!(
+        change.invert(&document()),
+        Some(ChangeOf::Splice {
+            key_path: keypath![Document: paragraphs].into(),
+            value: vec![json!("b"), json!("c")],
+            start: 1,
+            replace: 3,
+        })
+    );
+    assert_undoes(change);
+}
+
+#[test]
+fn inverts_inserting_and_deleting_map_entries() {
+    let insert = Change::update(keypath![Document: tags["serde".to_string()]], 2);
+
+    assert_eq!(
+        insert.invert(&document()),
+        Some(ChangeOf::Delete {
+            key_path: keypath![Document: tags["serde".to_string()]].into(),
+        })
+    );
+    assert_undoes(insert);
+    assert_undoes(Change::delete(keypath![Document: tags["rust".to_string()]]));
+}
+
+#[test]
+fn cannot_invert_changes_at_unresolvable_paths() {
+    let out_of_bounds = Change::update(keypath![Document: paragraphs[5]], "x".to_string());
+    let beyond_the_end = Change::splice(keypath![Document: paragraphs], vec![], 2, 2);
+    let overflowing = Change::splice(keypath![Document: paragraphs], vec![], 1, usize::MAX);
+
+    assert_eq!(out_of_bounds.invert(&document()), None);
+    assert_eq!(beyond_the_end.invert(&document()), None);
+    assert_eq!(overflowing.invert(&document()), None);
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Navigable, KeyPathMutable)]
+struct Redacted {
+    text: String,
+}
+
+impl Serialize for Redacted {
+    fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        Err(serde::ser::Error::custom("redacted"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Navigable, KeyPathMutable)]
+struct Memo {
+    title: String,
+    body: Redacted,
+}
+
+#[test]
+fn inverts_without_serializing_the_rest_of_the_root() {
+    let memo = Memo {
+        title: "Draft".to_string(),
+        body: Redacted {
+            text: "secret".to_string(),
+        },
+    };
+    let retitle = Change::update(keypath![Memo: title], "Final".to_string());
+    let rewrite = ChangeOf::<Memo>::Update {
+        key_path: keypath![Memo: body].into(),
+        value: json!({ "text": "public" }),
+    };
+
+    assert_eq!(
+        retitle.invert(&memo),
+        Some(ChangeOf::Update {
+            key_path: keypath![Memo: title].into(),
+            value: json!("Draft"),
+        })
+    );
+    assert_eq!(rewrite.invert(&memo), None);
+}
+
+#[derive(Serialize, Navigable)]
+struct Workspace {
+    files: BTreeMap<String, File>,
+    layout: Layout,
+    panel: Panel,
+    recent: Vec<String>,
+}
+
+#[derive(Serialize, Navigable)]
+struct File {
+    name: String,
+    pinned: Option<bool>,
+}
+
+#[derive(Serialize, Navigable)]
+enum Layout {
+    Split { ratio: f64 },
+}
+
+#[derive(Serialize, Navigable)]
+#[serde(tag = "kind")]
+enum Panel {
+    Sidebar { width: usize },
+}
+
+#[allow(dead_code)] // Only reflection is tested
+#[derive(Serialize, Navigable)]
+#[serde(tag = "t", content = "c")]
+enum Adjacent {
+    Content { value: usize },
+}
+
+fn workspace() -> Workspace {
+    Workspace {
+        files: BTreeMap::from([(
+            "src/main.rs".to_string(),
+            File {
+                name: "main".to_string(),
+                pinned: Some(true),
+            },
+        )]),
+        layout: Layout::Split { ratio: 0.5 },
+        panel: Panel::Sidebar { width: 200 },
+        recent: vec!["a".to_string(), "b".to_string()],
+    }
+}
+
+fn json_patch_path<T: Serialize + 'static>(key_path: KeyPath<Workspace, T>, value: T) -> String {
+    let operations = Change::update(key_path, value)
+        .as_patch()
+        .to_json_patch()
+        .unwrap();
+
+    operations[0]["path"].as_str().unwrap().to_string()
+}
+
+#[test]
+fn json_patch_paths_point_where_serde_puts_the_values() {
+    let root = serde_json::to_value(workspace()).unwrap();
+
+    let name = json_patch_path(
+        keypath![Workspace: files["src/main.rs".to_string()].name],
+        String::new(),
+    );
+    let ratio = json_patch_path(keypath![Workspace: layout.Split.ratio], 0.0);
+    let width = json_patch_path(keypath![Workspace: panel.Sidebar.width], 0);
+    let recent = json_patch_path(keypath![Workspace: recent[1]], String::new());
+
+    assert_eq!(name, "/files/src~1main.rs/name");
+    assert_eq!(root.pointer(&name), Some(&json!("main")));
+    assert_eq!(ratio, "/layout/Split/ratio");
+    assert_eq!(root.pointer(&ratio), Some(&json!(0.5)));
+    assert_eq!(width, "/panel/width");
+    assert_eq!(root.pointer(&width), Some(&json!(200)));
+    assert_eq!(recent, "/recent/1");
+    assert_eq!(root.pointer(&recent), Some(&json!("b")));
+    assert_eq!(json_patch_path(KeyPath::unit(), workspace()), "");
+}
+
+#[test]
+fn json_patch_operations_for_each_kind_of_change() {
+    let file = keypath![Workspace: files["src/lib.rs".to_string()]];
+    let update = Change::update(keypath![Workspace: recent[0]], "c".to_string());
+    let insert = Change::update(
+        file.clone(),
+        File {
+            name: "lib".to_string(),
+            pinned: None,
+        },
+    );
+    let splice = Change::splice(
+        keypath![Workspace: recent],
+        vec!["x".to_string(), "y".to_string()],
+        1,
+        1,
+    );
+    let delete_entry = Change::delete(file);
+    let delete_option =
+        Change::delete(keypath![Workspace: files["src/main.rs".to_string()].pinned]);
+
+    assert_eq!(
+        update.as_patch().to_json_patch().unwrap(),
+        json!([{ "op": "replace", "path": "/recent/0", "value": "c" }])
+    );
+    assert_eq!(
+        insert.as_patch().to_json_patch().unwrap(),
+        json!([{
+            "op": "add",
+            "path": "/files/src~1lib.rs",
+            "value": { "name": "lib", "pinned": null }
+        }])
+    );
+    assert_eq!(
+        splice.as_patch().to_json_patch().unwrap(),
+        json!([
+            { "op": "remove", "path": "/recent/1" },
+            { "op": "add", "path": "/recent/1", "value": "x" },
+            { "op": "add", "path": "/recent/2", "value": "y" },
+        ])
+    );
+    assert_eq!(
+        delete_entry.as_patch().to_json_patch().unwrap(),
+        json!([{ "op": "remove", "path": "/files/src~1lib.rs" }])
+    );
+    assert_eq!(
+        delete_option.as_patch().to_json_patch().unwrap(),
+        json!([{ "op": "replace", "path": "/files/src~1main.rs/pinned", "value": null }])
+    );
+}
+
+#[test]
+fn no_json_patch_through_adjacently_tagged_variants() {
+    let change = Change::update(keypath![Adjacent: Content.value], 1);
+
+    assert!(change.as_patch().to_json_patch().is_err());
+}
+
+#[test]
+fn decodes_an_update_patch() {
+    let key_path = keypath![EnumTest: NestedVariant.nested.my_vector[1]];
+    let change = Change::update(key_path.clone(), 2.5);
+
+    let (path, payload) = change.as_patch().decode().unwrap();
+
+    assert_eq!(path, key_path.path);
+    assert_eq!(payload, PatchPayload::Update(json!(2.5)));
+}
+
+#[test]
+fn converts_valid_json_to_patches() {
+    let update = json!({
+        "type": "update",
+        "keyPath": [{"type": "field", "key": "title"}],
+        "value": "Draft",
+    });
+    let splice = json!({
+        "type": "splice",
+        "keyPath": [{"type": "field", "key": "paragraphs"}],
+        "value": ["a", "b"],
+        "start": 1,
+        "replace": 2,
+    });
+
+    assert_eq!(
+        Patch::try_from(update).unwrap(),
+        Patch::Update {
+            key_path: json!([{"type": "field", "key": "title"}]),
+            value: json!("Draft"),
+        }
+    );
+    assert_eq!(
+        Patch::try_from(splice).unwrap(),
+        Patch::Splice {
+            key_path: json!([{"type": "field", "key": "paragraphs"}]),
+            value: vec![json!("a"), json!("b")],
+            start: 1,
+            replace: 2,
+        }
+    );
+}
+
+#[test]
+fn reports_why_json_is_not_a_patch() {
+    let key_path = json!([{"type": "field", "key": "title"}]);
+
+    assert!(matches!(
+        Patch::try_from(json!(["update"])),
+        Err(PatchDecodeError::NotAnObject)
+    ));
+    assert!(matches!(
+        Patch::try_from(json!({ "keyPath": key_path, "value": 1 })),
+        Err(PatchDecodeError::MissingType)
+    ));
+    assert!(matches!(
+        Patch::try_from(json!({ "type": "insert", "keyPath": key_path, "value": 1 })),
+        Err(PatchDecodeError::UnknownType { tag }) if tag == json!("insert")
+    ));
+    assert!(matches!(
+        Patch::try_from(json!({ "type": 1, "keyPath": key_path })),
+        Err(PatchDecodeError::UnknownType { tag }) if tag == json!(1)
+    ));
+    assert!(matches!(
+        Patch::try_from(json!({ "type": "update", "keyPath": key_path })),
+        Err(PatchDecodeError::MissingField {
+            patch_type: "update",
+            field: "value"
+        })
+    ));
+    assert!(matches!(
+        Patch::try_from(json!({ "type": "splice", "keyPath": key_path, "value": [], "start": 0 })),
+        Err(PatchDecodeError::MissingField {
+            patch_type: "splice",
+            field: "replace"
+        })
+    ));
+    assert!(matches!(
+        Patch::try_from(json!({
+            "type": "splice",
+            "keyPath": key_path,
+            "value": [],
+            "start": -1,
+            "replace": 0,
+        })),
+        Err(PatchDecodeError::InvalidField {
+            patch_type: "splice",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn decodes_a_splice_patch() {
+    let patch = Patch::Splice {
+        key_path: json!([
+            {"type": "field", "key": "my_vector"},
+            {"type": "stringKey", "key": "first"},
+        ]),
+        value: vec![json!(1), json!(2)],
+        start: 1,
+        replace: 0,
+    };
+
+    let (path, payload) = patch.decode().unwrap();
+
+    assert_eq!(
+        path,
+        vec![
+            KeyPathElement::Field {
+                key: "my_vector".into()
+            },
+            KeyPathElement::StringKey {
+                key: "first".into()
+            },
+        ]
+    );
+    assert_eq!(
+        payload,
+        PatchPayload::Splice {
+            value: vec![json!(1), json!(2)],
+            start: 1,
+            replace: 0,
+        }
+    );
+
+    let patch = Patch::Update {
+        key_path: json!([{"type": "unknown"}]),
+        value: json!(null),
+    };
+
+    assert!(patch.decode().is_err());
+}
+
+#[test]
+fn decodes_names_no_type_knows_into_owned_keys() {
+    let patch = Patch::Delete {
+        key_path: json!([
+            {"type": "field", "key": "not_a_field_of_anything"},
+            {"type": "stringKey", "key": "some key"},
+        ]),
+    };
+
+    let (path, payload) = patch.decode().unwrap();
+
+    assert!(matches!(
+        path.as_slice(),
+        [
+            KeyPathElement::Field {
+                key: std::borrow::Cow::Owned(name)
+            },
+            KeyPathElement::StringKey { key },
+        ] if name == "not_a_field_of_anything" && &**key == "some key"
+    ));
+    assert_eq!(payload, PatchPayload::Delete);
+}
+
+#[test]
+fn hashed_keypath_as_map_key() {
+    let paths = || -> Vec<KeyPathFrom<Test>> {
+        vec![
+            keypath![Test: my_scalar].into(),
+            keypath![Test: my_vector[1]].into(),
+            keypath![Test: my_nested.my_string].into(),
+            keypath![Test: my_vector_of_nested[0].my_vector[2]].into(),
+        ]
+    };
+
+    let plain: HashMap<Vec<KeyPathElement>, usize> = paths()
+        .into_iter()
+        .enumerate()
+        .map(|(ix, p)| (p.path, ix))
+        .collect();
+    let hashed: HashMap<HashedKeyPath<Test>, usize> = paths()
+        .into_iter()
+        .enumerate()
+        .map(|(ix, p)| (p.into(), ix))
+        .collect();
+
+    for path in paths() {
+        let elements = path.path.clone();
+
+        assert_eq!(plain.get(&elements), hashed.get(&path.into()));
+    }
+
+    let missing: KeyPathFrom<Test> = keypath![Test: my_vector[2]].into();
+
+    assert_eq!(plain.get(&missing.path), None);
+    assert_eq!(hashed.get(&missing.into()), None);
+}
+
+#[test]
+fn typed_and_erased_keypaths_as_element_slices() {
+    fn depth(path: impl AsRef<[KeyPathElement]>) -> usize {
+        path.as_ref().len()
+    }
+
+    let typed = keypath![Test: my_nested.my_vector[1]];
+    let erased: KeyPathFrom<Test> = keypath![Test: my_nested.my_vector[1]].into();
+
+    assert_eq!(depth(&typed), 3);
+    assert_eq!(depth(&erased), 3);
+    assert_eq!(typed.as_ref(), erased.as_ref());
+}
+
+#[test]
+fn erasing_a_keypath_by_reference_keeps_it_usable() {
+    let typed = keypath![Test: my_nested.my_vector];
+
+    let erased = typed.as_from();
+    let index = typed.at(1);
+
+    assert!(erased == typed);
+    assert_eq!(index.path.len(), 3);
+    assert_eq!(index.path[..2], erased.path[..]);
+}
+
+#[derive(Serialize, Deserialize, Navigable, KeyPathMutable)]
+struct HybridState {
+    name: String,
+    extra: serde_json::Value,
+}
+
+#[test]
+fn builds_keypaths_into_json_at_runtime() {
+    let keypath = serde_json::Value::keypaths().key("items").fields().index(1);
+
+    assert_eq!(
+        keypath.path,
+        vec![
+            KeyPathElement::StringKey {
+                key: "items".into()
+            },
+            KeyPathElement::Index { key: 1 }
+        ]
+    );
+}
+
+#[test]
+fn applies_a_change_through_a_runtime_json_keypath() {
+    let mut state = HybridState {
+        name: "state".to_string(),
+        extra: json!({ "items": [{ "done": false }, { "done": false }] }),
+    };
+
+    let keypath = keypath![HybridState: extra]
+        .fields()
+        .key("items")
+        .fields()
+        .index(1)
+        .fields()
+        .key("done");
+
+    state.apply_change(&Change::update(keypath, json!(true)));
+
+    assert_eq!(
+        state.extra,
+        json!({ "items": [{ "done": false }, { "done": true }] })
+    );
+}
+
+fn key_path_element() -> impl proptest::strategy::Strategy<Value = KeyPathElement> {
+    use proptest::prelude::*;
+
+    let tag = prop_oneof![
+        Just(VariantTagType::External),
+        Just(VariantTagType::Internal),
+        Just(VariantTagType::Adjacent),
+        Just(VariantTagType::Untagged),
+    ];
+
+    prop_oneof![
+        any::<String>().prop_map(|key| KeyPathElement::Field { key: key.into() }),
+        (any::<String>(), tag).prop_map(|(key, tag)| KeyPathElement::Variant {
+            key: key.into(),
+            tag
+        }),
+        any::<usize>().prop_map(|key| KeyPathElement::Index { key }),
+        any::<String>().prop_map(|key| KeyPathElement::StringKey {
+            key: key.as_str().into()
+        }),
+    ]
+}
+
+proptest::proptest! {
+    #[test]
+    fn key_path_elements_survive_a_json_round_trip(
+        path in proptest::collection::vec(key_path_element(), 0..8)
+    ) {
+        let json = serde_json::to_string(&path).unwrap();
+        let deserialized: Vec<KeyPathElement> = serde_json::from_str(&json).unwrap();
+
+        proptest::prop_assert_eq!(deserialized, path);
+    }
+}
+
+#[test]
+fn deserialized_names_are_owned_by_their_element() {
+    let json = json!([
+        { "type": "field", "key": "any name at all" },
+        { "type": "variant", "key": "AnyVariant", "tag": "external" },
+    ]);
+
+    let path: Vec<KeyPathElement> = serde_json::from_value(json).unwrap();
+
+    assert!(matches!(
+        path.as_slice(),
+        [
+            KeyPathElement::Field {
+                key: std::borrow::Cow::Owned(_)
+            },
+            KeyPathElement::Variant {
+                key: std::borrow::Cow::Owned(_),
+                ..
+            },
+        ]
+    ));
+}
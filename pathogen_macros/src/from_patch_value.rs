@@ -0,0 +1,643 @@
+use darling::{
+    ast::{Fields, Style},
+    util::Override,
+    FromAttributes,
+};
+use proc_macro2::{Literal, TokenStream};
+use proc_macro_error::abort;
+use quote::{format_ident, quote};
+use syn::{Generics, Ident};
+
+use crate::{
+    field_name,
+    keypath_mutable::{
+        KeyPathMutableAttrs, KeyPathMutableEnumVariant, KeyPathMutableStructField,
+        KeyPathMutableType,
+    },
+    tag_type_from_serde_attrs, variant_name, ContainerSerdeAttrs, ItemSerdeAtrs, VariantTagType,
+};
+
+/// The derived `FromPatchValue` impl, which takes the patch value apart the way serde's derived
+/// `Deserialize` would, following the serde attributes the derive understands, and converts each
+/// field with its own `FromPatchValue`.
+///
+/// Fields the `KeyPathMutable` derive doesn't require to implement the trait, because they are
+/// skipped or read-only, are deserialized with serde instead.
+pub(crate) fn from_patch_value_impl(
+    input: &KeyPathMutableType,
+    generics: &Generics,
+) -> TokenStream {
+    let crate_name = crate::crate_name();
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let container_attrs = ContainerSerdeAttrs::from_attributes(&input.attrs);
+    let with_serde = KeyPathMutableAttrs::from_attributes(&input.attrs)
+        .is_ok_and(|attrs| attrs.should_skip_all());
+
+    let body = match container_attrs.as_ref().ok() {
+        Some(ContainerSerdeAttrs {
+            from: Some(proxy), ..
+        }) => quote! {
+            <#proxy as #crate_name::FromPatchValue>::from_patch_value(value).map(<Self as From<#proxy>>::from)
+        },
+        Some(ContainerSerdeAttrs {
+            try_from: Some(proxy),
+            ..
+        }) => quote! {
+            let proxy = <#proxy as #crate_name::FromPatchValue>::from_patch_value(value)?;
+            <Self as TryFrom<#proxy>>::try_from(proxy).map_err(#crate_name::__private::serde::de::Error::custom)
+        },
+        _ => match input.data.as_ref().take_struct() {
+            Some(fields) => struct_body(ident, &fields, &container_attrs, with_serde),
+            None => {
+                let variants = input.data.as_ref().take_enum().unwrap_or_default();
+                enum_body(ident, &variants, &container_attrs, with_serde)
+            }
+        },
+    };
+
+    quote! {
+        impl #impl_generics #crate_name::FromPatchValue for #ident #ty_generics #where_clause {
+            fn from_patch_value(
+                value: #crate_name::__private::serde_json::Value,
+            ) -> Result<Self, #crate_name::__private::serde_json::Error> {
+                #body
+            }
+        }
+    }
+}
+
+fn struct_body(
+    ident: &Ident,
+    fields: &Fields<&KeyPathMutableStructField>,
+    container_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
+    with_serde: bool,
+) -> TokenStream {
+    let fields: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| Field::new(index, field, container_attrs, with_serde))
+        .collect();
+    let attrs = container_attrs.as_ref().ok();
+
+    if attrs.is_some_and(ContainerSerdeAttrs::is_transparent) {
+        return transparent_body(&fields);
+    }
+
+    let constructor = quote! { Self };
+    match fields.first().map(|field| field.ident.is_some()) {
+        Some(true) => {
+            let default = attrs.and_then(|attrs| attrs.default.as_ref());
+            let deny_unknown_fields = attrs.is_some_and(ContainerSerdeAttrs::denies_unknown_fields);
+            let value = quote! { value };
+
+            named_fields(
+                &constructor,
+                &format!("struct {ident}"),
+                &fields,
+                Members::Value(&value),
+                default,
+                deny_unknown_fields,
+            )
+        }
+        Some(false) => {
+            let value = quote! { value };
+
+            unnamed_fields(
+                &constructor,
+                &format!("tuple struct {ident}"),
+                &fields,
+                &value,
+            )
+        }
+        None => {
+            let crate_name = crate::crate_name();
+            let expected = format!("unit struct {ident}");
+
+            quote! {
+                #crate_name::__private::from_patch_value::unit(value, #expected)?;
+                Ok(Self)
+            }
+        }
+    }
+}
+
+/// A transparent struct is converted from the same value as its only field which isn't skipped
+fn transparent_body(fields: &[Field]) -> TokenStream {
+    let value = quote! { value };
+    let inits = fields.iter().map(|field| {
+        let init = if field.skips_deserializing() {
+            field.skipped(None)
+        } else {
+            let convert = field.convert(&value);
+            quote! { #convert? }
+        };
+
+        match &field.ident {
+            Some(ident) => quote! { #ident: #init },
+            None => init,
+        }
+    });
+
+    if fields.first().is_some_and(|field| field.ident.is_some()) {
+        quote! { Ok(Self { #(#inits),* }) }
+    } else {
+        quote! { Ok(Self(#(#inits),*)) }
+    }
+}
+
+/// Where the members of an object with named fields come from
+enum Members<'a> {
+    /// A value which has to be an object
+    Value(&'a TokenStream),
+    /// The `members` binding, which is already the members of an object
+    Taken,
+}
+
+/// Build `constructor { .. }` from the members of an object, one field at a time
+fn named_fields(
+    constructor: &TokenStream,
+    expected: &str,
+    fields: &[Field],
+    members: Members,
+    default: Option<&Override<syn::Path>>,
+    deny_unknown_fields: bool,
+) -> TokenStream {
+    let crate_name = crate::crate_name();
+    let module = quote! { #crate_name::__private::from_patch_value };
+
+    let container_default = default.map(|default| match default {
+        Override::Inherit => quote! { let __default: Self = Default::default(); },
+        Override::Explicit(path) => quote! { let __default: Self = #path(); },
+    });
+
+    // Flattened fields take whatever the other fields leave, so they're converted last
+    let (flattened, fields_in_order): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .partition(|field| !field.skips_deserializing() && field.is_flattened());
+    let bindings = fields_in_order.iter().chain(&flattened).map(|field| {
+        let binding = field.binding();
+        let init = if field.skips_deserializing() {
+            field.skipped(default.map(|_| field.default_member()))
+        } else if field.is_flattened() {
+            let convert = field.convert(&quote! {
+                #crate_name::__private::serde_json::Value::Object(std::mem::take(&mut members))
+            });
+            quote! { #convert? }
+        } else {
+            let take = field.take(default.map(|_| field.default_member()));
+            quote! { #take? }
+        };
+
+        quote! { let #binding = #init; }
+    });
+
+    let names: Vec<_> = fields
+        .iter()
+        .filter(|field| !field.skips_deserializing() && !field.is_flattened())
+        .map(|field| &field.name)
+        .collect();
+    let takes_members = fields.iter().any(|field| !field.skips_deserializing());
+
+    let members = match (members, takes_members, deny_unknown_fields) {
+        (Members::Value(value), true, _) => quote! {
+            let mut members = #module::object(#value, #expected)?;
+        },
+        (Members::Value(value), false, true) => quote! {
+            let members = #module::object(#value, #expected)?;
+        },
+        (Members::Value(value), false, false) => quote! {
+            #module::object(#value, #expected)?;
+        },
+        (Members::Taken, true, _) => quote! { let mut members = members; },
+        (Members::Taken, false, true) => quote! {},
+        (Members::Taken, false, false) => quote! { let _ = members; },
+    };
+    let deny_unknown_fields = deny_unknown_fields.then(|| {
+        quote! { #module::deny_unknown_fields(members, &[#(#names),*])?; }
+    });
+
+    let inits = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let binding = field.binding();
+
+        quote! { #ident: #binding }
+    });
+
+    quote! {
+        #members
+        #container_default
+        #(#bindings)*
+        #deny_unknown_fields
+        Ok(#constructor { #(#inits),* })
+    }
+}
+
+/// Build `constructor(..)` from `value`, which is the only field's value for a newtype, and an
+/// array of the fields which aren't skipped otherwise
+fn unnamed_fields(
+    constructor: &TokenStream,
+    expected: &str,
+    fields: &[Field],
+    value: &TokenStream,
+) -> TokenStream {
+    let crate_name = crate::crate_name();
+
+    if let [field] = fields {
+        let init = if field.skips_deserializing() {
+            field.skipped(None)
+        } else {
+            let convert = field.convert(value);
+            quote! { #convert? }
+        };
+
+        return quote! { Ok(#constructor(#init)) };
+    }
+
+    let items: Vec<_> = fields
+        .iter()
+        .filter(|field| !field.skips_deserializing())
+        .map(Field::binding)
+        .collect();
+    let inits = fields.iter().map(|field| {
+        if field.skips_deserializing() {
+            return field.skipped(None);
+        }
+
+        let binding = field.binding();
+        let convert = field.convert(&quote! { #binding });
+        quote! { #convert? }
+    });
+
+    quote! {
+        let [#(#items),*] = #crate_name::__private::from_patch_value::array(#value, #expected)?;
+        Ok(#constructor(#(#inits),*))
+    }
+}
+
+fn enum_body(
+    ident: &Ident,
+    variants: &[&KeyPathMutableEnumVariant],
+    container_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
+    with_serde: bool,
+) -> TokenStream {
+    let crate_name = crate::crate_name();
+    let module = quote! { #crate_name::__private::from_patch_value };
+    let expected = format!("enum {ident}");
+    let tag_type = tag_type_from_serde_attrs(container_attrs);
+    let deny_unknown_fields = container_attrs
+        .as_ref()
+        .is_ok_and(ContainerSerdeAttrs::denies_unknown_fields);
+
+    let variants: Vec<_> = variants
+        .iter()
+        .map(|variant| Variant::new(ident, variant, container_attrs, with_serde))
+        .filter(|variant| !variant.serde_attrs.skips_deserializing())
+        .collect();
+
+    if let VariantTagType::Untagged = tag_type {
+        let attempts = variants.iter().map(|variant| {
+            let body = variant.untagged(deny_unknown_fields);
+
+            quote! {
+                let attempt = || -> Result<Self, #crate_name::__private::serde_json::Error> {
+                    let value = value.clone();
+                    #body
+                };
+                if let Ok(converted) = attempt() {
+                    return Ok(converted);
+                }
+            }
+        });
+        let enum_name = ident.to_string();
+
+        return quote! {
+            #(#attempts)*
+            Err(#module::no_untagged_variant_matched(#enum_name))
+        };
+    }
+
+    let attrs = container_attrs.as_ref().ok();
+    let tag = attrs.and_then(|attrs| attrs.tag.as_deref());
+    let content = attrs
+        .and_then(|attrs| attrs.content.as_ref())
+        .map(|content| content.as_str());
+    let internal = matches!(tag_type, VariantTagType::Internal);
+    let variant_and_content = match tag_type {
+        VariantTagType::External => quote! {
+            let (variant, content) = #module::externally_tagged(value, #expected)?;
+        },
+        VariantTagType::Internal => quote! {
+            let (variant, members) = #module::internally_tagged(value, #tag, #expected)?;
+        },
+        VariantTagType::Adjacent => quote! {
+            let (variant, content) = #module::adjacently_tagged(value, #tag, #content, #expected)?;
+        },
+        VariantTagType::Untagged => unreachable!(),
+    };
+
+    let arms = variants
+        .iter()
+        .filter(|variant| !variant.serde_attrs.is_other())
+        .map(|variant| {
+            let names = std::iter::once(&variant.name).chain(&variant.serde_attrs.alias);
+            let body = variant.tagged(internal, deny_unknown_fields);
+
+            quote! { #(#names)|* => { #body } }
+        });
+    let names = variants.iter().map(|variant| &variant.name);
+    let fallback = match variants
+        .iter()
+        .find(|variant| variant.serde_attrs.is_other())
+    {
+        Some(other) => {
+            let variant_ident = other.ident;
+            quote! { _ => Ok(Self::#variant_ident) }
+        }
+        None => quote! { variant => Err(#module::unknown_variant(variant, &[#(#names),*])) },
+    };
+
+    quote! {
+        #variant_and_content
+        match variant.as_str() {
+            #(#arms)*
+            #fallback
+        }
+    }
+}
+
+struct Variant<'a> {
+    ident: &'a Ident,
+    /// The variant's path, e.g. `Enum::Variant`, for error messages
+    path: String,
+    name: String,
+    serde_attrs: ItemSerdeAtrs,
+    fields: Vec<Field<'a>>,
+    style: Style,
+}
+
+impl<'a> Variant<'a> {
+    fn new(
+        enum_ident: &Ident,
+        variant: &'a KeyPathMutableEnumVariant,
+        container_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
+        with_serde: bool,
+    ) -> Self {
+        let serde_attrs = ItemSerdeAtrs::from_attributes(&variant.attrs);
+        let name = variant_name(&variant.ident, container_attrs, &serde_attrs);
+        let serde_attrs = serde_attrs.unwrap_or_else(|_| ItemSerdeAtrs::default());
+        if serde_attrs.with.is_some()
+            || serde_attrs.deserialize_with.is_some()
+            || serde_attrs.is_untagged()
+        {
+            abort!(
+                variant.ident.span(),
+                "derive(KeyPathMutable) can't convert patch values into variants with #[serde(with)], #[serde(deserialize_with)] or #[serde(untagged)]";
+                help = "add #[keypath_mutable(custom_from_patch_value)] to the enum and implement FromPatchValue by hand"
+            );
+        }
+
+        let kpm_attrs = KeyPathMutableAttrs::from_attributes(&variant.attrs);
+        let with_serde = with_serde
+            || kpm_attrs.is_ok_and(|attrs| attrs.should_skip() || attrs.should_skip_all());
+        let variant_attrs = ContainerSerdeAttrs::from_attributes(&variant.attrs);
+        let fields = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| Field::new(index, field, &variant_attrs, with_serde))
+            .collect();
+
+        Self {
+            ident: &variant.ident,
+            path: format!("{enum_ident}::{}", variant.ident),
+            name,
+            serde_attrs,
+            fields,
+            style: variant.fields.style,
+        }
+    }
+
+    /// Build the variant from its `content` (or the `members` left next to the tag of an
+    /// internally tagged enum)
+    fn tagged(&self, internal: bool, deny_unknown_fields: bool) -> TokenStream {
+        let crate_name = crate::crate_name();
+        let module = quote! { #crate_name::__private::from_patch_value };
+        let ident = self.ident;
+        let constructor = quote! { Self::#ident };
+
+        let content = |expected: &str| {
+            if internal {
+                quote! { #crate_name::__private::serde_json::Value::Object(members) }
+            } else {
+                quote! { #module::variant_content(content, #expected)? }
+            }
+        };
+
+        match self.style {
+            // The members next to the tag are ignored, like serde does
+            Style::Unit if internal => quote! {
+                let _ = members;
+                Ok(#constructor)
+            },
+            Style::Unit => quote! {
+                #module::unit_variant(content)?;
+                Ok(#constructor)
+            },
+            Style::Tuple if self.fields.len() == 1 => {
+                let value = content("newtype variant");
+
+                unnamed_fields(&constructor, "", &self.fields, &value)
+            }
+            Style::Tuple => {
+                let value = content("tuple variant");
+
+                unnamed_fields(
+                    &constructor,
+                    &format!("tuple variant {}", self.path),
+                    &self.fields,
+                    &value,
+                )
+            }
+            Style::Struct if internal => named_fields(
+                &constructor,
+                "",
+                &self.fields,
+                Members::Taken,
+                None,
+                deny_unknown_fields,
+            ),
+            Style::Struct => {
+                let value = content("struct variant");
+
+                named_fields(
+                    &constructor,
+                    &format!("struct variant {}", self.path),
+                    &self.fields,
+                    Members::Value(&value),
+                    None,
+                    deny_unknown_fields,
+                )
+            }
+        }
+    }
+
+    /// Try to build the variant from the whole `value` of an untagged enum
+    fn untagged(&self, deny_unknown_fields: bool) -> TokenStream {
+        let crate_name = crate::crate_name();
+        let ident = self.ident;
+        let constructor = quote! { Self::#ident };
+        let value = quote! { value };
+
+        match self.style {
+            Style::Unit => {
+                let expected = format!("unit variant {}", self.path);
+
+                quote! {
+                    #crate_name::__private::from_patch_value::unit(value, #expected)?;
+                    Ok(#constructor)
+                }
+            }
+            Style::Tuple => unnamed_fields(
+                &constructor,
+                &format!("tuple variant {}", self.path),
+                &self.fields,
+                &value,
+            ),
+            Style::Struct => named_fields(
+                &constructor,
+                &format!("struct variant {}", self.path),
+                &self.fields,
+                Members::Value(&value),
+                None,
+                deny_unknown_fields,
+            ),
+        }
+    }
+}
+
+struct Field<'a> {
+    index: usize,
+    ident: Option<&'a Ident>,
+    ty: &'a syn::Type,
+    /// The serialized name
+    name: String,
+    serde_attrs: ItemSerdeAtrs,
+    /// Deserialize with serde, because the field's type needn't be `KeyPathMutable`
+    with_serde: bool,
+}
+
+impl<'a> Field<'a> {
+    fn new(
+        index: usize,
+        field: &'a KeyPathMutableStructField,
+        container_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
+        with_serde: bool,
+    ) -> Self {
+        let serde_attrs = ItemSerdeAtrs::from_attributes(&field.attrs);
+        let name = match &field.ident {
+            Some(ident) => field_name(ident, container_attrs, &serde_attrs),
+            None => index.to_string(),
+        };
+        let kpm_attrs = KeyPathMutableAttrs::from_attributes(&field.attrs);
+
+        Self {
+            index,
+            ident: field.ident.as_ref(),
+            ty: &field.ty,
+            name,
+            serde_attrs: serde_attrs.unwrap_or_else(|_| ItemSerdeAtrs::default()),
+            with_serde: with_serde
+                || kpm_attrs.is_ok_and(|attrs| attrs.should_skip() || attrs.is_read_only()),
+        }
+    }
+
+    fn skips_deserializing(&self) -> bool {
+        self.serde_attrs.skips_deserializing()
+    }
+
+    fn is_flattened(&self) -> bool {
+        self.serde_attrs.is_flattened()
+    }
+
+    /// The local variable holding the converted field
+    fn binding(&self) -> Ident {
+        format_ident!("field{}", self.index)
+    }
+
+    /// The field of the container's default, for a container with `#[serde(default)]`
+    fn default_member(&self) -> TokenStream {
+        match self.ident {
+            Some(ident) => quote! { __default.#ident },
+            None => {
+                let index = Literal::usize_unsuffixed(self.index);
+                quote! { __default.#index }
+            }
+        }
+    }
+
+    /// An expression converting `value`, a present `serde_json::Value`, into the field
+    fn convert(&self, value: &TokenStream) -> TokenStream {
+        let crate_name = crate::crate_name();
+        let ty = self.ty;
+
+        if let Some(with) = &self.serde_attrs.with {
+            quote! { #with::deserialize(#value) }
+        } else if let Some(deserialize_with) = &self.serde_attrs.deserialize_with {
+            quote! { #deserialize_with(#value) }
+        } else if self.with_serde {
+            quote! { #crate_name::__private::serde_json::from_value::<#ty>(#value) }
+        } else {
+            quote! { <#ty as #crate_name::FromPatchValue>::from_patch_value(#value) }
+        }
+    }
+
+    /// The default of a field which isn't deserialized, from the field's `default`, the
+    /// container's default or `Default`
+    fn skipped(&self, container_default: Option<TokenStream>) -> TokenStream {
+        match (&self.serde_attrs.default, container_default) {
+            (Some(Override::Explicit(path)), _) => quote! { #path() },
+            (None, Some(member)) => member,
+            (Some(Override::Inherit), _) | (None, None) => quote! { Default::default() },
+        }
+    }
+
+    /// An expression taking the field out of `members` and converting it, or defaulting it if
+    /// it's missing
+    fn take(&self, container_default: Option<TokenStream>) -> TokenStream {
+        let crate_name = crate::crate_name();
+        let module = quote! { #crate_name::__private::from_patch_value };
+        let ty = self.ty;
+        let name = &self.name;
+        let aliases = &self.serde_attrs.alias;
+        let take = quote! { #module::take(&mut members, #name, &[#(#aliases),*]) };
+
+        let missing = match (&self.serde_attrs.default, container_default) {
+            (Some(Override::Explicit(path)), _) => Some(quote! { Ok(#path()) }),
+            (Some(Override::Inherit), _) => Some(quote! { Ok(Default::default()) }),
+            (None, Some(member)) => Some(quote! { Ok(#member) }),
+            (None, None) => None,
+        };
+        let custom = self.serde_attrs.with.is_some() || self.serde_attrs.deserialize_with.is_some();
+
+        match missing {
+            None if !custom && self.with_serde => {
+                quote! { #module::deserialize_field::<#ty>(#take, #name) }
+            }
+            None if !custom => quote! { #module::field::<#ty>(#take, #name) },
+            missing => {
+                let missing = missing.unwrap_or_else(|| {
+                    quote! { Err(#crate_name::__private::serde::de::Error::missing_field(#name)) }
+                });
+                let convert = self.convert(&quote! { value });
+
+                quote! {
+                    match #take {
+                        Some(value) => #convert,
+                        None => #missing,
+                    }
+                }
+            }
+        }
+    }
+}
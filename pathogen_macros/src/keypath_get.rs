@@ -0,0 +1,265 @@
+use darling::{
+    ast::{self, Fields},
+    FromAttributes, FromDeriveInput, FromField, FromVariant,
+};
+use proc_macro2::{Literal, TokenStream};
+use proc_macro_error::abort_call_site;
+use quote::{quote, ToTokens};
+use syn::{parse_quote, DeriveInput, Generics, Ident};
+
+use crate::{
+    field_name, keypath_mutable::KeyPathMutableAttrs, variant_name, ContainerSerdeAttrs,
+    ItemSerdeAtrs,
+};
+
+pub(crate) fn keypath_get_impl(input: &DeriveInput) -> TokenStream {
+    let input = match KeyPathGetType::from_derive_input(input) {
+        Ok(v) => v,
+        Err(e) => {
+            return e.write_errors();
+        }
+    };
+
+    quote!(#input)
+}
+
+#[derive(FromDeriveInput, Debug)]
+#[darling(forward_attrs(serde, keypath_mutable))]
+struct KeyPathGetType {
+    ident: Ident,
+    generics: Generics,
+    data: ast::Data<KeyPathGetEnumVariant, KeyPathGetStructField>,
+    attrs: Vec<syn::Attribute>,
+}
+
+#[derive(FromField, Debug)]
+#[darling(forward_attrs(serde))]
+struct KeyPathGetStructField {
+    ident: Option<Ident>,
+    attrs: Vec<syn::Attribute>,
+}
+
+#[derive(FromVariant, Debug)]
+#[darling(forward_attrs(serde))]
+struct KeyPathGetEnumVariant {
+    ident: Ident,
+    fields: darling::ast::Fields<KeyPathGetStructField>,
+    attrs: Vec<syn::Attribute>,
+}
+
+impl ToTokens for KeyPathGetType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let crate_name = super::crate_name();
+        let generics = self.generics();
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let ident = &self.ident;
+
+        let lookup = if let Some(fields) = self.data.as_ref().take_struct() {
+            Self::struct_lookup(fields, &self.attrs)
+        } else if let Some(variants) = self.data.as_ref().take_enum() {
+            Self::enum_lookup(&variants, &self.attrs)
+        } else {
+            abort_call_site!("derive(KeyPathGet) only supports structs and enums");
+        };
+
+        tokens.extend(quote! {
+            impl #impl_generics #crate_name::KeyPathGet for #ident #ty_generics #where_clause {
+                fn get_keypath(&self, keys: &[#crate_name::KeyPathElement]) -> Option<&dyn ::std::any::Any> {
+                    if keys.is_empty() {
+                        return Some(self);
+                    }
+
+                    #lookup
+                }
+            }
+        });
+    }
+}
+
+impl KeyPathGetType {
+    /// The generics of the type, with its type parameters bounded so that the fields using them
+    /// can be read
+    fn generics(&self) -> Generics {
+        let crate_name = super::crate_name();
+        let mut generics = self.generics.clone();
+        let params: Vec<_> = generics
+            .type_params()
+            .map(|param| param.ident.clone())
+            .collect();
+        if params.is_empty() {
+            return generics;
+        }
+
+        let where_clause = generics.make_where_clause();
+        for param in params {
+            where_clause
+                .predicates
+                .push(parse_quote! { #param: #crate_name::KeyPathGet });
+        }
+
+        generics
+    }
+
+    /// Dispatches on the `Field` element naming a field, like `KeyPathMutable` does
+    fn struct_lookup(
+        fields: Fields<&KeyPathGetStructField>,
+        attrs: &[syn::Attribute],
+    ) -> TokenStream {
+        let crate_name = super::crate_name();
+        let container_attrs = ContainerSerdeAttrs::from_attributes(attrs);
+
+        let match_arms: Vec<_> = fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| match f.ident.as_ref() {
+                Some(ident) => {
+                    let field_attrs = ItemSerdeAtrs::from_attributes(&f.attrs);
+                    let name = field_name(ident, &container_attrs, &field_attrs);
+
+                    quote! { #name => #crate_name::KeyPathGet::get_keypath(&self.#ident, &keys[1..]) }
+                }
+                None => {
+                    let name = i.to_string();
+                    let lit = Literal::usize_unsuffixed(i);
+
+                    quote! { #name => #crate_name::KeyPathGet::get_keypath(&self.#lit, &keys[1..]) }
+                }
+            })
+            .collect();
+
+        if match_arms.is_empty() {
+            return quote! { None };
+        }
+
+        quote! {
+            let #crate_name::KeyPathElement::Field { key } = &keys[0] else {
+                return None;
+            };
+
+            match key.as_ref() {
+                #( #match_arms, )*
+                _ => None,
+            }
+        }
+    }
+
+    /// Dispatches on the `Variant` element naming the current variant, followed by the `Field`
+    /// element naming a field of it. A keypath through any other variant points at nothing.
+    ///
+    /// With `#[keypath_mutable(direct_dispatch)]`, like `KeyPathMutable`, keypaths have no
+    /// elements for the variant and apply to the content of the current one.
+    fn enum_lookup(variants: &[&KeyPathGetEnumVariant], attrs: &[syn::Attribute]) -> TokenStream {
+        let crate_name = super::crate_name();
+        let serde_attrs = ContainerSerdeAttrs::from_attributes(attrs);
+
+        let dispatch_directly = KeyPathMutableAttrs::from_attributes(attrs)
+            .map(|attrs| attrs.should_dispatch_directly())
+            .unwrap_or(false);
+        if dispatch_directly {
+            let match_arms = variants.iter().map(|variant| {
+                let variant_name = &variant.ident;
+                if !variant.fields.is_tuple() {
+                    abort_call_site!("direct dispatch is only supported on tuple variants");
+                }
+                if variant.fields.len() != 1 {
+                    abort_call_site!(
+                        "tuple variants must have exactly one element to support direct dispatch"
+                    );
+                }
+
+                quote! {
+                    Self::#variant_name(value) => #crate_name::KeyPathGet::get_keypath(value, keys)
+                }
+            });
+
+            return quote! {
+                match self {
+                    #( #match_arms, )*
+                }
+            };
+        }
+
+        let match_arms: Vec<_> = variants
+            .iter()
+            .filter(|variant| !variant.fields.is_empty())
+            .map(|variant| Self::variant_match_arm(variant, &serde_attrs))
+            .collect();
+
+        if match_arms.is_empty() {
+            return quote! { None };
+        }
+
+        quote! {
+            let #crate_name::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return None;
+            };
+
+            let Some(#crate_name::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return None;
+            };
+
+            match self {
+                #( #match_arms, )*
+                _ => None,
+            }
+        }
+    }
+
+    fn variant_match_arm(
+        variant: &KeyPathGetEnumVariant,
+        serde_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
+    ) -> TokenStream {
+        let crate_name = super::crate_name();
+        let variant_attrs = ItemSerdeAtrs::from_attributes(&variant.attrs);
+        let variant_name_str = variant_name(&variant.ident, serde_attrs, &variant_attrs);
+        let variant_name = &variant.ident;
+
+        let (bindings, field_arms): (Vec<_>, Vec<_>) = if variant.fields.is_tuple() {
+            (0..variant.fields.len())
+                .map(|i| {
+                    let binding = Ident::new(&format!("value{}", i), variant_name.span());
+                    let name = i.to_string();
+
+                    (
+                        quote! { #binding },
+                        quote! { #name => #crate_name::KeyPathGet::get_keypath(#binding, &keys[2..]) },
+                    )
+                })
+                .unzip()
+        } else {
+            let variant_container_attrs = ContainerSerdeAttrs::from_attributes(&variant.attrs);
+
+            variant
+                .fields
+                .iter()
+                .map(|f| {
+                    let ident = f.ident.as_ref().expect("no ident for struct variant field");
+                    let field_attrs = ItemSerdeAtrs::from_attributes(&f.attrs);
+                    let name = field_name(ident, &variant_container_attrs, &field_attrs);
+
+                    (
+                        quote! { #ident },
+                        quote! { #name => #crate_name::KeyPathGet::get_keypath(#ident, &keys[2..]) },
+                    )
+                })
+                .unzip()
+        };
+
+        let pattern = if variant.fields.is_tuple() {
+            quote! { Self::#variant_name(#( #bindings ),*) }
+        } else {
+            quote! { Self::#variant_name { #( #bindings ),* } }
+        };
+
+        quote! {
+            #pattern if variant == #variant_name_str => match field_name.as_ref() {
+                #( #field_arms, )*
+                _ => None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "keypath_get.test.rs"]
+mod tests;
@@ -0,0 +1,178 @@
+use darling::FromDeriveInput;
+use quote::quote;
+use syn::parse_str;
+
+use super::KeyPathGetType;
+
+fn pretty_print(ts: &proc_macro2::TokenStream) -> String {
+    if let Ok(file) = syn::parse_file(&ts.to_string()) {
+        prettyplease::unparse(&file)
+    } else {
+        panic!("Invalid output to pretty_print: {:?}", ts.to_string())
+    }
+}
+
+#[test]
+fn struct_with_serde_rename() {
+    let input = r#"
+            #[derive(KeyPathGet)]
+            #[serde(rename_all = "camelCase")]
+            struct MyStruct {
+                first_field: usize,
+                #[serde(rename = "second")]
+                second_field: Vec<String>,
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = KeyPathGetType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::KeyPathGet for MyStruct {
+        fn get_keypath(
+            &self,
+            keys: &[pathogen::KeyPathElement],
+        ) -> Option<&dyn ::std::any::Any> {
+            if keys.is_empty() {
+                return Some(self);
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return None;
+            };
+            match key.as_ref() {
+                "firstField" => {
+                    pathogen::KeyPathGet::get_keypath(&self.first_field, &keys[1..])
+                }
+                "second" => pathogen::KeyPathGet::get_keypath(&self.second_field, &keys[1..]),
+                _ => None,
+            }
+        }
+    }
+    "###);
+}
+
+#[test]
+fn tuple_struct_with_a_type_parameter() {
+    let input = r#"
+            #[derive(KeyPathGet)]
+            struct Wrapper<T>(T, usize);
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = KeyPathGetType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl<T> pathogen::KeyPathGet for Wrapper<T>
+    where
+        T: pathogen::KeyPathGet,
+    {
+        fn get_keypath(
+            &self,
+            keys: &[pathogen::KeyPathElement],
+        ) -> Option<&dyn ::std::any::Any> {
+            if keys.is_empty() {
+                return Some(self);
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return None;
+            };
+            match key.as_ref() {
+                "0" => pathogen::KeyPathGet::get_keypath(&self.0, &keys[1..]),
+                "1" => pathogen::KeyPathGet::get_keypath(&self.1, &keys[1..]),
+                _ => None,
+            }
+        }
+    }
+    "###);
+}
+
+#[test]
+fn enum_with_all_kinds_of_variants() {
+    let input = r#"
+            #[derive(KeyPathGet)]
+            enum MyEnum {
+                Unit,
+                Tuple(usize, String),
+                #[serde(rename = "named")]
+                Struct { field: f64 },
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = KeyPathGetType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::KeyPathGet for MyEnum {
+        fn get_keypath(
+            &self,
+            keys: &[pathogen::KeyPathElement],
+        ) -> Option<&dyn ::std::any::Any> {
+            if keys.is_empty() {
+                return Some(self);
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return None;
+            };
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return None;
+            };
+            match self {
+                Self::Tuple(value0, value1) if variant == "Tuple" => {
+                    match field_name.as_ref() {
+                        "0" => pathogen::KeyPathGet::get_keypath(value0, &keys[2..]),
+                        "1" => pathogen::KeyPathGet::get_keypath(value1, &keys[2..]),
+                        _ => None,
+                    }
+                }
+                Self::Struct { field } if variant == "named" => {
+                    match field_name.as_ref() {
+                        "field" => pathogen::KeyPathGet::get_keypath(field, &keys[2..]),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+    }
+    "###);
+}
+
+#[test]
+fn enum_with_direct_dispatch() {
+    let input = r#"
+            #[derive(KeyPathGet)]
+            #[keypath_mutable(direct_dispatch)]
+            enum MyEnum {
+                First(First),
+                Second(Second),
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = KeyPathGetType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::KeyPathGet for MyEnum {
+        fn get_keypath(
+            &self,
+            keys: &[pathogen::KeyPathElement],
+        ) -> Option<&dyn ::std::any::Any> {
+            if keys.is_empty() {
+                return Some(self);
+            }
+            match self {
+                Self::First(value) => pathogen::KeyPathGet::get_keypath(value, keys),
+                Self::Second(value) => pathogen::KeyPathGet::get_keypath(value, keys),
+            }
+        }
+    }
+    "###);
+}
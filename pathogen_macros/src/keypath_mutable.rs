@@ -3,11 +3,14 @@ use darling::{
     FromAttributes, FromDeriveInput, FromField, FromVariant,
 };
 use proc_macro2::{Literal, TokenStream};
-use proc_macro_error::abort_call_site;
+use proc_macro_error::{abort, abort_call_site};
 use quote::{quote, ToTokens};
-use syn::{spanned::Spanned, DeriveInput, Ident};
+use syn::{parse_quote, spanned::Spanned, DeriveInput, Generics, Ident};
 
-use crate::{field_name, ContainerSerdeAttrs, ItemSerdeAtrs};
+use crate::{
+    field_name, from_patch_value::from_patch_value_impl, tag_type_from_serde_attrs, variant_name,
+    ContainerSerdeAttrs, ItemSerdeAtrs, VariantTagType,
+};
 
 pub(crate) fn keypath_mutable_impl(input: &DeriveInput) -> TokenStream {
     let input = match KeyPathMutableType::from_derive_input(input) {
@@ -22,22 +25,92 @@ pub(crate) fn keypath_mutable_impl(input: &DeriveInput) -> TokenStream {
 
 #[derive(FromDeriveInput, Debug)]
 #[darling(forward_attrs(serde, keypath_mutable))]
-struct KeyPathMutableType {
-    ident: Ident,
-    data: ast::Data<KeyPathMutableEnumVariant, KeyPathMutableStructField>,
-    attrs: Vec<syn::Attribute>,
+pub(crate) struct KeyPathMutableType {
+    pub(crate) ident: Ident,
+    generics: Generics,
+    pub(crate) data: ast::Data<KeyPathMutableEnumVariant, KeyPathMutableStructField>,
+    pub(crate) attrs: Vec<syn::Attribute>,
 }
 
 #[derive(FromField, Debug)]
 #[darling(forward_attrs(serde, keypath_mutable))]
-struct KeyPathMutableStructField {
-    ident: Option<Ident>,
-    attrs: Vec<syn::Attribute>,
+pub(crate) struct KeyPathMutableStructField {
+    pub(crate) ident: Option<Ident>,
+    pub(crate) ty: syn::Type,
+    pub(crate) attrs: Vec<syn::Attribute>,
+}
+
+impl KeyPathMutableStructField {
+    /// Trait objects can't implement `KeyPathMutable` (which requires `Sized` and
+    /// deserialization), so point at them directly instead of failing inside `patch_keypath`
+    fn abort_if_trait_object(&self) {
+        if let Some(trait_object) = find_trait_object(&self.ty) {
+            abort!(
+                trait_object.span(),
+                "trait objects can't be mutated by keypath";
+                help = "add #[keypath_mutable(skip)] to the field, or implement KeyPathMutable for the containing type by hand"
+            );
+        }
+    }
+
+    /// With the `strict-skip` feature, reject skipping a named field which serde still
+    /// deserializes while denying unknown fields. Updating the whole value would accept the field,
+    /// but changes addressing it would fail as unknown.
+    fn abort_if_skip_mismatch(
+        &self,
+        container_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
+    ) {
+        if !cfg!(feature = "strict-skip") {
+            return;
+        }
+
+        let Some(ident) = self.ident.as_ref() else {
+            return;
+        };
+
+        let denies_unknown_fields = container_attrs
+            .as_ref()
+            .is_ok_and(|attrs| attrs.denies_unknown_fields());
+        let serde_skips = ItemSerdeAtrs::from_attributes(&self.attrs)
+            .is_ok_and(|attrs| attrs.skips_deserializing());
+
+        if denies_unknown_fields && !serde_skips {
+            abort!(
+                ident.span(),
+                "field is skipped by keypath_mutable, but serde still deserializes it under deny_unknown_fields";
+                help = "use #[keypath_mutable(read_only)] to reject changes to it explicitly, or skip it with #[serde(skip)] too"
+            );
+        }
+    }
+}
+
+fn find_trait_object(ty: &syn::Type) -> Option<&syn::TypeTraitObject> {
+    match ty {
+        syn::Type::TraitObject(trait_object) => Some(trait_object),
+        syn::Type::Array(array) => find_trait_object(&array.elem),
+        syn::Type::Group(group) => find_trait_object(&group.elem),
+        syn::Type::Paren(paren) => find_trait_object(&paren.elem),
+        syn::Type::Ptr(ptr) => find_trait_object(&ptr.elem),
+        syn::Type::Reference(reference) => find_trait_object(&reference.elem),
+        syn::Type::Slice(slice) => find_trait_object(&slice.elem),
+        syn::Type::Tuple(tuple) => tuple.elems.iter().find_map(find_trait_object),
+        syn::Type::Path(path) => path.path.segments.iter().find_map(|segment| {
+            let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+                return None;
+            };
+
+            arguments.args.iter().find_map(|argument| match argument {
+                syn::GenericArgument::Type(ty) => find_trait_object(ty),
+                _ => None,
+            })
+        }),
+        _ => None,
+    }
 }
 
 #[derive(FromAttributes, Debug)]
 #[darling(attributes(keypath_mutable))]
-struct KeyPathMutableAttrs {
+pub(crate) struct KeyPathMutableAttrs {
     /// Directs the macro to use "direct dispatch".
     ///
     /// This means the keypath won't include elements for identifying which
@@ -46,28 +119,50 @@ struct KeyPathMutableAttrs {
 
     skip: Option<bool>,
     skip_all: Option<bool>,
+
+    /// Reject changes addressing this field with a `ReadOnlyField` error.
+    ///
+    /// Unlike `skip`, which makes the field unknown, this explicitly signals that the field
+    /// exists but may not be changed, e.g. an `id`.
+    read_only: Option<bool>,
+
+    /// The type implements `FromPatchValue` by hand, instead of having it derived to convert
+    /// each field with its own `FromPatchValue`.
+    custom_from_patch_value: Option<bool>,
+
+    /// The path to pathogen in the generated code, e.g. `"::my_reexport"`, instead of `pathogen`
+    #[darling(rename = "crate")]
+    crate_path: Option<syn::Path>,
 }
 
 impl KeyPathMutableAttrs {
-    fn should_dispatch_directly(&self) -> bool {
+    pub(crate) fn should_dispatch_directly(&self) -> bool {
         self.direct_dispatch.unwrap_or(false)
     }
 
-    fn should_skip(&self) -> bool {
+    pub(crate) fn should_skip(&self) -> bool {
         self.skip.unwrap_or(false)
     }
 
-    fn should_skip_all(&self) -> bool {
+    pub(crate) fn should_skip_all(&self) -> bool {
         self.skip_all.unwrap_or(false)
     }
+
+    pub(crate) fn is_read_only(&self) -> bool {
+        self.read_only.unwrap_or(false)
+    }
+
+    fn has_custom_from_patch_value(&self) -> bool {
+        self.custom_from_patch_value.unwrap_or(false)
+    }
 }
 
 #[derive(FromVariant, Debug)]
 #[darling(forward_attrs(serde, keypath_mutable))]
-struct KeyPathMutableEnumVariant {
-    ident: Ident,
-    fields: darling::ast::Fields<KeyPathMutableStructField>,
-    attrs: Vec<syn::Attribute>,
+pub(crate) struct KeyPathMutableEnumVariant {
+    pub(crate) ident: Ident,
+    pub(crate) fields: darling::ast::Fields<KeyPathMutableStructField>,
+    pub(crate) attrs: Vec<syn::Attribute>,
 }
 
 impl KeyPathMutableEnumVariant {
@@ -78,12 +173,33 @@ impl KeyPathMutableEnumVariant {
 
 impl ToTokens for KeyPathMutableType {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let crate_path = KeyPathMutableAttrs::from_attributes(&self.attrs)
+            .ok()
+            .and_then(|attrs| attrs.crate_path);
+
+        super::with_crate_path(crate_path, || self.expand(tokens))
+    }
+}
+
+impl KeyPathMutableType {
+    fn expand(&self, tokens: &mut TokenStream) {
+        tokens.extend(self.derive_from_patch_value());
+
         if let Some(fields) = self.data.as_ref().take_struct() {
-            return Self::derive_struct(tokens, &self.ident, fields, &self.attrs);
+            return Self::derive_struct(tokens, &self.ident, &self.generics(), fields, &self.attrs);
         }
 
         if let Some(variants) = self.data.as_ref().take_enum() {
-            return Self::derive_enum(tokens, &self.ident, variants, &self.attrs);
+            // An uninhabited enum has no value an update could ever deserialize into
+            if variants.is_empty() {
+                abort!(
+                    self.ident.span(),
+                    "derive(KeyPathMutable) doesn't support enums without variants";
+                    help = "an enum without variants has no values to mutate, remove the derive"
+                );
+            }
+
+            return Self::derive_enum(tokens, &self.ident, &self.generics(), variants, &self.attrs);
         }
 
         abort_call_site!("derive(KeyPathMutable) only supports structs");
@@ -91,13 +207,51 @@ impl ToTokens for KeyPathMutableType {
 }
 
 impl KeyPathMutableType {
+    /// The generics of the type, with its type parameters bounded so that the fields using them
+    /// can be mutated and the whole value can be replaced
+    fn generics(&self) -> Generics {
+        let crate_name = super::crate_name();
+        let mut generics = self.generics.clone();
+        let params: Vec<_> = generics
+            .type_params()
+            .map(|param| param.ident.clone())
+            .collect();
+        if params.is_empty() {
+            return generics;
+        }
+
+        let where_clause = generics.make_where_clause();
+        for param in params {
+            where_clause.predicates.push(parse_quote! {
+                #param: #crate_name::KeyPathMutable + #crate_name::__private::serde::de::DeserializeOwned
+            });
+        }
+
+        generics
+    }
+
+    /// Replacing the whole value converts the patch value with `FromPatchValue`, which is
+    /// derived field by field unless the type opts out to implement it by hand.
+    fn derive_from_patch_value(&self) -> TokenStream {
+        let custom = KeyPathMutableAttrs::from_attributes(&self.attrs)
+            .is_ok_and(|attrs| attrs.has_custom_from_patch_value());
+        if custom {
+            return quote! {};
+        }
+
+        from_patch_value_impl(self, &self.generics())
+    }
+
     fn derive_struct(
         tokens: &mut TokenStream,
         ident: &Ident,
+        generics: &Generics,
         fields: Fields<&KeyPathMutableStructField>,
         attrs: &[syn::Attribute],
     ) {
         let crate_name = super::crate_name();
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let self_ty = quote! { #ident #ty_generics };
         let container_attrs = ContainerSerdeAttrs::from_attributes(attrs);
         let kpm_attrs = KeyPathMutableAttrs::from_attributes(attrs);
         let skip_all = kpm_attrs
@@ -105,72 +259,134 @@ impl KeyPathMutableType {
             .map(|it| it.should_skip_all())
             .unwrap_or(false);
 
-        let match_arms: Vec<_> = fields
+        let ((match_arms, validate_arms), splice_arms): ((Vec<_>, Vec<_>), Vec<_>) = fields
             .into_iter()
             .enumerate()
             .filter_map(|(i, f)| {
-                if KeyPathMutableAttrs::from_attributes(&f.attrs)
-                    .unwrap()
-                    .should_skip()
-                {
+                let field_kpm_attrs = KeyPathMutableAttrs::from_attributes(&f.attrs).unwrap();
+                if field_kpm_attrs.should_skip() {
+                    f.abort_if_skip_mismatch(&container_attrs);
                     return None;
                 };
 
-                Some(if let Some(ident) = f.ident.as_ref() {
+                let ty = &f.ty;
+                let (name, patch) = if let Some(ident) = f.ident.as_ref() {
                     // Structs
                     let field_attrs = ItemSerdeAtrs::from_attributes(&f.attrs);
                     let ident_name = field_name(ident, &container_attrs, &field_attrs);
 
-                    quote! { #ident_name => self.#ident.patch_keypath(&keys[1..], patch) }
+                    (ident_name, quote! { self.#ident.patch_keypath(&keys[1..], patch) })
                 } else {
                     // Tuple structs
+                    //
+                    // serde serializes a newtype as just its content, whether `transparent` or not,
+                    // so "0" addresses that whole value and an empty keypath replaces it with a bare
+                    // inner value
                     let lit = Literal::usize_unsuffixed(i);
-                    let lit_name = i.to_string();
 
-                    quote! { #lit_name => self.#lit.patch_keypath(&keys[1..], patch) }
+                    (i.to_string(), quote! { self.#lit.patch_keypath(&keys[1..], patch) })
+                };
+
+                Some(if field_kpm_attrs.is_read_only() {
+                    let read_only = quote! {
+                        #name => Err(#crate_name::KeyPathError::read_only_field::<#self_ty>(#name))
+                    };
+
+                    ((read_only.clone(), read_only.clone()), read_only)
+                } else {
+                    if !skip_all {
+                        f.abort_if_trait_object();
+                    }
+
+                    (
+                        (
+                            quote! { #name => #patch },
+                            quote! { #name => <#ty as #crate_name::KeyPathMutable>::validate_path(&keys[1..]) },
+                        ),
+                        quote! { #name => <#ty as #crate_name::KeyPathMutable>::validate_splice(&keys[1..]) },
+                    )
                 })
             })
-            .collect();
+            .unzip();
 
-        let fields_match = if skip_all || match_arms.is_empty() {
-            quote! {
-                Err(#crate_name::KeyPathError::unknown_field::<#ident>(key))
-            }
-        } else {
-            quote! {
-                match key {
-                    #( #match_arms ),*,
-                    _ => Err(#crate_name::KeyPathError::unknown_field::<#ident>(key)),
-                }
-            }
-        };
+        let fields_match = Self::struct_fields_match(&self_ty, skip_all, &match_arms);
+        let validate_fields_match = Self::struct_fields_match(&self_ty, skip_all, &validate_arms);
+        let splice_fields_match = Self::struct_fields_match(&self_ty, skip_all, &splice_arms);
 
         tokens.extend(quote! {
-            impl #crate_name::KeyPathMutable for #ident {
+            impl #impl_generics #crate_name::KeyPathMutable for #ident #ty_generics #where_clause {
                 fn patch_keypath(&mut self, keys: &[#crate_name::KeyPathElement], patch: #crate_name::Patch) -> Result<(), #crate_name::KeyPathError> {
 
                     if keys.is_empty() {
-                        return if let #crate_name::Patch::Update { value, .. } = patch {
-                            *self = serde_json::from_value(value).map_err(#crate_name::KeyPathError::from_deserialization_error::<#ident>)?;
-                            Ok(())
-                        } else {
-                            Err(#crate_name::KeyPathError::cannot_splice_type::<#ident>())
+                        return match patch {
+                            #crate_name::Patch::Update { value, .. } => {
+                                *self = <#self_ty as #crate_name::FromPatchValue>::from_patch_value(value).map_err(#crate_name::KeyPathError::from_deserialization_error::<#self_ty>)?;
+                                Ok(())
+                            }
+                            #crate_name::Patch::Splice { .. } => Err(#crate_name::KeyPathError::splice_on_non_list::<#self_ty>()),
+                            #crate_name::Patch::Delete { .. } => Err(#crate_name::KeyPathError::cannot_delete::<#self_ty>()),
                         };
                     }
 
-                    let #crate_name::KeyPathElement::Field { key } = keys[0] else {
-                        return Err(#crate_name::KeyPathError::must_mutate_struct_with_field::<#ident>());
+                    let #crate_name::KeyPathElement::Field { key } = &keys[0] else {
+                        return Err(#crate_name::KeyPathError::must_mutate_struct_with_field::<#self_ty>());
                     };
 
                     #fields_match
                 }
+
+                fn validate_path(keys: &[#crate_name::KeyPathElement]) -> Result<(), #crate_name::KeyPathError> {
+                    if keys.is_empty() {
+                        return Ok(());
+                    }
+
+                    let #crate_name::KeyPathElement::Field { key } = &keys[0] else {
+                        return Err(#crate_name::KeyPathError::must_mutate_struct_with_field::<#self_ty>());
+                    };
+
+                    #validate_fields_match
+                }
+
+                fn validate_splice(keys: &[#crate_name::KeyPathElement]) -> Result<(), #crate_name::KeyPathError> {
+                    if keys.is_empty() {
+                        return Err(#crate_name::KeyPathError::splice_on_non_list::<#self_ty>());
+                    }
+
+                    let #crate_name::KeyPathElement::Field { key } = &keys[0] else {
+                        return Err(#crate_name::KeyPathError::must_mutate_struct_with_field::<#self_ty>());
+                    };
+
+                    #splice_fields_match
+                }
             }
         })
     }
 
+    fn struct_fields_match(
+        self_ty: &TokenStream,
+        skip_all: bool,
+        match_arms: &[TokenStream],
+    ) -> TokenStream {
+        let crate_name = super::crate_name();
+
+        if skip_all || match_arms.is_empty() {
+            quote! {
+                Err(#crate_name::KeyPathError::unknown_field::<#self_ty>(key))
+            }
+        } else {
+            quote! {
+                match key.as_ref() {
+                    #( #match_arms ),*,
+                    _ => Err(#crate_name::KeyPathError::unknown_field::<#self_ty>(key)),
+                }
+            }
+        }
+    }
+
     fn derive_enum(
         tokens: &mut TokenStream,
         ident: &Ident,
+        generics: &Generics,
         variants: Vec<&KeyPathMutableEnumVariant>,
         attrs: &[syn::Attribute],
     ) {
@@ -179,6 +395,14 @@ impl KeyPathMutableType {
         let kpm_attrs = KeyPathMutableAttrs::from_attributes(attrs).unwrap();
         let dispatch_directly = kpm_attrs.should_dispatch_directly();
 
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let self_ty = quote! { #ident #ty_generics };
+
+        let validation =
+            Self::enum_validation(&self_ty, &variants, &kpm_attrs, &serde_attrs, false);
+        let splice_validation =
+            Self::enum_validation(&self_ty, &variants, &kpm_attrs, &serde_attrs, true);
+
         let dispatch = if dispatch_directly {
             let match_arms = variants.into_iter().map(|variant| {
                 let kpm_attrs = KeyPathMutableAttrs::from_attributes(&variant.attrs).unwrap();
@@ -216,24 +440,37 @@ impl KeyPathMutableType {
 
             let match_statement = if kpm_attrs.should_skip_all() {
                 quote! {
-                    Err(#crate_name::KeyPathError::unknown_variant_or_field::<#ident>(variant, field_name))
+                    Err(#crate_name::KeyPathError::unknown_variant_or_field::<#self_ty>(variant, field_name))
                 }
             } else {
                 quote! {
                     match self {
                         #(#match_arms),*
-                        _ => Err(#crate_name::KeyPathError::unknown_variant_or_field::<#ident>(variant, field_name)),
+                        _ => Err(#crate_name::KeyPathError::unknown_variant_or_field::<#self_ty>(variant, field_name)),
                     }
                 }
             };
 
+            let serialized_variant = Self::serialized_variant(&serde_attrs);
+
             quote! {
-                let #crate_name::KeyPathElement::Variant { key: variant, .. } = keys[0] else {
-                    return Err(#crate_name::KeyPathError::must_mutate_enum_with_variant::<#ident>());
+                let #crate_name::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                    return Err(#crate_name::KeyPathError::must_mutate_enum_with_variant::<#self_ty>());
                 };
 
-                let #crate_name::KeyPathElement::Field { key: field_name } = keys[1] else {
-                    return Err(#crate_name::KeyPathError::must_mutate_enum_variant_with_field::<#ident>(variant));
+                if keys.len() == 1 {
+                    return match patch {
+                        #crate_name::Patch::Update { value, .. } => {
+                            *self = <#self_ty as #crate_name::FromPatchValue>::from_patch_value(#serialized_variant).map_err(#crate_name::KeyPathError::from_deserialization_error::<#self_ty>)?;
+                            Ok(())
+                        }
+                        #crate_name::Patch::Splice { .. } => Err(#crate_name::KeyPathError::splice_on_non_list::<#self_ty>()),
+                        #crate_name::Patch::Delete { .. } => Err(#crate_name::KeyPathError::cannot_delete::<#self_ty>()),
+                    };
+                }
+
+                let #crate_name::KeyPathElement::Field { key: field_name } = &keys[1] else {
+                    return Err(#crate_name::KeyPathError::must_mutate_enum_variant_with_field::<#self_ty>(variant));
                 };
 
                 #match_statement
@@ -241,32 +478,267 @@ impl KeyPathMutableType {
         };
 
         tokens.extend(quote! {
-            impl #crate_name::KeyPathMutable for #ident {
+            impl #impl_generics #crate_name::KeyPathMutable for #ident #ty_generics #where_clause {
                 fn patch_keypath(&mut self, keys: &[#crate_name::KeyPathElement], patch: #crate_name::Patch) -> Result<(), #crate_name::KeyPathError> {
                     if keys.is_empty() {
-                        return if let #crate_name::Patch::Update { value, .. } = patch {
-                            *self = serde_json::from_value(value).map_err(#crate_name::KeyPathError::from_deserialization_error::<#ident>)?;
-                            Ok(())
-                        } else {
-                            Err(#crate_name::KeyPathError::cannot_splice_type::<#ident>())
+                        return match patch {
+                            #crate_name::Patch::Update { value, .. } => {
+                                *self = <#self_ty as #crate_name::FromPatchValue>::from_patch_value(value).map_err(#crate_name::KeyPathError::from_deserialization_error::<#self_ty>)?;
+                                Ok(())
+                            }
+                            #crate_name::Patch::Splice { .. } => Err(#crate_name::KeyPathError::splice_on_non_list::<#self_ty>()),
+                            #crate_name::Patch::Delete { .. } => Err(#crate_name::KeyPathError::cannot_delete::<#self_ty>()),
                         };
                     }
 
                     #dispatch
                 }
+
+                fn validate_path(keys: &[#crate_name::KeyPathElement]) -> Result<(), #crate_name::KeyPathError> {
+                    if keys.is_empty() {
+                        return Ok(());
+                    }
+
+                    #validation
+                }
+
+                fn validate_splice(keys: &[#crate_name::KeyPathElement]) -> Result<(), #crate_name::KeyPathError> {
+                    if keys.is_empty() {
+                        return Err(#crate_name::KeyPathError::splice_on_non_list::<#self_ty>());
+                    }
+
+                    #splice_validation
+                }
             }
         });
     }
 
+    /// An expression for the serialized form of the enum, from the serialized content of
+    /// `variant` in `value`, i.e. what a keypath ending at the variant points at.
+    ///
+    /// The content of an internally tagged variant sits next to the tag, so the tag is (re)set
+    /// to match the keypath.
+    fn serialized_variant(
+        serde_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
+    ) -> TokenStream {
+        let crate_name = super::crate_name();
+        let tag = serde_attrs
+            .as_ref()
+            .ok()
+            .and_then(|attrs| attrs.tag.as_deref());
+        let content = serde_attrs
+            .as_ref()
+            .ok()
+            .and_then(|attrs| attrs.content.as_ref())
+            .map(|content| content.as_str());
+
+        match tag_type_from_serde_attrs(serde_attrs) {
+            VariantTagType::External => quote! {
+                {
+                    let mut map = #crate_name::__private::serde_json::Map::new();
+                    map.insert(variant.to_string(), value);
+                    #crate_name::__private::serde_json::Value::Object(map)
+                }
+            },
+            VariantTagType::Internal => quote! {
+                {
+                    let tag = #crate_name::__private::serde_json::Value::from(variant.as_ref());
+                    match value {
+                        #crate_name::__private::serde_json::Value::Object(mut map) => {
+                            map.insert(#tag.to_string(), tag);
+                            #crate_name::__private::serde_json::Value::Object(map)
+                        }
+                        // A variant without content is serialized as just the tag
+                        #crate_name::__private::serde_json::Value::Null => {
+                            let mut map = #crate_name::__private::serde_json::Map::new();
+                            map.insert(#tag.to_string(), tag);
+                            #crate_name::__private::serde_json::Value::Object(map)
+                        }
+                        value => value,
+                    }
+                }
+            },
+            VariantTagType::Adjacent => quote! {
+                {
+                    let mut map = #crate_name::__private::serde_json::Map::new();
+                    map.insert(#tag.to_string(), #crate_name::__private::serde_json::Value::from(variant.as_ref()));
+                    map.insert(#content.to_string(), value);
+                    #crate_name::__private::serde_json::Value::Object(map)
+                }
+            },
+            VariantTagType::Untagged => quote! { value },
+        }
+    }
+
+    /// The body of `validate_path`, or `validate_splice` if `splice` is set, for an enum, once
+    /// the keys are known not to be empty.
+    ///
+    /// Which variant a directly dispatched path applies to depends on the value, so it is valid
+    /// if it is valid for any of the variants.
+    fn enum_validation(
+        self_ty: &TokenStream,
+        variants: &[&KeyPathMutableEnumVariant],
+        kpm_attrs: &KeyPathMutableAttrs,
+        serde_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
+        splice: bool,
+    ) -> TokenStream {
+        let crate_name = super::crate_name();
+        let method = if splice {
+            quote! { validate_splice }
+        } else {
+            quote! { validate_path }
+        };
+
+        if kpm_attrs.should_dispatch_directly() {
+            let validations = variants.iter().flat_map(|variant| {
+                variant.fields.iter().map(|f| {
+                    f.abort_if_trait_object();
+
+                    let ty = &f.ty;
+                    quote! { <#ty as #crate_name::KeyPathMutable>::#method(keys) }
+                })
+            });
+            let no_variants = if splice {
+                quote! { Err(#crate_name::KeyPathError::splice_on_non_list::<#self_ty>()) }
+            } else {
+                quote! { Ok(()) }
+            };
+
+            return quote! {
+                [#(#validations),*]
+                    .into_iter()
+                    .reduce(|valid, next| valid.or(next))
+                    .unwrap_or(#no_variants)
+            };
+        }
+
+        let variant_arms = variants.iter().filter_map(|variant| {
+            let variant_kpm_attrs = KeyPathMutableAttrs::from_attributes(&variant.attrs).unwrap();
+            if kpm_attrs.should_skip_all()
+                || variant_kpm_attrs.should_skip()
+                || variant_kpm_attrs.should_skip_all()
+            {
+                return None;
+            }
+
+            let variant_attrs = ItemSerdeAtrs::from_attributes(&variant.attrs);
+            let variant_container_attrs = ContainerSerdeAttrs::from_attributes(&variant.attrs);
+            let variant_name_str = variant_name(&variant.ident, serde_attrs, &variant_attrs);
+
+            let field_arms: Vec<_> = variant
+                .fields
+                .iter()
+                .enumerate()
+                .filter_map(|(i, f)| {
+                    let field_kpm_attrs = KeyPathMutableAttrs::from_attributes(&f.attrs);
+                    if field_kpm_attrs.as_ref().is_ok_and(|a| a.should_skip()) {
+                        f.abort_if_skip_mismatch(serde_attrs);
+                        return None;
+                    }
+
+                    let name = match f.ident.as_ref() {
+                        Some(ident) => {
+                            let field_attrs = ItemSerdeAtrs::from_attributes(&f.attrs);
+                            field_name(ident, &variant_container_attrs, &field_attrs)
+                        }
+                        None => i.to_string(),
+                    };
+
+                    if field_kpm_attrs.is_ok_and(|a| a.is_read_only()) {
+                        return Some(quote! {
+                            #name => Err(#crate_name::KeyPathError::read_only_field::<#self_ty>(#name))
+                        });
+                    }
+
+                    f.abort_if_trait_object();
+
+                    let ty = &f.ty;
+                    Some(quote! {
+                        #name => <#ty as #crate_name::KeyPathMutable>::#method(&keys[2..])
+                    })
+                })
+                .collect();
+
+            if field_arms.is_empty() {
+                return None;
+            }
+
+            Some(quote! {
+                #variant_name_str => match field_name.as_ref() {
+                    #(#field_arms),*,
+                    _ => Err(#crate_name::KeyPathError::unknown_variant_or_field::<#self_ty>(#variant_name_str, field_name))
+                }
+            })
+        }).collect::<Vec<_>>();
+
+        let variant_names: Vec<_> = variants
+            .iter()
+            .map(|variant| {
+                let variant_attrs = ItemSerdeAtrs::from_attributes(&variant.attrs);
+                variant_name(&variant.ident, serde_attrs, &variant_attrs)
+            })
+            .collect();
+
+        // The content of a variant is never a list itself
+        let whole_variant = if splice {
+            quote! { Err(#crate_name::KeyPathError::splice_on_non_list::<#self_ty>()) }
+        } else {
+            quote! { Ok(()) }
+        };
+
+        let variant_name_match = if variant_names.is_empty() {
+            quote! {
+                Err(#crate_name::KeyPathError::unknown_variant_or_field::<#self_ty>(variant, ""))
+            }
+        } else {
+            quote! {
+                match variant.as_ref() {
+                    #(#variant_names)|* => #whole_variant,
+                    _ => Err(#crate_name::KeyPathError::unknown_variant_or_field::<#self_ty>(variant, "")),
+                }
+            }
+        };
+
+        let variants_match = if variant_arms.is_empty() {
+            quote! {
+                Err(#crate_name::KeyPathError::unknown_variant_or_field::<#self_ty>(variant, field_name))
+            }
+        } else {
+            quote! {
+                match variant.as_ref() {
+                    #(#variant_arms),*
+                    _ => Err(#crate_name::KeyPathError::unknown_variant_or_field::<#self_ty>(variant, field_name)),
+                }
+            }
+        };
+
+        quote! {
+            let #crate_name::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(#crate_name::KeyPathError::must_mutate_enum_with_variant::<#self_ty>());
+            };
+
+            // Replacing the whole variant
+            if keys.len() == 1 {
+                return #variant_name_match;
+            }
+
+            let Some(#crate_name::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(#crate_name::KeyPathError::must_mutate_enum_variant_with_field::<#self_ty>(variant));
+            };
+
+            #variants_match
+        }
+    }
+
     fn tuple_variant_match_arm(
         variant: &KeyPathMutableEnumVariant,
         skip_all: bool,
         serde_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
     ) -> TokenStream {
         let crate_name = super::crate_name();
-        let variant_name = &variant.ident;
         let variant_attrs = ItemSerdeAtrs::from_attributes(&variant.attrs);
-        let variant_name_str = field_name(variant_name, serde_attrs, &variant_attrs);
+        let variant_name_str = variant_name(&variant.ident, serde_attrs, &variant_attrs);
+        let variant_name = &variant.ident;
         let match_arms: Vec<_> = variant
             .fields
             .iter()
@@ -290,7 +762,7 @@ impl KeyPathMutableType {
         }
 
         quote! {
-            Self::#variant_name(#(#element_name_bindings),*) if variant == #variant_name_str => match field_name {
+            Self::#variant_name(#(#element_name_bindings),*) if variant == #variant_name_str => match field_name.as_ref() {
                 #(#match_arms),*,
                 _ => Err(#crate_name::KeyPathError::unknown_variant_or_field::<Self>(#variant_name_str, field_name))
             }
@@ -300,14 +772,21 @@ impl KeyPathMutableType {
     fn tuple_variant_field_match_arm(
         field: (usize, &KeyPathMutableStructField),
     ) -> Option<TokenStream> {
+        let crate_name = super::crate_name();
         let keypathmutable_attrs = KeyPathMutableAttrs::from_attributes(&field.1.attrs);
-        if keypathmutable_attrs.is_ok_and(|a| a.should_skip()) {
+        if keypathmutable_attrs.as_ref().is_ok_and(|a| a.should_skip()) {
             return None;
         }
 
         let value_ident = Ident::new(&format!("value{}", field.0), field.1.ident.span());
         let index_str = field.0.to_string();
 
+        if keypathmutable_attrs.is_ok_and(|a| a.is_read_only()) {
+            return Some(quote! {
+                #index_str => Err(#crate_name::KeyPathError::read_only_field::<Self>(#index_str))
+            });
+        }
+
         Some(quote! {
             #index_str => #value_ident.patch_keypath(&keys[2..], patch)
         })
@@ -315,7 +794,7 @@ impl KeyPathMutableType {
 
     fn tuple_variant_field_binding(index: usize, field: &KeyPathMutableStructField) -> Ident {
         let keypathmutable_attrs = KeyPathMutableAttrs::from_attributes(&field.attrs);
-        if keypathmutable_attrs.is_ok_and(|a| a.should_skip()) {
+        if keypathmutable_attrs.is_ok_and(|a| a.should_skip() || a.is_read_only()) {
             Ident::new(&format!("_value{}", index), field.ident.span())
         } else {
             Ident::new(&format!("value{}", index), field.ident.span())
@@ -328,10 +807,10 @@ impl KeyPathMutableType {
         serde_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
     ) -> TokenStream {
         let crate_name = super::crate_name();
-        let variant_name = &variant.ident;
         let variant_attrs = ItemSerdeAtrs::from_attributes(&variant.attrs);
         let variant_container_attrs = ContainerSerdeAttrs::from_attributes(&variant.attrs);
-        let variant_name_str = field_name(variant_name, serde_attrs, &variant_attrs);
+        let variant_name_str = variant_name(&variant.ident, serde_attrs, &variant_attrs);
+        let variant_name = &variant.ident;
         let match_arms: Vec<_> = variant
             .fields
             .iter()
@@ -352,7 +831,7 @@ impl KeyPathMutableType {
         }
 
         quote! {
-            Self::#variant_name { #(#field_name_bindings),* } if variant == #variant_name_str => match field_name {
+            Self::#variant_name { #(#field_name_bindings),* } if variant == #variant_name_str => match field_name.as_ref() {
                 #(#match_arms),*,
                 _ => Err(#crate_name::KeyPathError::unknown_variant_or_field::<Self>(#variant_name_str, field_name))
             }
@@ -363,8 +842,9 @@ impl KeyPathMutableType {
         field: &KeyPathMutableStructField,
         serde_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
     ) -> Option<TokenStream> {
+        let crate_name = super::crate_name();
         let keypathmutable_attrs = KeyPathMutableAttrs::from_attributes(&field.attrs);
-        if keypathmutable_attrs.is_ok_and(|a| a.should_skip()) {
+        if keypathmutable_attrs.as_ref().is_ok_and(|a| a.should_skip()) {
             return None;
         }
 
@@ -376,6 +856,12 @@ impl KeyPathMutableType {
         let field_attrs = ItemSerdeAtrs::from_attributes(&field.attrs);
         let field_name_str = field_name(ident, serde_attrs, &field_attrs);
 
+        if keypathmutable_attrs.is_ok_and(|a| a.is_read_only()) {
+            return Some(quote! {
+                #field_name_str => Err(#crate_name::KeyPathError::read_only_field::<Self>(#field_name_str))
+            });
+        }
+
         Some(quote! {
             #field_name_str => #ident.patch_keypath(&keys[2..], patch)
         })
@@ -383,7 +869,7 @@ impl KeyPathMutableType {
 
     fn struct_variant_field_binding(field: &KeyPathMutableStructField) -> TokenStream {
         let keypathmutable_attrs = KeyPathMutableAttrs::from_attributes(&field.attrs);
-        if keypathmutable_attrs.is_ok_and(|a| a.should_skip()) {
+        if keypathmutable_attrs.is_ok_and(|a| a.should_skip() || a.is_read_only()) {
             field
                 .ident
                 .as_ref()
@@ -27,6 +27,20 @@ fn struct_with_one_field() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyStruct {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let mut members = pathogen::__private::from_patch_value::object(
+                value,
+                "struct MyStruct",
+            )?;
+            let field0 = pathogen::__private::from_patch_value::field::<
+                usize,
+            >(pathogen::__private::from_patch_value::take(&mut members, "a", &[]), "a")?;
+            Ok(Self { a: field0 })
+        }
+    }
     impl pathogen::KeyPathMutable for MyStruct {
         fn patch_keypath(
             &mut self,
@@ -34,26 +48,68 @@ fn struct_with_one_field() {
             patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<MyStruct>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<MyStruct>())
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyStruct as pathogen::FromPatchValue>::from_patch_value(
+                                value,
+                            )
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    MyStruct,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyStruct>())
+                    }
                 };
             }
-            let pathogen::KeyPathElement::Field { key } = keys[0] else {
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
                 );
             };
-            match key {
+            match key.as_ref() {
                 "a" => self.a.patch_keypath(&keys[1..], patch),
                 _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
             }
         }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            match key.as_ref() {
+                "a" => <usize as pathogen::KeyPathMutable>::validate_path(&keys[1..]),
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            match key.as_ref() {
+                "a" => <usize as pathogen::KeyPathMutable>::validate_splice(&keys[1..]),
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
+        }
     }
     "###);
 }
@@ -74,6 +130,20 @@ fn struct_with_one_skipped_field() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyStruct {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let mut members = pathogen::__private::from_patch_value::object(
+                value,
+                "struct MyStruct",
+            )?;
+            let field0 = pathogen::__private::from_patch_value::deserialize_field::<
+                usize,
+            >(pathogen::__private::from_patch_value::take(&mut members, "a", &[]), "a")?;
+            Ok(Self { a: field0 })
+        }
+    }
     impl pathogen::KeyPathMutable for MyStruct {
         fn patch_keypath(
             &mut self,
@@ -81,17 +151,53 @@ fn struct_with_one_skipped_field() {
             patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<MyStruct>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<MyStruct>())
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyStruct as pathogen::FromPatchValue>::from_patch_value(
+                                value,
+                            )
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    MyStruct,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyStruct>())
+                    }
                 };
             }
-            let pathogen::KeyPathElement::Field { key } = keys[0] else {
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key))
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key))
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
                 );
@@ -118,6 +224,20 @@ fn struct_with_skip_all() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyStruct {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let mut members = pathogen::__private::from_patch_value::object(
+                value,
+                "struct MyStruct",
+            )?;
+            let field0 = pathogen::__private::from_patch_value::deserialize_field::<
+                usize,
+            >(pathogen::__private::from_patch_value::take(&mut members, "a", &[]), "a")?;
+            Ok(Self { a: field0 })
+        }
+    }
     impl pathogen::KeyPathMutable for MyStruct {
         fn patch_keypath(
             &mut self,
@@ -125,17 +245,53 @@ fn struct_with_skip_all() {
             patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<MyStruct>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<MyStruct>())
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyStruct as pathogen::FromPatchValue>::from_patch_value(
+                                value,
+                            )
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    MyStruct,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyStruct>())
+                    }
                 };
             }
-            let pathogen::KeyPathElement::Field { key } = keys[0] else {
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key))
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key))
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
                 );
@@ -158,6 +314,13 @@ fn newtype_struct() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyNumber {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            Ok(Self(<usize as pathogen::FromPatchValue>::from_patch_value(value)?))
+        }
+    }
     impl pathogen::KeyPathMutable for MyNumber {
         fn patch_keypath(
             &mut self,
@@ -165,26 +328,68 @@ fn newtype_struct() {
             patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<MyNumber>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<MyNumber>())
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyNumber as pathogen::FromPatchValue>::from_patch_value(
+                                value,
+                            )
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    MyNumber,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyNumber>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyNumber>())
+                    }
                 };
             }
-            let pathogen::KeyPathElement::Field { key } = keys[0] else {
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_struct_with_field::<MyNumber>(),
                 );
             };
-            match key {
+            match key.as_ref() {
                 "0" => self.0.patch_keypath(&keys[1..], patch),
                 _ => Err(pathogen::KeyPathError::unknown_field::<MyNumber>(key)),
             }
         }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyNumber>(),
+                );
+            };
+            match key.as_ref() {
+                "0" => <usize as pathogen::KeyPathMutable>::validate_path(&keys[1..]),
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyNumber>(key)),
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyNumber>());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyNumber>(),
+                );
+            };
+            match key.as_ref() {
+                "0" => <usize as pathogen::KeyPathMutable>::validate_splice(&keys[1..]),
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyNumber>(key)),
+            }
+        }
     }
     "###);
 }
@@ -206,6 +411,30 @@ fn struct_with_multiple_fields() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyStruct {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let mut members = pathogen::__private::from_patch_value::object(
+                value,
+                "struct MyStruct",
+            )?;
+            let field0 = pathogen::__private::from_patch_value::field::<
+                usize,
+            >(pathogen::__private::from_patch_value::take(&mut members, "a", &[]), "a")?;
+            let field1 = pathogen::__private::from_patch_value::field::<
+                String,
+            >(pathogen::__private::from_patch_value::take(&mut members, "b", &[]), "b")?;
+            let field2 = pathogen::__private::from_patch_value::field::<
+                f64,
+            >(pathogen::__private::from_patch_value::take(&mut members, "c", &[]), "c")?;
+            Ok(Self {
+                a: field0,
+                b: field1,
+                c: field2,
+            })
+        }
+    }
     impl pathogen::KeyPathMutable for MyStruct {
         fn patch_keypath(
             &mut self,
@@ -213,28 +442,74 @@ fn struct_with_multiple_fields() {
             patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<MyStruct>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<MyStruct>())
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyStruct as pathogen::FromPatchValue>::from_patch_value(
+                                value,
+                            )
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    MyStruct,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyStruct>())
+                    }
                 };
             }
-            let pathogen::KeyPathElement::Field { key } = keys[0] else {
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
                 );
             };
-            match key {
+            match key.as_ref() {
                 "a" => self.a.patch_keypath(&keys[1..], patch),
                 "b" => self.b.patch_keypath(&keys[1..], patch),
                 "c" => self.c.patch_keypath(&keys[1..], patch),
                 _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
             }
         }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            match key.as_ref() {
+                "a" => <usize as pathogen::KeyPathMutable>::validate_path(&keys[1..]),
+                "b" => <String as pathogen::KeyPathMutable>::validate_path(&keys[1..]),
+                "c" => <f64 as pathogen::KeyPathMutable>::validate_path(&keys[1..]),
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            match key.as_ref() {
+                "a" => <usize as pathogen::KeyPathMutable>::validate_splice(&keys[1..]),
+                "b" => <String as pathogen::KeyPathMutable>::validate_splice(&keys[1..]),
+                "c" => <f64 as pathogen::KeyPathMutable>::validate_splice(&keys[1..]),
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
+        }
     }
     "###);
 }
@@ -257,6 +532,43 @@ fn struct_with_multiple_fields_and_rename() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyStruct {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let mut members = pathogen::__private::from_patch_value::object(
+                value,
+                "struct MyStruct",
+            )?;
+            let field0 = pathogen::__private::from_patch_value::field::<
+                usize,
+            >(
+                pathogen::__private::from_patch_value::take(&mut members, "longField", &[]),
+                "longField",
+            )?;
+            let field1 = pathogen::__private::from_patch_value::field::<
+                String,
+            >(
+                pathogen::__private::from_patch_value::take(
+                    &mut members,
+                    "evenLongerField",
+                    &[],
+                ),
+                "evenLongerField",
+            )?;
+            let field2 = pathogen::__private::from_patch_value::field::<
+                f64,
+            >(
+                pathogen::__private::from_patch_value::take(&mut members, "andOneMore", &[]),
+                "andOneMore",
+            )?;
+            Ok(Self {
+                long_field: field0,
+                even_longer_field: field1,
+                and_one_more: field2,
+            })
+        }
+    }
     impl pathogen::KeyPathMutable for MyStruct {
         fn patch_keypath(
             &mut self,
@@ -264,28 +576,82 @@ fn struct_with_multiple_fields_and_rename() {
             patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<MyStruct>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<MyStruct>())
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyStruct as pathogen::FromPatchValue>::from_patch_value(
+                                value,
+                            )
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    MyStruct,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyStruct>())
+                    }
                 };
             }
-            let pathogen::KeyPathElement::Field { key } = keys[0] else {
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
                 );
             };
-            match key {
+            match key.as_ref() {
                 "longField" => self.long_field.patch_keypath(&keys[1..], patch),
                 "evenLongerField" => self.even_longer_field.patch_keypath(&keys[1..], patch),
                 "andOneMore" => self.and_one_more.patch_keypath(&keys[1..], patch),
                 _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
             }
         }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            match key.as_ref() {
+                "longField" => <usize as pathogen::KeyPathMutable>::validate_path(&keys[1..]),
+                "evenLongerField" => {
+                    <String as pathogen::KeyPathMutable>::validate_path(&keys[1..])
+                }
+                "andOneMore" => <f64 as pathogen::KeyPathMutable>::validate_path(&keys[1..]),
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            match key.as_ref() {
+                "longField" => {
+                    <usize as pathogen::KeyPathMutable>::validate_splice(&keys[1..])
+                }
+                "evenLongerField" => {
+                    <String as pathogen::KeyPathMutable>::validate_splice(&keys[1..])
+                }
+                "andOneMore" => {
+                    <f64 as pathogen::KeyPathMutable>::validate_splice(&keys[1..])
+                }
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
+        }
     }
     "###);
 }
@@ -306,6 +672,38 @@ fn enum_with_no_data() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for BasicEnum {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let (variant, content) = pathogen::__private::from_patch_value::externally_tagged(
+                value,
+                "enum BasicEnum",
+            )?;
+            match variant.as_str() {
+                "First" => {
+                    pathogen::__private::from_patch_value::unit_variant(content)?;
+                    Ok(Self::First)
+                }
+                "Second" => {
+                    pathogen::__private::from_patch_value::unit_variant(content)?;
+                    Ok(Self::Second)
+                }
+                "Third" => {
+                    pathogen::__private::from_patch_value::unit_variant(content)?;
+                    Ok(Self::Third)
+                }
+                variant => {
+                    Err(
+                        pathogen::__private::from_patch_value::unknown_variant(
+                            variant,
+                            &["First", "Second", "Third"],
+                        ),
+                    )
+                }
+            }
+        }
+    }
     impl pathogen::KeyPathMutable for BasicEnum {
         fn patch_keypath(
             &mut self,
@@ -313,22 +711,55 @@ fn enum_with_no_data() {
             patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<BasicEnum>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<BasicEnum>())
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <BasicEnum as pathogen::FromPatchValue>::from_patch_value(
+                                value,
+                            )
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    BasicEnum,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<BasicEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<BasicEnum>())
+                    }
                 };
             }
-            let pathogen::KeyPathElement::Variant { key: variant, .. } = keys[0] else {
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_enum_with_variant::<BasicEnum>(),
                 );
             };
-            let pathogen::KeyPathElement::Field { key: field_name } = keys[1] else {
+            if keys.len() == 1 {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <BasicEnum as pathogen::FromPatchValue>::from_patch_value({
+                                let mut map = pathogen::__private::serde_json::Map::new();
+                                map.insert(variant.to_string(), value);
+                                pathogen::__private::serde_json::Value::Object(map)
+                            })
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    BasicEnum,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<BasicEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<BasicEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key: field_name } = &keys[1] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
                         BasicEnum,
@@ -345,6 +776,80 @@ fn enum_with_no_data() {
                 }
             }
         }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<BasicEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => Ok(()),
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                BasicEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        BasicEnum,
+                    >(variant),
+                );
+            };
+            Err(
+                pathogen::KeyPathError::unknown_variant_or_field::<
+                    BasicEnum,
+                >(variant, field_name),
+            )
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<BasicEnum>());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<BasicEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<BasicEnum>())
+                    }
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                BasicEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        BasicEnum,
+                    >(variant),
+                );
+            };
+            Err(
+                pathogen::KeyPathError::unknown_variant_or_field::<
+                    BasicEnum,
+                >(variant, field_name),
+            )
+        }
     }
     "###);
 }
@@ -368,6 +873,138 @@ fn enum_with_all_trimmings() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for ExhaustingEnum {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let (variant, content) = pathogen::__private::from_patch_value::externally_tagged(
+                value,
+                "enum ExhaustingEnum",
+            )?;
+            match variant.as_str() {
+                "First" => {
+                    Ok(
+                        Self::First(
+                            <usize as pathogen::FromPatchValue>::from_patch_value(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                "Second" => {
+                    let mut members = pathogen::__private::from_patch_value::object(
+                        pathogen::__private::from_patch_value::variant_content(
+                            content,
+                            "struct variant",
+                        )?,
+                        "struct variant ExhaustingEnum::Second",
+                    )?;
+                    let field0 = pathogen::__private::from_patch_value::field::<
+                        String,
+                    >(
+                        pathogen::__private::from_patch_value::take(
+                            &mut members,
+                            "field",
+                            &[],
+                        ),
+                        "field",
+                    )?;
+                    Ok(Self::Second { field: field0 })
+                }
+                "Third" => {
+                    let [field0, field1] = pathogen::__private::from_patch_value::array(
+                        pathogen::__private::from_patch_value::variant_content(
+                            content,
+                            "tuple variant",
+                        )?,
+                        "tuple variant ExhaustingEnum::Third",
+                    )?;
+                    Ok(
+                        Self::Third(
+                            <usize as pathogen::FromPatchValue>::from_patch_value(field0)?,
+                            <String as pathogen::FromPatchValue>::from_patch_value(field1)?,
+                        ),
+                    )
+                }
+                "Fourth" => {
+                    let mut members = pathogen::__private::from_patch_value::object(
+                        pathogen::__private::from_patch_value::variant_content(
+                            content,
+                            "struct variant",
+                        )?,
+                        "struct variant ExhaustingEnum::Fourth",
+                    )?;
+                    let field0 = pathogen::__private::from_patch_value::field::<
+                        usize,
+                    >(
+                        pathogen::__private::from_patch_value::take(
+                            &mut members,
+                            "field1",
+                            &[],
+                        ),
+                        "field1",
+                    )?;
+                    let field1 = pathogen::__private::from_patch_value::field::<
+                        String,
+                    >(
+                        pathogen::__private::from_patch_value::take(
+                            &mut members,
+                            "field2",
+                            &[],
+                        ),
+                        "field2",
+                    )?;
+                    Ok(Self::Fourth {
+                        field1: field0,
+                        field2: field1,
+                    })
+                }
+                "Fifth" => {
+                    Ok(
+                        Self::Fifth(
+                            <SimpleStruct as pathogen::FromPatchValue>::from_patch_value(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                "Sixth" => {
+                    let mut members = pathogen::__private::from_patch_value::object(
+                        pathogen::__private::from_patch_value::variant_content(
+                            content,
+                            "struct variant",
+                        )?,
+                        "struct variant ExhaustingEnum::Sixth",
+                    )?;
+                    let field0 = pathogen::__private::from_patch_value::field::<
+                        SimpleStruct,
+                    >(
+                        pathogen::__private::from_patch_value::take(
+                            &mut members,
+                            "field",
+                            &[],
+                        ),
+                        "field",
+                    )?;
+                    Ok(Self::Sixth { field: field0 })
+                }
+                variant => {
+                    Err(
+                        pathogen::__private::from_patch_value::unknown_variant(
+                            variant,
+                            &["First", "Second", "Third", "Fourth", "Fifth", "Sixth"],
+                        ),
+                    )
+                }
+            }
+        }
+    }
     impl pathogen::KeyPathMutable for ExhaustingEnum {
         fn patch_keypath(
             &mut self,
@@ -375,24 +1012,55 @@ fn enum_with_all_trimmings() {
             patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<
-                                ExhaustingEnum,
-                            >,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<ExhaustingEnum>())
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <ExhaustingEnum as pathogen::FromPatchValue>::from_patch_value(
+                                value,
+                            )
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    ExhaustingEnum,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<ExhaustingEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<ExhaustingEnum>())
+                    }
                 };
             }
-            let pathogen::KeyPathElement::Variant { key: variant, .. } = keys[0] else {
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_enum_with_variant::<ExhaustingEnum>(),
                 );
             };
-            let pathogen::KeyPathElement::Field { key: field_name } = keys[1] else {
+            if keys.len() == 1 {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <ExhaustingEnum as pathogen::FromPatchValue>::from_patch_value({
+                                let mut map = pathogen::__private::serde_json::Map::new();
+                                map.insert(variant.to_string(), value);
+                                pathogen::__private::serde_json::Value::Object(map)
+                            })
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    ExhaustingEnum,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<ExhaustingEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<ExhaustingEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key: field_name } = &keys[1] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
                         ExhaustingEnum,
@@ -401,7 +1069,7 @@ fn enum_with_all_trimmings() {
             };
             match self {
                 Self::First(value0) if variant == "First" => {
-                    match field_name {
+                    match field_name.as_ref() {
                         "0" => value0.patch_keypath(&keys[2..], patch),
                         _ => {
                             Err(
@@ -413,7 +1081,7 @@ fn enum_with_all_trimmings() {
                     }
                 }
                 Self::Second { field } if variant == "Second" => {
-                    match field_name {
+                    match field_name.as_ref() {
                         "field" => field.patch_keypath(&keys[2..], patch),
                         _ => {
                             Err(
@@ -425,7 +1093,7 @@ fn enum_with_all_trimmings() {
                     }
                 }
                 Self::Third(value0, value1) if variant == "Third" => {
-                    match field_name {
+                    match field_name.as_ref() {
                         "0" => value0.patch_keypath(&keys[2..], patch),
                         "1" => value1.patch_keypath(&keys[2..], patch),
                         _ => {
@@ -438,7 +1106,7 @@ fn enum_with_all_trimmings() {
                     }
                 }
                 Self::Fourth { field1, field2 } if variant == "Fourth" => {
-                    match field_name {
+                    match field_name.as_ref() {
                         "field1" => field1.patch_keypath(&keys[2..], patch),
                         "field2" => field2.patch_keypath(&keys[2..], patch),
                         _ => {
@@ -451,7 +1119,7 @@ fn enum_with_all_trimmings() {
                     }
                 }
                 Self::Fifth(value0) if variant == "Fifth" => {
-                    match field_name {
+                    match field_name.as_ref() {
                         "0" => value0.patch_keypath(&keys[2..], patch),
                         _ => {
                             Err(
@@ -463,7 +1131,7 @@ fn enum_with_all_trimmings() {
                     }
                 }
                 Self::Sixth { field } if variant == "Sixth" => {
-                    match field_name {
+                    match field_name.as_ref() {
                         "field" => field.patch_keypath(&keys[2..], patch),
                         _ => {
                             Err(
@@ -483,6 +1151,272 @@ fn enum_with_all_trimmings() {
                 }
             }
         }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<ExhaustingEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" | "Fourth" | "Fifth" | "Sixth" => Ok(()),
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                ExhaustingEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        ExhaustingEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "First" => {
+                    match field_name.as_ref() {
+                        "0" => <usize as pathogen::KeyPathMutable>::validate_path(&keys[2..]),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("First", field_name),
+                            )
+                        }
+                    }
+                }
+                "Second" => {
+                    match field_name.as_ref() {
+                        "field" => {
+                            <String as pathogen::KeyPathMutable>::validate_path(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("Second", field_name),
+                            )
+                        }
+                    }
+                }
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => <usize as pathogen::KeyPathMutable>::validate_path(&keys[2..]),
+                        "1" => {
+                            <String as pathogen::KeyPathMutable>::validate_path(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                "Fourth" => {
+                    match field_name.as_ref() {
+                        "field1" => {
+                            <usize as pathogen::KeyPathMutable>::validate_path(&keys[2..])
+                        }
+                        "field2" => {
+                            <String as pathogen::KeyPathMutable>::validate_path(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("Fourth", field_name),
+                            )
+                        }
+                    }
+                }
+                "Fifth" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <SimpleStruct as pathogen::KeyPathMutable>::validate_path(
+                                &keys[2..],
+                            )
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("Fifth", field_name),
+                            )
+                        }
+                    }
+                }
+                "Sixth" => {
+                    match field_name.as_ref() {
+                        "field" => {
+                            <SimpleStruct as pathogen::KeyPathMutable>::validate_path(
+                                &keys[2..],
+                            )
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("Sixth", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            ExhaustingEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<ExhaustingEnum>());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<ExhaustingEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" | "Fourth" | "Fifth" | "Sixth" => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<ExhaustingEnum>())
+                    }
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                ExhaustingEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        ExhaustingEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "First" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <usize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("First", field_name),
+                            )
+                        }
+                    }
+                }
+                "Second" => {
+                    match field_name.as_ref() {
+                        "field" => {
+                            <String as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("Second", field_name),
+                            )
+                        }
+                    }
+                }
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <usize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        "1" => {
+                            <String as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                "Fourth" => {
+                    match field_name.as_ref() {
+                        "field1" => {
+                            <usize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        "field2" => {
+                            <String as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("Fourth", field_name),
+                            )
+                        }
+                    }
+                }
+                "Fifth" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <SimpleStruct as pathogen::KeyPathMutable>::validate_splice(
+                                &keys[2..],
+                            )
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("Fifth", field_name),
+                            )
+                        }
+                    }
+                }
+                "Sixth" => {
+                    match field_name.as_ref() {
+                        "field" => {
+                            <SimpleStruct as pathogen::KeyPathMutable>::validate_splice(
+                                &keys[2..],
+                            )
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("Sixth", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            ExhaustingEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
     }
     "###);
 }
@@ -509,6 +1443,147 @@ fn enum_with_all_trimmings_and_serde() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for ExhaustingEnum {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let (variant, content) = pathogen::__private::from_patch_value::externally_tagged(
+                value,
+                "enum ExhaustingEnum",
+            )?;
+            match variant.as_str() {
+                "firstThing" => {
+                    Ok(
+                        Self::FirstThing(
+                            <usize as pathogen::FromPatchValue>::from_patch_value(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                "secondThing" => {
+                    let mut members = pathogen::__private::from_patch_value::object(
+                        pathogen::__private::from_patch_value::variant_content(
+                            content,
+                            "struct variant",
+                        )?,
+                        "struct variant ExhaustingEnum::SecondThing",
+                    )?;
+                    let field0 = pathogen::__private::from_patch_value::field::<
+                        String,
+                    >(
+                        pathogen::__private::from_patch_value::take(
+                            &mut members,
+                            "longField",
+                            &[],
+                        ),
+                        "longField",
+                    )?;
+                    Ok(Self::SecondThing {
+                        long_field: field0,
+                    })
+                }
+                "thirdOption" => {
+                    let [field0, field1] = pathogen::__private::from_patch_value::array(
+                        pathogen::__private::from_patch_value::variant_content(
+                            content,
+                            "tuple variant",
+                        )?,
+                        "tuple variant ExhaustingEnum::ThirdOption",
+                    )?;
+                    Ok(
+                        Self::ThirdOption(
+                            <usize as pathogen::FromPatchValue>::from_patch_value(field0)?,
+                            <String as pathogen::FromPatchValue>::from_patch_value(field1)?,
+                        ),
+                    )
+                }
+                "fourthKind" => {
+                    let mut members = pathogen::__private::from_patch_value::object(
+                        pathogen::__private::from_patch_value::variant_content(
+                            content,
+                            "struct variant",
+                        )?,
+                        "struct variant ExhaustingEnum::FourthKind",
+                    )?;
+                    let field0 = pathogen::__private::from_patch_value::field::<
+                        usize,
+                    >(
+                        pathogen::__private::from_patch_value::take(
+                            &mut members,
+                            "longField",
+                            &[],
+                        ),
+                        "longField",
+                    )?;
+                    let field1 = pathogen::__private::from_patch_value::field::<
+                        String,
+                    >(
+                        pathogen::__private::from_patch_value::take(
+                            &mut members,
+                            "longer_field",
+                            &[],
+                        ),
+                        "longer_field",
+                    )?;
+                    Ok(Self::FourthKind {
+                        long_field: field0,
+                        even_longer_field: field1,
+                    })
+                }
+                "fifthCleverThing" => {
+                    Ok(
+                        Self::FifthCleverThing(
+                            <SimpleStruct as pathogen::FromPatchValue>::from_patch_value(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                "sixth" => {
+                    let mut members = pathogen::__private::from_patch_value::object(
+                        pathogen::__private::from_patch_value::variant_content(
+                            content,
+                            "struct variant",
+                        )?,
+                        "struct variant ExhaustingEnum::Sixth",
+                    )?;
+                    let field0 = pathogen::__private::from_patch_value::field::<
+                        SimpleStruct,
+                    >(
+                        pathogen::__private::from_patch_value::take(
+                            &mut members,
+                            "field",
+                            &[],
+                        ),
+                        "field",
+                    )?;
+                    Ok(Self::Sixth { field: field0 })
+                }
+                variant => {
+                    Err(
+                        pathogen::__private::from_patch_value::unknown_variant(
+                            variant,
+                            &[
+                                "firstThing",
+                                "secondThing",
+                                "thirdOption",
+                                "fourthKind",
+                                "fifthCleverThing",
+                                "sixth",
+                            ],
+                        ),
+                    )
+                }
+            }
+        }
+    }
     impl pathogen::KeyPathMutable for ExhaustingEnum {
         fn patch_keypath(
             &mut self,
@@ -516,24 +1591,55 @@ fn enum_with_all_trimmings_and_serde() {
             patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<
-                                ExhaustingEnum,
-                            >,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<ExhaustingEnum>())
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <ExhaustingEnum as pathogen::FromPatchValue>::from_patch_value(
+                                value,
+                            )
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    ExhaustingEnum,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<ExhaustingEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<ExhaustingEnum>())
+                    }
                 };
             }
-            let pathogen::KeyPathElement::Variant { key: variant, .. } = keys[0] else {
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_enum_with_variant::<ExhaustingEnum>(),
                 );
             };
-            let pathogen::KeyPathElement::Field { key: field_name } = keys[1] else {
+            if keys.len() == 1 {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <ExhaustingEnum as pathogen::FromPatchValue>::from_patch_value({
+                                let mut map = pathogen::__private::serde_json::Map::new();
+                                map.insert(variant.to_string(), value);
+                                pathogen::__private::serde_json::Value::Object(map)
+                            })
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    ExhaustingEnum,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<ExhaustingEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<ExhaustingEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key: field_name } = &keys[1] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
                         ExhaustingEnum,
@@ -542,7 +1648,7 @@ fn enum_with_all_trimmings_and_serde() {
             };
             match self {
                 Self::FirstThing(value0) if variant == "firstThing" => {
-                    match field_name {
+                    match field_name.as_ref() {
                         "0" => value0.patch_keypath(&keys[2..], patch),
                         _ => {
                             Err(
@@ -554,7 +1660,7 @@ fn enum_with_all_trimmings_and_serde() {
                     }
                 }
                 Self::SecondThing { long_field } if variant == "secondThing" => {
-                    match field_name {
+                    match field_name.as_ref() {
                         "longField" => long_field.patch_keypath(&keys[2..], patch),
                         _ => {
                             Err(
@@ -566,7 +1672,7 @@ fn enum_with_all_trimmings_and_serde() {
                     }
                 }
                 Self::ThirdOption(value0, value1) if variant == "thirdOption" => {
-                    match field_name {
+                    match field_name.as_ref() {
                         "0" => value0.patch_keypath(&keys[2..], patch),
                         "1" => value1.patch_keypath(&keys[2..], patch),
                         _ => {
@@ -582,7 +1688,7 @@ fn enum_with_all_trimmings_and_serde() {
                     long_field,
                     even_longer_field,
                 } if variant == "fourthKind" => {
-                    match field_name {
+                    match field_name.as_ref() {
                         "longField" => long_field.patch_keypath(&keys[2..], patch),
                         "longer_field" => even_longer_field.patch_keypath(&keys[2..], patch),
                         _ => {
@@ -595,7 +1701,7 @@ fn enum_with_all_trimmings_and_serde() {
                     }
                 }
                 Self::FifthCleverThing(value0) if variant == "fifthCleverThing" => {
-                    match field_name {
+                    match field_name.as_ref() {
                         "0" => value0.patch_keypath(&keys[2..], patch),
                         _ => {
                             Err(
@@ -607,7 +1713,7 @@ fn enum_with_all_trimmings_and_serde() {
                     }
                 }
                 Self::Sixth { field } if variant == "sixth" => {
-                    match field_name {
+                    match field_name.as_ref() {
                         "field" => field.patch_keypath(&keys[2..], patch),
                         _ => {
                             Err(
@@ -627,52 +1733,2759 @@ fn enum_with_all_trimmings_and_serde() {
                 }
             }
         }
-    }
-    "###);
-}
-
-#[test]
-fn struct_with_a_skip() {
-    let input = r#"
-            struct MyStruct {
-                long_field: usize,
-                #[keypath_mutable(skip)]
-                even_longer_field: String,
-                and_one_more: f64,
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
             }
-        "#;
-
-    let input = parse_str(input).unwrap();
-    let input = KeyPathMutableType::from_derive_input(&input).unwrap();
-
-    let actual = quote!(#input);
-
-    insta::assert_snapshot!(pretty_print(&actual), @r###"
-    impl pathogen::KeyPathMutable for MyStruct {
-        fn patch_keypath(
-            &mut self,
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<ExhaustingEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "firstThing" | "secondThing" | "thirdOption" | "fourthKind"
+                    | "fifthCleverThing" | "sixth" => Ok(()),
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                ExhaustingEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        ExhaustingEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "firstThing" => {
+                    match field_name.as_ref() {
+                        "0" => <usize as pathogen::KeyPathMutable>::validate_path(&keys[2..]),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("firstThing", field_name),
+                            )
+                        }
+                    }
+                }
+                "secondThing" => {
+                    match field_name.as_ref() {
+                        "longField" => {
+                            <String as pathogen::KeyPathMutable>::validate_path(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("secondThing", field_name),
+                            )
+                        }
+                    }
+                }
+                "thirdOption" => {
+                    match field_name.as_ref() {
+                        "0" => <usize as pathogen::KeyPathMutable>::validate_path(&keys[2..]),
+                        "1" => {
+                            <String as pathogen::KeyPathMutable>::validate_path(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("thirdOption", field_name),
+                            )
+                        }
+                    }
+                }
+                "fourthKind" => {
+                    match field_name.as_ref() {
+                        "longField" => {
+                            <usize as pathogen::KeyPathMutable>::validate_path(&keys[2..])
+                        }
+                        "longer_field" => {
+                            <String as pathogen::KeyPathMutable>::validate_path(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("fourthKind", field_name),
+                            )
+                        }
+                    }
+                }
+                "fifthCleverThing" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <SimpleStruct as pathogen::KeyPathMutable>::validate_path(
+                                &keys[2..],
+                            )
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("fifthCleverThing", field_name),
+                            )
+                        }
+                    }
+                }
+                "sixth" => {
+                    match field_name.as_ref() {
+                        "field" => {
+                            <SimpleStruct as pathogen::KeyPathMutable>::validate_path(
+                                &keys[2..],
+                            )
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("sixth", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            ExhaustingEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<ExhaustingEnum>());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<ExhaustingEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "firstThing" | "secondThing" | "thirdOption" | "fourthKind"
+                    | "fifthCleverThing" | "sixth" => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<ExhaustingEnum>())
+                    }
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                ExhaustingEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        ExhaustingEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "firstThing" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <usize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("firstThing", field_name),
+                            )
+                        }
+                    }
+                }
+                "secondThing" => {
+                    match field_name.as_ref() {
+                        "longField" => {
+                            <String as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("secondThing", field_name),
+                            )
+                        }
+                    }
+                }
+                "thirdOption" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <usize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        "1" => {
+                            <String as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("thirdOption", field_name),
+                            )
+                        }
+                    }
+                }
+                "fourthKind" => {
+                    match field_name.as_ref() {
+                        "longField" => {
+                            <usize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        "longer_field" => {
+                            <String as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("fourthKind", field_name),
+                            )
+                        }
+                    }
+                }
+                "fifthCleverThing" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <SimpleStruct as pathogen::KeyPathMutable>::validate_splice(
+                                &keys[2..],
+                            )
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("fifthCleverThing", field_name),
+                            )
+                        }
+                    }
+                }
+                "sixth" => {
+                    match field_name.as_ref() {
+                        "field" => {
+                            <SimpleStruct as pathogen::KeyPathMutable>::validate_splice(
+                                &keys[2..],
+                            )
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    ExhaustingEnum,
+                                >("sixth", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            ExhaustingEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+    }
+    "###);
+}
+
+#[test]
+fn struct_with_a_skip() {
+    let input = r#"
+            struct MyStruct {
+                long_field: usize,
+                #[keypath_mutable(skip)]
+                even_longer_field: String,
+                and_one_more: f64,
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = KeyPathMutableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyStruct {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let mut members = pathogen::__private::from_patch_value::object(
+                value,
+                "struct MyStruct",
+            )?;
+            let field0 = pathogen::__private::from_patch_value::field::<
+                usize,
+            >(
+                pathogen::__private::from_patch_value::take(&mut members, "long_field", &[]),
+                "long_field",
+            )?;
+            let field1 = pathogen::__private::from_patch_value::deserialize_field::<
+                String,
+            >(
+                pathogen::__private::from_patch_value::take(
+                    &mut members,
+                    "even_longer_field",
+                    &[],
+                ),
+                "even_longer_field",
+            )?;
+            let field2 = pathogen::__private::from_patch_value::field::<
+                f64,
+            >(
+                pathogen::__private::from_patch_value::take(
+                    &mut members,
+                    "and_one_more",
+                    &[],
+                ),
+                "and_one_more",
+            )?;
+            Ok(Self {
+                long_field: field0,
+                even_longer_field: field1,
+                and_one_more: field2,
+            })
+        }
+    }
+    impl pathogen::KeyPathMutable for MyStruct {
+        fn patch_keypath(
+            &mut self,
+            keys: &[pathogen::KeyPathElement],
+            patch: pathogen::Patch,
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyStruct as pathogen::FromPatchValue>::from_patch_value(
+                                value,
+                            )
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    MyStruct,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyStruct>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            match key.as_ref() {
+                "long_field" => self.long_field.patch_keypath(&keys[1..], patch),
+                "and_one_more" => self.and_one_more.patch_keypath(&keys[1..], patch),
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            match key.as_ref() {
+                "long_field" => {
+                    <usize as pathogen::KeyPathMutable>::validate_path(&keys[1..])
+                }
+                "and_one_more" => {
+                    <f64 as pathogen::KeyPathMutable>::validate_path(&keys[1..])
+                }
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            match key.as_ref() {
+                "long_field" => {
+                    <usize as pathogen::KeyPathMutable>::validate_splice(&keys[1..])
+                }
+                "and_one_more" => {
+                    <f64 as pathogen::KeyPathMutable>::validate_splice(&keys[1..])
+                }
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
+        }
+    }
+    "###);
+}
+
+#[test]
+fn tuple_struct_with_a_skip() {
+    let input = r#"
+            struct Pair(#[keypath_mutable(skip)] usize, String);
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = KeyPathMutableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for Pair {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let [field0, field1] = pathogen::__private::from_patch_value::array(
+                value,
+                "tuple struct Pair",
+            )?;
+            Ok(
+                Self(
+                    pathogen::__private::serde_json::from_value::<usize>(field0)?,
+                    <String as pathogen::FromPatchValue>::from_patch_value(field1)?,
+                ),
+            )
+        }
+    }
+    impl pathogen::KeyPathMutable for Pair {
+        fn patch_keypath(
+            &mut self,
+            keys: &[pathogen::KeyPathElement],
+            patch: pathogen::Patch,
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <Pair as pathogen::FromPatchValue>::from_patch_value(value)
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<Pair>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<Pair>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<Pair>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(pathogen::KeyPathError::must_mutate_struct_with_field::<Pair>());
+            };
+            match key.as_ref() {
+                "1" => self.1.patch_keypath(&keys[1..], patch),
+                _ => Err(pathogen::KeyPathError::unknown_field::<Pair>(key)),
+            }
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(pathogen::KeyPathError::must_mutate_struct_with_field::<Pair>());
+            };
+            match key.as_ref() {
+                "1" => <String as pathogen::KeyPathMutable>::validate_path(&keys[1..]),
+                _ => Err(pathogen::KeyPathError::unknown_field::<Pair>(key)),
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<Pair>());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(pathogen::KeyPathError::must_mutate_struct_with_field::<Pair>());
+            };
+            match key.as_ref() {
+                "1" => <String as pathogen::KeyPathMutable>::validate_splice(&keys[1..]),
+                _ => Err(pathogen::KeyPathError::unknown_field::<Pair>(key)),
+            }
+        }
+    }
+    "###);
+}
+
+#[test]
+fn enum_with_a_skip() {
+    let input = r#"
+            enum MyEnum {
+                First,
+                #[keypath_mutable(skip)]
+                Second(usize),
+                Third(isize),
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = KeyPathMutableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyEnum {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let (variant, content) = pathogen::__private::from_patch_value::externally_tagged(
+                value,
+                "enum MyEnum",
+            )?;
+            match variant.as_str() {
+                "First" => {
+                    pathogen::__private::from_patch_value::unit_variant(content)?;
+                    Ok(Self::First)
+                }
+                "Second" => {
+                    Ok(
+                        Self::Second(
+                            pathogen::__private::serde_json::from_value::<
+                                usize,
+                            >(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                "Third" => {
+                    Ok(
+                        Self::Third(
+                            <isize as pathogen::FromPatchValue>::from_patch_value(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                variant => {
+                    Err(
+                        pathogen::__private::from_patch_value::unknown_variant(
+                            variant,
+                            &["First", "Second", "Third"],
+                        ),
+                    )
+                }
+            }
+        }
+    }
+    impl pathogen::KeyPathMutable for MyEnum {
+        fn patch_keypath(
+            &mut self,
+            keys: &[pathogen::KeyPathElement],
+            patch: pathogen::Patch,
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value(value)
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value({
+                                let mut map = pathogen::__private::serde_json::Map::new();
+                                map.insert(variant.to_string(), value);
+                                pathogen::__private::serde_json::Value::Object(map)
+                            })
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key: field_name } = &keys[1] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match self {
+                Self::Third(value0) if variant == "Third" => {
+                    match field_name.as_ref() {
+                        "0" => value0.patch_keypath(&keys[2..], patch),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    Self,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => Ok(()),
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => <isize as pathogen::KeyPathMutable>::validate_path(&keys[2..]),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <isize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+    }
+    "###);
+}
+
+#[test]
+fn enum_with_skip_all() {
+    let input = r#"
+            enum MyEnum {
+                First,
+                #[keypath_mutable(skip_all)]
+                Second(usize),
+                Third(isize),
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = KeyPathMutableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyEnum {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let (variant, content) = pathogen::__private::from_patch_value::externally_tagged(
+                value,
+                "enum MyEnum",
+            )?;
+            match variant.as_str() {
+                "First" => {
+                    pathogen::__private::from_patch_value::unit_variant(content)?;
+                    Ok(Self::First)
+                }
+                "Second" => {
+                    Ok(
+                        Self::Second(
+                            pathogen::__private::serde_json::from_value::<
+                                usize,
+                            >(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                "Third" => {
+                    Ok(
+                        Self::Third(
+                            <isize as pathogen::FromPatchValue>::from_patch_value(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                variant => {
+                    Err(
+                        pathogen::__private::from_patch_value::unknown_variant(
+                            variant,
+                            &["First", "Second", "Third"],
+                        ),
+                    )
+                }
+            }
+        }
+    }
+    impl pathogen::KeyPathMutable for MyEnum {
+        fn patch_keypath(
+            &mut self,
+            keys: &[pathogen::KeyPathElement],
+            patch: pathogen::Patch,
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value(value)
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value({
+                                let mut map = pathogen::__private::serde_json::Map::new();
+                                map.insert(variant.to_string(), value);
+                                pathogen::__private::serde_json::Value::Object(map)
+                            })
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key: field_name } = &keys[1] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match self {
+                Self::Second(_) if variant == "Second" => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            Self,
+                        >("Second", field_name),
+                    )
+                }
+                Self::Third(value0) if variant == "Third" => {
+                    match field_name.as_ref() {
+                        "0" => value0.patch_keypath(&keys[2..], patch),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    Self,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => Ok(()),
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => <isize as pathogen::KeyPathMutable>::validate_path(&keys[2..]),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <isize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+    }
+    "###);
+}
+
+#[test]
+fn enum_with_a_top_level_skip_all() {
+    let input = r#"
+            #[keypath_mutable(skip_all)]
+            enum MyEnum {
+                First,
+                Second(usize),
+                Third(isize),
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = KeyPathMutableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyEnum {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let (variant, content) = pathogen::__private::from_patch_value::externally_tagged(
+                value,
+                "enum MyEnum",
+            )?;
+            match variant.as_str() {
+                "First" => {
+                    pathogen::__private::from_patch_value::unit_variant(content)?;
+                    Ok(Self::First)
+                }
+                "Second" => {
+                    Ok(
+                        Self::Second(
+                            pathogen::__private::serde_json::from_value::<
+                                usize,
+                            >(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                "Third" => {
+                    Ok(
+                        Self::Third(
+                            pathogen::__private::serde_json::from_value::<
+                                isize,
+                            >(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                variant => {
+                    Err(
+                        pathogen::__private::from_patch_value::unknown_variant(
+                            variant,
+                            &["First", "Second", "Third"],
+                        ),
+                    )
+                }
+            }
+        }
+    }
+    impl pathogen::KeyPathMutable for MyEnum {
+        fn patch_keypath(
+            &mut self,
+            keys: &[pathogen::KeyPathElement],
+            patch: pathogen::Patch,
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value(value)
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value({
+                                let mut map = pathogen::__private::serde_json::Map::new();
+                                map.insert(variant.to_string(), value);
+                                pathogen::__private::serde_json::Value::Object(map)
+                            })
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key: field_name } = &keys[1] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            Err(
+                pathogen::KeyPathError::unknown_variant_or_field::<
+                    MyEnum,
+                >(variant, field_name),
+            )
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => Ok(()),
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            Err(
+                pathogen::KeyPathError::unknown_variant_or_field::<
+                    MyEnum,
+                >(variant, field_name),
+            )
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            Err(
+                pathogen::KeyPathError::unknown_variant_or_field::<
+                    MyEnum,
+                >(variant, field_name),
+            )
+        }
+    }
+    "###);
+}
+
+#[test]
+fn enum_with_a_skip_in_a_struct_variant() {
+    let input = r#"
+            enum MyEnum {
+                First,
+                Second { a: usize, #[keypath_mutable(skip)] b: String },
+                Third(isize),
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = KeyPathMutableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyEnum {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let (variant, content) = pathogen::__private::from_patch_value::externally_tagged(
+                value,
+                "enum MyEnum",
+            )?;
+            match variant.as_str() {
+                "First" => {
+                    pathogen::__private::from_patch_value::unit_variant(content)?;
+                    Ok(Self::First)
+                }
+                "Second" => {
+                    let mut members = pathogen::__private::from_patch_value::object(
+                        pathogen::__private::from_patch_value::variant_content(
+                            content,
+                            "struct variant",
+                        )?,
+                        "struct variant MyEnum::Second",
+                    )?;
+                    let field0 = pathogen::__private::from_patch_value::field::<
+                        usize,
+                    >(
+                        pathogen::__private::from_patch_value::take(&mut members, "a", &[]),
+                        "a",
+                    )?;
+                    let field1 = pathogen::__private::from_patch_value::deserialize_field::<
+                        String,
+                    >(
+                        pathogen::__private::from_patch_value::take(&mut members, "b", &[]),
+                        "b",
+                    )?;
+                    Ok(Self::Second {
+                        a: field0,
+                        b: field1,
+                    })
+                }
+                "Third" => {
+                    Ok(
+                        Self::Third(
+                            <isize as pathogen::FromPatchValue>::from_patch_value(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                variant => {
+                    Err(
+                        pathogen::__private::from_patch_value::unknown_variant(
+                            variant,
+                            &["First", "Second", "Third"],
+                        ),
+                    )
+                }
+            }
+        }
+    }
+    impl pathogen::KeyPathMutable for MyEnum {
+        fn patch_keypath(
+            &mut self,
+            keys: &[pathogen::KeyPathElement],
+            patch: pathogen::Patch,
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value(value)
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value({
+                                let mut map = pathogen::__private::serde_json::Map::new();
+                                map.insert(variant.to_string(), value);
+                                pathogen::__private::serde_json::Value::Object(map)
+                            })
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key: field_name } = &keys[1] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match self {
+                Self::Second { a, b: _ } if variant == "Second" => {
+                    match field_name.as_ref() {
+                        "a" => a.patch_keypath(&keys[2..], patch),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    Self,
+                                >("Second", field_name),
+                            )
+                        }
+                    }
+                }
+                Self::Third(value0) if variant == "Third" => {
+                    match field_name.as_ref() {
+                        "0" => value0.patch_keypath(&keys[2..], patch),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    Self,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => Ok(()),
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "Second" => {
+                    match field_name.as_ref() {
+                        "a" => <usize as pathogen::KeyPathMutable>::validate_path(&keys[2..]),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Second", field_name),
+                            )
+                        }
+                    }
+                }
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => <isize as pathogen::KeyPathMutable>::validate_path(&keys[2..]),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "Second" => {
+                    match field_name.as_ref() {
+                        "a" => {
+                            <usize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Second", field_name),
+                            )
+                        }
+                    }
+                }
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <isize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+    }
+    "###);
+}
+
+#[test]
+fn enum_with_a_skip_in_a_tuple_variant() {
+    let input = r#"
+            enum MyEnum {
+                First,
+                Second(usize, #[keypath_mutable(skip)] String),
+                Third(isize),
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = KeyPathMutableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyEnum {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let (variant, content) = pathogen::__private::from_patch_value::externally_tagged(
+                value,
+                "enum MyEnum",
+            )?;
+            match variant.as_str() {
+                "First" => {
+                    pathogen::__private::from_patch_value::unit_variant(content)?;
+                    Ok(Self::First)
+                }
+                "Second" => {
+                    let [field0, field1] = pathogen::__private::from_patch_value::array(
+                        pathogen::__private::from_patch_value::variant_content(
+                            content,
+                            "tuple variant",
+                        )?,
+                        "tuple variant MyEnum::Second",
+                    )?;
+                    Ok(
+                        Self::Second(
+                            <usize as pathogen::FromPatchValue>::from_patch_value(field0)?,
+                            pathogen::__private::serde_json::from_value::<String>(field1)?,
+                        ),
+                    )
+                }
+                "Third" => {
+                    Ok(
+                        Self::Third(
+                            <isize as pathogen::FromPatchValue>::from_patch_value(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                variant => {
+                    Err(
+                        pathogen::__private::from_patch_value::unknown_variant(
+                            variant,
+                            &["First", "Second", "Third"],
+                        ),
+                    )
+                }
+            }
+        }
+    }
+    impl pathogen::KeyPathMutable for MyEnum {
+        fn patch_keypath(
+            &mut self,
+            keys: &[pathogen::KeyPathElement],
+            patch: pathogen::Patch,
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value(value)
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value({
+                                let mut map = pathogen::__private::serde_json::Map::new();
+                                map.insert(variant.to_string(), value);
+                                pathogen::__private::serde_json::Value::Object(map)
+                            })
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key: field_name } = &keys[1] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match self {
+                Self::Second(value0, _value1) if variant == "Second" => {
+                    match field_name.as_ref() {
+                        "0" => value0.patch_keypath(&keys[2..], patch),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    Self,
+                                >("Second", field_name),
+                            )
+                        }
+                    }
+                }
+                Self::Third(value0) if variant == "Third" => {
+                    match field_name.as_ref() {
+                        "0" => value0.patch_keypath(&keys[2..], patch),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    Self,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => Ok(()),
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "Second" => {
+                    match field_name.as_ref() {
+                        "0" => <usize as pathogen::KeyPathMutable>::validate_path(&keys[2..]),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Second", field_name),
+                            )
+                        }
+                    }
+                }
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => <isize as pathogen::KeyPathMutable>::validate_path(&keys[2..]),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "Second" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <usize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Second", field_name),
+                            )
+                        }
+                    }
+                }
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <isize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+    }
+    "###);
+}
+
+#[test]
+fn enum_with_all_items_skipped_in_a_tuple_variant() {
+    let input = r#"
+            enum MyEnum {
+                First,
+                Second(#[keypath_mutable(skip)] usize, #[keypath_mutable(skip)] String),
+                Third(isize),
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = KeyPathMutableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyEnum {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let (variant, content) = pathogen::__private::from_patch_value::externally_tagged(
+                value,
+                "enum MyEnum",
+            )?;
+            match variant.as_str() {
+                "First" => {
+                    pathogen::__private::from_patch_value::unit_variant(content)?;
+                    Ok(Self::First)
+                }
+                "Second" => {
+                    let [field0, field1] = pathogen::__private::from_patch_value::array(
+                        pathogen::__private::from_patch_value::variant_content(
+                            content,
+                            "tuple variant",
+                        )?,
+                        "tuple variant MyEnum::Second",
+                    )?;
+                    Ok(
+                        Self::Second(
+                            pathogen::__private::serde_json::from_value::<usize>(field0)?,
+                            pathogen::__private::serde_json::from_value::<String>(field1)?,
+                        ),
+                    )
+                }
+                "Third" => {
+                    Ok(
+                        Self::Third(
+                            <isize as pathogen::FromPatchValue>::from_patch_value(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                variant => {
+                    Err(
+                        pathogen::__private::from_patch_value::unknown_variant(
+                            variant,
+                            &["First", "Second", "Third"],
+                        ),
+                    )
+                }
+            }
+        }
+    }
+    impl pathogen::KeyPathMutable for MyEnum {
+        fn patch_keypath(
+            &mut self,
+            keys: &[pathogen::KeyPathElement],
+            patch: pathogen::Patch,
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value(value)
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value({
+                                let mut map = pathogen::__private::serde_json::Map::new();
+                                map.insert(variant.to_string(), value);
+                                pathogen::__private::serde_json::Value::Object(map)
+                            })
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key: field_name } = &keys[1] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match self {
+                Self::Second(_, _) if variant == "Second" => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            Self,
+                        >("Second", field_name),
+                    )
+                }
+                Self::Third(value0) if variant == "Third" => {
+                    match field_name.as_ref() {
+                        "0" => value0.patch_keypath(&keys[2..], patch),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    Self,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => Ok(()),
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => <isize as pathogen::KeyPathMutable>::validate_path(&keys[2..]),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <isize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+    }
+    "###);
+}
+
+#[test]
+fn enum_with_all_fields_skipped_in_a_struct_variant() {
+    let input = r#"
+            enum MyEnum {
+                First,
+                Second { #[keypath_mutable(skip)] a: usize, #[keypath_mutable(skip)] b: String },
+                Third(isize),
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = KeyPathMutableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyEnum {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let (variant, content) = pathogen::__private::from_patch_value::externally_tagged(
+                value,
+                "enum MyEnum",
+            )?;
+            match variant.as_str() {
+                "First" => {
+                    pathogen::__private::from_patch_value::unit_variant(content)?;
+                    Ok(Self::First)
+                }
+                "Second" => {
+                    let mut members = pathogen::__private::from_patch_value::object(
+                        pathogen::__private::from_patch_value::variant_content(
+                            content,
+                            "struct variant",
+                        )?,
+                        "struct variant MyEnum::Second",
+                    )?;
+                    let field0 = pathogen::__private::from_patch_value::deserialize_field::<
+                        usize,
+                    >(
+                        pathogen::__private::from_patch_value::take(&mut members, "a", &[]),
+                        "a",
+                    )?;
+                    let field1 = pathogen::__private::from_patch_value::deserialize_field::<
+                        String,
+                    >(
+                        pathogen::__private::from_patch_value::take(&mut members, "b", &[]),
+                        "b",
+                    )?;
+                    Ok(Self::Second {
+                        a: field0,
+                        b: field1,
+                    })
+                }
+                "Third" => {
+                    Ok(
+                        Self::Third(
+                            <isize as pathogen::FromPatchValue>::from_patch_value(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                variant => {
+                    Err(
+                        pathogen::__private::from_patch_value::unknown_variant(
+                            variant,
+                            &["First", "Second", "Third"],
+                        ),
+                    )
+                }
+            }
+        }
+    }
+    impl pathogen::KeyPathMutable for MyEnum {
+        fn patch_keypath(
+            &mut self,
+            keys: &[pathogen::KeyPathElement],
+            patch: pathogen::Patch,
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value(value)
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value({
+                                let mut map = pathogen::__private::serde_json::Map::new();
+                                map.insert(variant.to_string(), value);
+                                pathogen::__private::serde_json::Value::Object(map)
+                            })
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key: field_name } = &keys[1] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match self {
+                Self::Second { .. } if variant == "Second" => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            Self,
+                        >("Second", field_name),
+                    )
+                }
+                Self::Third(value0) if variant == "Third" => {
+                    match field_name.as_ref() {
+                        "0" => value0.patch_keypath(&keys[2..], patch),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    Self,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => Ok(()),
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => <isize as pathogen::KeyPathMutable>::validate_path(&keys[2..]),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <isize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+    }
+    "###);
+}
+
+#[test]
+fn enum_with_skipped_all_on_a_struct_variant() {
+    let input = r#"
+            enum MyEnum {
+                First,
+                #[keypath_mutable(skip_all)]
+                Second { a: usize, b: String },
+                Third(isize),
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = KeyPathMutableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyEnum {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let (variant, content) = pathogen::__private::from_patch_value::externally_tagged(
+                value,
+                "enum MyEnum",
+            )?;
+            match variant.as_str() {
+                "First" => {
+                    pathogen::__private::from_patch_value::unit_variant(content)?;
+                    Ok(Self::First)
+                }
+                "Second" => {
+                    let mut members = pathogen::__private::from_patch_value::object(
+                        pathogen::__private::from_patch_value::variant_content(
+                            content,
+                            "struct variant",
+                        )?,
+                        "struct variant MyEnum::Second",
+                    )?;
+                    let field0 = pathogen::__private::from_patch_value::deserialize_field::<
+                        usize,
+                    >(
+                        pathogen::__private::from_patch_value::take(&mut members, "a", &[]),
+                        "a",
+                    )?;
+                    let field1 = pathogen::__private::from_patch_value::deserialize_field::<
+                        String,
+                    >(
+                        pathogen::__private::from_patch_value::take(&mut members, "b", &[]),
+                        "b",
+                    )?;
+                    Ok(Self::Second {
+                        a: field0,
+                        b: field1,
+                    })
+                }
+                "Third" => {
+                    Ok(
+                        Self::Third(
+                            <isize as pathogen::FromPatchValue>::from_patch_value(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                variant => {
+                    Err(
+                        pathogen::__private::from_patch_value::unknown_variant(
+                            variant,
+                            &["First", "Second", "Third"],
+                        ),
+                    )
+                }
+            }
+        }
+    }
+    impl pathogen::KeyPathMutable for MyEnum {
+        fn patch_keypath(
+            &mut self,
+            keys: &[pathogen::KeyPathElement],
+            patch: pathogen::Patch,
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value(value)
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value({
+                                let mut map = pathogen::__private::serde_json::Map::new();
+                                map.insert(variant.to_string(), value);
+                                pathogen::__private::serde_json::Value::Object(map)
+                            })
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key: field_name } = &keys[1] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match self {
+                Self::Second { .. } if variant == "Second" => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            Self,
+                        >("Second", field_name),
+                    )
+                }
+                Self::Third(value0) if variant == "Third" => {
+                    match field_name.as_ref() {
+                        "0" => value0.patch_keypath(&keys[2..], patch),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    Self,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => Ok(()),
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => <isize as pathogen::KeyPathMutable>::validate_path(&keys[2..]),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" | "Third" => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "Third" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <isize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Third", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+    }
+    "###);
+}
+
+#[test]
+fn struct_with_a_read_only_field() {
+    let input = r#"
+            struct MyStruct {
+                #[keypath_mutable(read_only)]
+                id: usize,
+                name: String,
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = KeyPathMutableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyStruct {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let mut members = pathogen::__private::from_patch_value::object(
+                value,
+                "struct MyStruct",
+            )?;
+            let field0 = pathogen::__private::from_patch_value::deserialize_field::<
+                usize,
+            >(pathogen::__private::from_patch_value::take(&mut members, "id", &[]), "id")?;
+            let field1 = pathogen::__private::from_patch_value::field::<
+                String,
+            >(
+                pathogen::__private::from_patch_value::take(&mut members, "name", &[]),
+                "name",
+            )?;
+            Ok(Self { id: field0, name: field1 })
+        }
+    }
+    impl pathogen::KeyPathMutable for MyStruct {
+        fn patch_keypath(
+            &mut self,
+            keys: &[pathogen::KeyPathElement],
+            patch: pathogen::Patch,
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyStruct as pathogen::FromPatchValue>::from_patch_value(
+                                value,
+                            )
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    MyStruct,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyStruct>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            match key.as_ref() {
+                "id" => Err(pathogen::KeyPathError::read_only_field::<MyStruct>("id")),
+                "name" => self.name.patch_keypath(&keys[1..], patch),
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            match key.as_ref() {
+                "id" => Err(pathogen::KeyPathError::read_only_field::<MyStruct>("id")),
+                "name" => <String as pathogen::KeyPathMutable>::validate_path(&keys[1..]),
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
+        }
+        fn validate_splice(
             keys: &[pathogen::KeyPathElement],
-            patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<MyStruct>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<MyStruct>())
-                };
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>());
             }
-            let pathogen::KeyPathElement::Field { key } = keys[0] else {
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
                 );
             };
-            match key {
-                "long_field" => self.long_field.patch_keypath(&keys[1..], patch),
-                "and_one_more" => self.and_one_more.patch_keypath(&keys[1..], patch),
+            match key.as_ref() {
+                "id" => Err(pathogen::KeyPathError::read_only_field::<MyStruct>("id")),
+                "name" => <String as pathogen::KeyPathMutable>::validate_splice(&keys[1..]),
                 _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
             }
         }
@@ -681,13 +4494,11 @@ fn struct_with_a_skip() {
 }
 
 #[test]
-fn enum_with_a_skip() {
+fn enum_with_a_read_only_field_in_a_struct_variant() {
     let input = r#"
             enum MyEnum {
                 First,
-                #[keypath_mutable(skip)]
-                Second(usize),
-                Third(isize),
+                Second { #[keypath_mutable(read_only)] id: usize, b: String },
             }
         "#;
 
@@ -697,6 +4508,55 @@ fn enum_with_a_skip() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyEnum {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let (variant, content) = pathogen::__private::from_patch_value::externally_tagged(
+                value,
+                "enum MyEnum",
+            )?;
+            match variant.as_str() {
+                "First" => {
+                    pathogen::__private::from_patch_value::unit_variant(content)?;
+                    Ok(Self::First)
+                }
+                "Second" => {
+                    let mut members = pathogen::__private::from_patch_value::object(
+                        pathogen::__private::from_patch_value::variant_content(
+                            content,
+                            "struct variant",
+                        )?,
+                        "struct variant MyEnum::Second",
+                    )?;
+                    let field0 = pathogen::__private::from_patch_value::deserialize_field::<
+                        usize,
+                    >(
+                        pathogen::__private::from_patch_value::take(&mut members, "id", &[]),
+                        "id",
+                    )?;
+                    let field1 = pathogen::__private::from_patch_value::field::<
+                        String,
+                    >(
+                        pathogen::__private::from_patch_value::take(&mut members, "b", &[]),
+                        "b",
+                    )?;
+                    Ok(Self::Second {
+                        id: field0,
+                        b: field1,
+                    })
+                }
+                variant => {
+                    Err(
+                        pathogen::__private::from_patch_value::unknown_variant(
+                            variant,
+                            &["First", "Second"],
+                        ),
+                    )
+                }
+            }
+        }
+    }
     impl pathogen::KeyPathMutable for MyEnum {
         fn patch_keypath(
             &mut self,
@@ -704,22 +4564,49 @@ fn enum_with_a_skip() {
             patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<MyEnum>())
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value(value)
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
                 };
             }
-            let pathogen::KeyPathElement::Variant { key: variant, .. } = keys[0] else {
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
                 );
             };
-            let pathogen::KeyPathElement::Field { key: field_name } = keys[1] else {
+            if keys.len() == 1 {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value({
+                                let mut map = pathogen::__private::serde_json::Map::new();
+                                map.insert(variant.to_string(), value);
+                                pathogen::__private::serde_json::Value::Object(map)
+                            })
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key: field_name } = &keys[1] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
                         MyEnum,
@@ -727,14 +4614,15 @@ fn enum_with_a_skip() {
                 );
             };
             match self {
-                Self::Third(value0) if variant == "Third" => {
-                    match field_name {
-                        "0" => value0.patch_keypath(&keys[2..], patch),
+                Self::Second { id: _, b } if variant == "Second" => {
+                    match field_name.as_ref() {
+                        "id" => Err(pathogen::KeyPathError::read_only_field::<Self>("id")),
+                        "b" => b.patch_keypath(&keys[2..], patch),
                         _ => {
                             Err(
                                 pathogen::KeyPathError::unknown_variant_or_field::<
                                     Self,
-                                >("Third", field_name),
+                                >("Second", field_name),
                             )
                         }
                     }
@@ -748,72 +4636,105 @@ fn enum_with_a_skip() {
                 }
             }
         }
-    }
-    "###);
-}
-
-#[test]
-fn enum_with_skip_all() {
-    let input = r#"
-            enum MyEnum {
-                First,
-                #[keypath_mutable(skip_all)]
-                Second(usize),
-                Third(isize),
-            }
-        "#;
-
-    let input = parse_str(input).unwrap();
-    let input = KeyPathMutableType::from_derive_input(&input).unwrap();
-
-    let actual = quote!(#input);
-
-    insta::assert_snapshot!(pretty_print(&actual), @r###"
-    impl pathogen::KeyPathMutable for MyEnum {
-        fn patch_keypath(
-            &mut self,
+        fn validate_path(
             keys: &[pathogen::KeyPathElement],
-            patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<MyEnum>())
-                };
+                return Ok(());
             }
-            let pathogen::KeyPathElement::Variant { key: variant, .. } = keys[0] else {
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
                 );
             };
-            let pathogen::KeyPathElement::Field { key: field_name } = keys[1] else {
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" => Ok(()),
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
                         MyEnum,
                     >(variant),
                 );
             };
-            match self {
-                Self::Second(_) if variant == "Second" => {
+            match variant.as_ref() {
+                "Second" => {
+                    match field_name.as_ref() {
+                        "id" => Err(pathogen::KeyPathError::read_only_field::<MyEnum>("id")),
+                        "b" => {
+                            <String as pathogen::KeyPathMutable>::validate_path(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("Second", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
                     Err(
                         pathogen::KeyPathError::unknown_variant_or_field::<
-                            Self,
-                        >("Second", field_name),
+                            MyEnum,
+                        >(variant, field_name),
                     )
                 }
-                Self::Third(value0) if variant == "Third" => {
-                    match field_name {
-                        "0" => value0.patch_keypath(&keys[2..], patch),
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "First" | "Second" => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "Second" => {
+                    match field_name.as_ref() {
+                        "id" => Err(pathogen::KeyPathError::read_only_field::<MyEnum>("id")),
+                        "b" => {
+                            <String as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
                         _ => {
                             Err(
                                 pathogen::KeyPathError::unknown_variant_or_field::<
-                                    Self,
-                                >("Third", field_name),
+                                    MyEnum,
+                                >("Second", field_name),
                             )
                         }
                     }
@@ -832,13 +4753,12 @@ fn enum_with_skip_all() {
 }
 
 #[test]
-fn enum_with_a_top_level_skip_all() {
+fn struct_with_serde_rename_all_uppercase() {
     let input = r#"
-            #[keypath_mutable(skip_all)]
-            enum MyEnum {
-                First,
-                Second(usize),
-                Third(isize),
+            #[derive(KeyPathMutable)]
+            #[serde(rename_all = "UPPERCASE")]
+            struct MyStruct {
+                my_string: String,
             }
         "#;
 
@@ -848,52 +4768,108 @@ fn enum_with_a_top_level_skip_all() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
-    impl pathogen::KeyPathMutable for MyEnum {
+    impl pathogen::FromPatchValue for MyStruct {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let mut members = pathogen::__private::from_patch_value::object(
+                value,
+                "struct MyStruct",
+            )?;
+            let field0 = pathogen::__private::from_patch_value::field::<
+                String,
+            >(
+                pathogen::__private::from_patch_value::take(&mut members, "MY_STRING", &[]),
+                "MY_STRING",
+            )?;
+            Ok(Self { my_string: field0 })
+        }
+    }
+    impl pathogen::KeyPathMutable for MyStruct {
         fn patch_keypath(
             &mut self,
             keys: &[pathogen::KeyPathElement],
             patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<MyEnum>())
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyStruct as pathogen::FromPatchValue>::from_patch_value(
+                                value,
+                            )
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    MyStruct,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyStruct>())
+                    }
                 };
             }
-            let pathogen::KeyPathElement::Variant { key: variant, .. } = keys[0] else {
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
                 return Err(
-                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            match key.as_ref() {
+                "MY_STRING" => self.my_string.patch_keypath(&keys[1..], patch),
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
                 );
             };
-            let pathogen::KeyPathElement::Field { key: field_name } = keys[1] else {
+            match key.as_ref() {
+                "MY_STRING" => {
+                    <String as pathogen::KeyPathMutable>::validate_path(&keys[1..])
+                }
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyStruct>());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
                 return Err(
-                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
-                        MyEnum,
-                    >(variant),
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
                 );
             };
-            Err(
-                pathogen::KeyPathError::unknown_variant_or_field::<
-                    MyEnum,
-                >(variant, field_name),
-            )
+            match key.as_ref() {
+                "MY_STRING" => {
+                    <String as pathogen::KeyPathMutable>::validate_splice(&keys[1..])
+                }
+                _ => Err(pathogen::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
         }
     }
     "###);
 }
 
 #[test]
-fn enum_with_a_skip_in_a_struct_variant() {
+fn enum_with_serde_rename_all_lowercase() {
     let input = r#"
+            #[derive(KeyPathMutable)]
+            #[serde(rename_all = "lowercase")]
             enum MyEnum {
-                First,
-                Second { a: usize, #[keypath_mutable(skip)] b: String },
-                Third(isize),
+                VariantOne { my_field: usize },
+                VariantTwo(String),
             }
         "#;
 
@@ -903,56 +4879,275 @@ fn enum_with_a_skip_in_a_struct_variant() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::FromPatchValue for MyEnum {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let (variant, content) = pathogen::__private::from_patch_value::externally_tagged(
+                value,
+                "enum MyEnum",
+            )?;
+            match variant.as_str() {
+                "variantone" => {
+                    let mut members = pathogen::__private::from_patch_value::object(
+                        pathogen::__private::from_patch_value::variant_content(
+                            content,
+                            "struct variant",
+                        )?,
+                        "struct variant MyEnum::VariantOne",
+                    )?;
+                    let field0 = pathogen::__private::from_patch_value::field::<
+                        usize,
+                    >(
+                        pathogen::__private::from_patch_value::take(
+                            &mut members,
+                            "my_field",
+                            &[],
+                        ),
+                        "my_field",
+                    )?;
+                    Ok(Self::VariantOne {
+                        my_field: field0,
+                    })
+                }
+                "varianttwo" => {
+                    Ok(
+                        Self::VariantTwo(
+                            <String as pathogen::FromPatchValue>::from_patch_value(
+                                pathogen::__private::from_patch_value::variant_content(
+                                    content,
+                                    "newtype variant",
+                                )?,
+                            )?,
+                        ),
+                    )
+                }
+                variant => {
+                    Err(
+                        pathogen::__private::from_patch_value::unknown_variant(
+                            variant,
+                            &["variantone", "varianttwo"],
+                        ),
+                    )
+                }
+            }
+        }
+    }
     impl pathogen::KeyPathMutable for MyEnum {
         fn patch_keypath(
             &mut self,
             keys: &[pathogen::KeyPathElement],
-            patch: pathogen::Patch,
+            patch: pathogen::Patch,
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value(value)
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <MyEnum as pathogen::FromPatchValue>::from_patch_value({
+                                let mut map = pathogen::__private::serde_json::Map::new();
+                                map.insert(variant.to_string(), value);
+                                pathogen::__private::serde_json::Value::Object(map)
+                            })
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<MyEnum>())
+                    }
+                };
+            }
+            let pathogen::KeyPathElement::Field { key: field_name } = &keys[1] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match self {
+                Self::VariantOne { my_field } if variant == "variantone" => {
+                    match field_name.as_ref() {
+                        "my_field" => my_field.patch_keypath(&keys[2..], patch),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    Self,
+                                >("variantone", field_name),
+                            )
+                        }
+                    }
+                }
+                Self::VariantTwo(value0) if variant == "varianttwo" => {
+                    match field_name.as_ref() {
+                        "0" => value0.patch_keypath(&keys[2..], patch),
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    Self,
+                                >("varianttwo", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                );
+            };
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "variantone" | "varianttwo" => Ok(()),
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
+                        MyEnum,
+                    >(variant),
+                );
+            };
+            match variant.as_ref() {
+                "variantone" => {
+                    match field_name.as_ref() {
+                        "my_field" => {
+                            <usize as pathogen::KeyPathMutable>::validate_path(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("variantone", field_name),
+                            )
+                        }
+                    }
+                }
+                "varianttwo" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <String as pathogen::KeyPathMutable>::validate_path(&keys[2..])
+                        }
+                        _ => {
+                            Err(
+                                pathogen::KeyPathError::unknown_variant_or_field::<
+                                    MyEnum,
+                                >("varianttwo", field_name),
+                            )
+                        }
+                    }
+                }
+                _ => {
+                    Err(
+                        pathogen::KeyPathError::unknown_variant_or_field::<
+                            MyEnum,
+                        >(variant, field_name),
+                    )
+                }
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<MyEnum>())
-                };
+                return Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>());
             }
-            let pathogen::KeyPathElement::Variant { key: variant, .. } = keys[0] else {
+            let pathogen::KeyPathElement::Variant { key: variant, .. } = &keys[0] else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
                 );
             };
-            let pathogen::KeyPathElement::Field { key: field_name } = keys[1] else {
+            if keys.len() == 1 {
+                return match variant.as_ref() {
+                    "variantone" | "varianttwo" => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<MyEnum>())
+                    }
+                    _ => {
+                        Err(
+                            pathogen::KeyPathError::unknown_variant_or_field::<
+                                MyEnum,
+                            >(variant, ""),
+                        )
+                    }
+                };
+            }
+            let Some(pathogen::KeyPathElement::Field { key: field_name }) = keys.get(1) else {
                 return Err(
                     pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
                         MyEnum,
                     >(variant),
                 );
             };
-            match self {
-                Self::Second { a, b: _ } if variant == "Second" => {
-                    match field_name {
-                        "a" => a.patch_keypath(&keys[2..], patch),
+            match variant.as_ref() {
+                "variantone" => {
+                    match field_name.as_ref() {
+                        "my_field" => {
+                            <usize as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
                         _ => {
                             Err(
                                 pathogen::KeyPathError::unknown_variant_or_field::<
-                                    Self,
-                                >("Second", field_name),
+                                    MyEnum,
+                                >("variantone", field_name),
                             )
                         }
                     }
                 }
-                Self::Third(value0) if variant == "Third" => {
-                    match field_name {
-                        "0" => value0.patch_keypath(&keys[2..], patch),
+                "varianttwo" => {
+                    match field_name.as_ref() {
+                        "0" => {
+                            <String as pathogen::KeyPathMutable>::validate_splice(&keys[2..])
+                        }
                         _ => {
                             Err(
                                 pathogen::KeyPathError::unknown_variant_or_field::<
-                                    Self,
-                                >("Third", field_name),
+                                    MyEnum,
+                                >("varianttwo", field_name),
                             )
                         }
                     }
@@ -971,12 +5166,12 @@ fn enum_with_a_skip_in_a_struct_variant() {
 }
 
 #[test]
-fn enum_with_a_skip_in_a_tuple_variant() {
+fn struct_with_a_type_parameter() {
     let input = r#"
-            enum MyEnum {
-                First,
-                Second(usize, #[keypath_mutable(skip)] String),
-                Third(isize),
+            #[derive(KeyPathMutable)]
+            struct Page<T> {
+                items: Vec<T>,
+                total: usize,
             }
         "#;
 
@@ -986,67 +5181,106 @@ fn enum_with_a_skip_in_a_tuple_variant() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
-    impl pathogen::KeyPathMutable for MyEnum {
+    impl<T> pathogen::FromPatchValue for Page<T>
+    where
+        T: pathogen::KeyPathMutable + pathogen::__private::serde::de::DeserializeOwned,
+    {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let mut members = pathogen::__private::from_patch_value::object(
+                value,
+                "struct Page",
+            )?;
+            let field0 = pathogen::__private::from_patch_value::field::<
+                Vec<T>,
+            >(
+                pathogen::__private::from_patch_value::take(&mut members, "items", &[]),
+                "items",
+            )?;
+            let field1 = pathogen::__private::from_patch_value::field::<
+                usize,
+            >(
+                pathogen::__private::from_patch_value::take(&mut members, "total", &[]),
+                "total",
+            )?;
+            Ok(Self {
+                items: field0,
+                total: field1,
+            })
+        }
+    }
+    impl<T> pathogen::KeyPathMutable for Page<T>
+    where
+        T: pathogen::KeyPathMutable + pathogen::__private::serde::de::DeserializeOwned,
+    {
         fn patch_keypath(
             &mut self,
             keys: &[pathogen::KeyPathElement],
             patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<MyEnum>())
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <Page<
+                            T,
+                        > as pathogen::FromPatchValue>::from_patch_value(value)
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<Page<T>>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<Page<T>>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<Page<T>>())
+                    }
                 };
             }
-            let pathogen::KeyPathElement::Variant { key: variant, .. } = keys[0] else {
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
                 return Err(
-                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<Page<T>>(),
                 );
             };
-            let pathogen::KeyPathElement::Field { key: field_name } = keys[1] else {
+            match key.as_ref() {
+                "items" => self.items.patch_keypath(&keys[1..], patch),
+                "total" => self.total.patch_keypath(&keys[1..], patch),
+                _ => Err(pathogen::KeyPathError::unknown_field::<Page<T>>(key)),
+            }
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
                 return Err(
-                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
-                        MyEnum,
-                    >(variant),
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<Page<T>>(),
                 );
             };
-            match self {
-                Self::Second(value0, _value1) if variant == "Second" => {
-                    match field_name {
-                        "0" => value0.patch_keypath(&keys[2..], patch),
-                        _ => {
-                            Err(
-                                pathogen::KeyPathError::unknown_variant_or_field::<
-                                    Self,
-                                >("Second", field_name),
-                            )
-                        }
-                    }
-                }
-                Self::Third(value0) if variant == "Third" => {
-                    match field_name {
-                        "0" => value0.patch_keypath(&keys[2..], patch),
-                        _ => {
-                            Err(
-                                pathogen::KeyPathError::unknown_variant_or_field::<
-                                    Self,
-                                >("Third", field_name),
-                            )
-                        }
-                    }
-                }
-                _ => {
-                    Err(
-                        pathogen::KeyPathError::unknown_variant_or_field::<
-                            MyEnum,
-                        >(variant, field_name),
-                    )
-                }
+            match key.as_ref() {
+                "items" => <Vec<T> as pathogen::KeyPathMutable>::validate_path(&keys[1..]),
+                "total" => <usize as pathogen::KeyPathMutable>::validate_path(&keys[1..]),
+                _ => Err(pathogen::KeyPathError::unknown_field::<Page<T>>(key)),
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<Page<T>>());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<Page<T>>(),
+                );
+            };
+            match key.as_ref() {
+                "items" => <Vec<T> as pathogen::KeyPathMutable>::validate_splice(&keys[1..]),
+                "total" => <usize as pathogen::KeyPathMutable>::validate_splice(&keys[1..]),
+                _ => Err(pathogen::KeyPathError::unknown_field::<Page<T>>(key)),
             }
         }
     }
@@ -1054,12 +5288,14 @@ fn enum_with_a_skip_in_a_tuple_variant() {
 }
 
 #[test]
-fn enum_with_all_items_skipped_in_a_tuple_variant() {
+fn struct_with_a_where_clause() {
     let input = r#"
-            enum MyEnum {
-                First,
-                Second(#[keypath_mutable(skip)] usize, #[keypath_mutable(skip)] String),
-                Third(isize),
+            #[derive(KeyPathMutable)]
+            struct Lookup<K, V>
+            where
+                K: Ord,
+            {
+                entries: BTreeMap<K, V>,
             }
         "#;
 
@@ -1069,62 +5305,105 @@ fn enum_with_all_items_skipped_in_a_tuple_variant() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
-    impl pathogen::KeyPathMutable for MyEnum {
+    impl<K, V> pathogen::FromPatchValue for Lookup<K, V>
+    where
+        K: Ord,
+        K: pathogen::KeyPathMutable + pathogen::__private::serde::de::DeserializeOwned,
+        V: pathogen::KeyPathMutable + pathogen::__private::serde::de::DeserializeOwned,
+    {
+        fn from_patch_value(
+            value: pathogen::__private::serde_json::Value,
+        ) -> Result<Self, pathogen::__private::serde_json::Error> {
+            let mut members = pathogen::__private::from_patch_value::object(
+                value,
+                "struct Lookup",
+            )?;
+            let field0 = pathogen::__private::from_patch_value::field::<
+                BTreeMap<K, V>,
+            >(
+                pathogen::__private::from_patch_value::take(&mut members, "entries", &[]),
+                "entries",
+            )?;
+            Ok(Self { entries: field0 })
+        }
+    }
+    impl<K, V> pathogen::KeyPathMutable for Lookup<K, V>
+    where
+        K: Ord,
+        K: pathogen::KeyPathMutable + pathogen::__private::serde::de::DeserializeOwned,
+        V: pathogen::KeyPathMutable + pathogen::__private::serde::de::DeserializeOwned,
+    {
         fn patch_keypath(
             &mut self,
             keys: &[pathogen::KeyPathElement],
             patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<MyEnum>())
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <Lookup<
+                            K,
+                            V,
+                        > as pathogen::FromPatchValue>::from_patch_value(value)
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<
+                                    Lookup<K, V>,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<Lookup<K, V>>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<Lookup<K, V>>())
+                    }
                 };
             }
-            let pathogen::KeyPathElement::Variant { key: variant, .. } = keys[0] else {
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
                 return Err(
-                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<Lookup<K, V>>(),
                 );
             };
-            let pathogen::KeyPathElement::Field { key: field_name } = keys[1] else {
+            match key.as_ref() {
+                "entries" => self.entries.patch_keypath(&keys[1..], patch),
+                _ => Err(pathogen::KeyPathError::unknown_field::<Lookup<K, V>>(key)),
+            }
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
                 return Err(
-                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
-                        MyEnum,
-                    >(variant),
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<Lookup<K, V>>(),
                 );
             };
-            match self {
-                Self::Second(_, _) if variant == "Second" => {
-                    Err(
-                        pathogen::KeyPathError::unknown_variant_or_field::<
-                            Self,
-                        >("Second", field_name),
-                    )
+            match key.as_ref() {
+                "entries" => {
+                    <BTreeMap<K, V> as pathogen::KeyPathMutable>::validate_path(&keys[1..])
                 }
-                Self::Third(value0) if variant == "Third" => {
-                    match field_name {
-                        "0" => value0.patch_keypath(&keys[2..], patch),
-                        _ => {
-                            Err(
-                                pathogen::KeyPathError::unknown_variant_or_field::<
-                                    Self,
-                                >("Third", field_name),
-                            )
-                        }
-                    }
-                }
-                _ => {
-                    Err(
-                        pathogen::KeyPathError::unknown_variant_or_field::<
-                            MyEnum,
-                        >(variant, field_name),
-                    )
+                _ => Err(pathogen::KeyPathError::unknown_field::<Lookup<K, V>>(key)),
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<Lookup<K, V>>());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    pathogen::KeyPathError::must_mutate_struct_with_field::<Lookup<K, V>>(),
+                );
+            };
+            match key.as_ref() {
+                "entries" => {
+                    <BTreeMap<K, V> as pathogen::KeyPathMutable>::validate_splice(&keys[1..])
                 }
+                _ => Err(pathogen::KeyPathError::unknown_field::<Lookup<K, V>>(key)),
             }
         }
     }
@@ -1132,13 +5411,11 @@ fn enum_with_all_items_skipped_in_a_tuple_variant() {
 }
 
 #[test]
-fn enum_with_all_fields_skipped_in_a_struct_variant() {
+fn newtype_struct_with_a_custom_from_patch_value() {
     let input = r#"
-            enum MyEnum {
-                First,
-                Second { #[keypath_mutable(skip)] a: usize, #[keypath_mutable(skip)] b: String },
-                Third(isize),
-            }
+            #[derive(KeyPathMutable)]
+            #[keypath_mutable(custom_from_patch_value)]
+            struct Cents(u64);
         "#;
 
     let input = parse_str(input).unwrap();
@@ -1147,62 +5424,63 @@ fn enum_with_all_fields_skipped_in_a_struct_variant() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
-    impl pathogen::KeyPathMutable for MyEnum {
+    impl pathogen::KeyPathMutable for Cents {
         fn patch_keypath(
             &mut self,
             keys: &[pathogen::KeyPathElement],
             patch: pathogen::Patch,
         ) -> Result<(), pathogen::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<MyEnum>())
+                return match patch {
+                    pathogen::Patch::Update { value, .. } => {
+                        *self = <Cents as pathogen::FromPatchValue>::from_patch_value(value)
+                            .map_err(
+                                pathogen::KeyPathError::from_deserialization_error::<Cents>,
+                            )?;
+                        Ok(())
+                    }
+                    pathogen::Patch::Splice { .. } => {
+                        Err(pathogen::KeyPathError::splice_on_non_list::<Cents>())
+                    }
+                    pathogen::Patch::Delete { .. } => {
+                        Err(pathogen::KeyPathError::cannot_delete::<Cents>())
+                    }
                 };
             }
-            let pathogen::KeyPathElement::Variant { key: variant, .. } = keys[0] else {
-                return Err(
-                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
-                );
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(pathogen::KeyPathError::must_mutate_struct_with_field::<Cents>());
             };
-            let pathogen::KeyPathElement::Field { key: field_name } = keys[1] else {
-                return Err(
-                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
-                        MyEnum,
-                    >(variant),
-                );
+            match key.as_ref() {
+                "0" => self.0.patch_keypath(&keys[1..], patch),
+                _ => Err(pathogen::KeyPathError::unknown_field::<Cents>(key)),
+            }
+        }
+        fn validate_path(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(pathogen::KeyPathError::must_mutate_struct_with_field::<Cents>());
             };
-            match self {
-                Self::Second { .. } if variant == "Second" => {
-                    Err(
-                        pathogen::KeyPathError::unknown_variant_or_field::<
-                            Self,
-                        >("Second", field_name),
-                    )
-                }
-                Self::Third(value0) if variant == "Third" => {
-                    match field_name {
-                        "0" => value0.patch_keypath(&keys[2..], patch),
-                        _ => {
-                            Err(
-                                pathogen::KeyPathError::unknown_variant_or_field::<
-                                    Self,
-                                >("Third", field_name),
-                            )
-                        }
-                    }
-                }
-                _ => {
-                    Err(
-                        pathogen::KeyPathError::unknown_variant_or_field::<
-                            MyEnum,
-                        >(variant, field_name),
-                    )
-                }
+            match key.as_ref() {
+                "0" => <u64 as pathogen::KeyPathMutable>::validate_path(&keys[1..]),
+                _ => Err(pathogen::KeyPathError::unknown_field::<Cents>(key)),
+            }
+        }
+        fn validate_splice(
+            keys: &[pathogen::KeyPathElement],
+        ) -> Result<(), pathogen::KeyPathError> {
+            if keys.is_empty() {
+                return Err(pathogen::KeyPathError::splice_on_non_list::<Cents>());
+            }
+            let pathogen::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(pathogen::KeyPathError::must_mutate_struct_with_field::<Cents>());
+            };
+            match key.as_ref() {
+                "0" => <u64 as pathogen::KeyPathMutable>::validate_splice(&keys[1..]),
+                _ => Err(pathogen::KeyPathError::unknown_field::<Cents>(key)),
             }
         }
     }
@@ -1210,13 +5488,11 @@ fn enum_with_all_fields_skipped_in_a_struct_variant() {
 }
 
 #[test]
-fn enum_with_skipped_all_on_a_struct_variant() {
+fn struct_with_a_crate_path() {
     let input = r#"
-            enum MyEnum {
-                First,
-                #[keypath_mutable(skip_all)]
-                Second { a: usize, b: String },
-                Third(isize),
+            #[keypath_mutable(crate = "::my_reexport")]
+            struct MyStruct {
+                a: usize,
             }
         "#;
 
@@ -1226,62 +5502,90 @@ fn enum_with_skipped_all_on_a_struct_variant() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
-    impl pathogen::KeyPathMutable for MyEnum {
+    impl ::my_reexport::FromPatchValue for MyStruct {
+        fn from_patch_value(
+            value: ::my_reexport::__private::serde_json::Value,
+        ) -> Result<Self, ::my_reexport::__private::serde_json::Error> {
+            let mut members = ::my_reexport::__private::from_patch_value::object(
+                value,
+                "struct MyStruct",
+            )?;
+            let field0 = ::my_reexport::__private::from_patch_value::field::<
+                usize,
+            >(
+                ::my_reexport::__private::from_patch_value::take(&mut members, "a", &[]),
+                "a",
+            )?;
+            Ok(Self { a: field0 })
+        }
+    }
+    impl ::my_reexport::KeyPathMutable for MyStruct {
         fn patch_keypath(
             &mut self,
-            keys: &[pathogen::KeyPathElement],
-            patch: pathogen::Patch,
-        ) -> Result<(), pathogen::KeyPathError> {
+            keys: &[::my_reexport::KeyPathElement],
+            patch: ::my_reexport::Patch,
+        ) -> Result<(), ::my_reexport::KeyPathError> {
             if keys.is_empty() {
-                return if let pathogen::Patch::Update { value, .. } = patch {
-                    *self = serde_json::from_value(value)
-                        .map_err(
-                            pathogen::KeyPathError::from_deserialization_error::<MyEnum>,
-                        )?;
-                    Ok(())
-                } else {
-                    Err(pathogen::KeyPathError::cannot_splice_type::<MyEnum>())
+                return match patch {
+                    ::my_reexport::Patch::Update { value, .. } => {
+                        *self = <MyStruct as ::my_reexport::FromPatchValue>::from_patch_value(
+                                value,
+                            )
+                            .map_err(
+                                ::my_reexport::KeyPathError::from_deserialization_error::<
+                                    MyStruct,
+                                >,
+                            )?;
+                        Ok(())
+                    }
+                    ::my_reexport::Patch::Splice { .. } => {
+                        Err(::my_reexport::KeyPathError::splice_on_non_list::<MyStruct>())
+                    }
+                    ::my_reexport::Patch::Delete { .. } => {
+                        Err(::my_reexport::KeyPathError::cannot_delete::<MyStruct>())
+                    }
                 };
             }
-            let pathogen::KeyPathElement::Variant { key: variant, .. } = keys[0] else {
+            let ::my_reexport::KeyPathElement::Field { key } = &keys[0] else {
                 return Err(
-                    pathogen::KeyPathError::must_mutate_enum_with_variant::<MyEnum>(),
+                    ::my_reexport::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
                 );
             };
-            let pathogen::KeyPathElement::Field { key: field_name } = keys[1] else {
+            match key.as_ref() {
+                "a" => self.a.patch_keypath(&keys[1..], patch),
+                _ => Err(::my_reexport::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
+        }
+        fn validate_path(
+            keys: &[::my_reexport::KeyPathElement],
+        ) -> Result<(), ::my_reexport::KeyPathError> {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let ::my_reexport::KeyPathElement::Field { key } = &keys[0] else {
                 return Err(
-                    pathogen::KeyPathError::must_mutate_enum_variant_with_field::<
-                        MyEnum,
-                    >(variant),
+                    ::my_reexport::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
                 );
             };
-            match self {
-                Self::Second { .. } if variant == "Second" => {
-                    Err(
-                        pathogen::KeyPathError::unknown_variant_or_field::<
-                            Self,
-                        >("Second", field_name),
-                    )
-                }
-                Self::Third(value0) if variant == "Third" => {
-                    match field_name {
-                        "0" => value0.patch_keypath(&keys[2..], patch),
-                        _ => {
-                            Err(
-                                pathogen::KeyPathError::unknown_variant_or_field::<
-                                    Self,
-                                >("Third", field_name),
-                            )
-                        }
-                    }
-                }
-                _ => {
-                    Err(
-                        pathogen::KeyPathError::unknown_variant_or_field::<
-                            MyEnum,
-                        >(variant, field_name),
-                    )
-                }
+            match key.as_ref() {
+                "a" => <usize as ::my_reexport::KeyPathMutable>::validate_path(&keys[1..]),
+                _ => Err(::my_reexport::KeyPathError::unknown_field::<MyStruct>(key)),
+            }
+        }
+        fn validate_splice(
+            keys: &[::my_reexport::KeyPathElement],
+        ) -> Result<(), ::my_reexport::KeyPathError> {
+            if keys.is_empty() {
+                return Err(::my_reexport::KeyPathError::splice_on_non_list::<MyStruct>());
+            }
+            let ::my_reexport::KeyPathElement::Field { key } = &keys[0] else {
+                return Err(
+                    ::my_reexport::KeyPathError::must_mutate_struct_with_field::<MyStruct>(),
+                );
+            };
+            match key.as_ref() {
+                "a" => <usize as ::my_reexport::KeyPathMutable>::validate_splice(&keys[1..]),
+                _ => Err(::my_reexport::KeyPathError::unknown_field::<MyStruct>(key)),
             }
         }
     }
@@ -1,30 +1,72 @@
+mod from_patch_value;
+mod keypath_get;
 mod keypath_mutable;
 mod navigable;
 
-use std::env;
+use std::{cell::RefCell, env};
 
-use darling::FromAttributes;
+use darling::{
+    util::{Override, SpannedValue},
+    FromAttributes,
+};
 use proc_macro::TokenStream;
-use proc_macro_error::{abort_call_site, proc_macro_error};
+use proc_macro_error::{abort, abort_call_site, proc_macro_error};
 use quote::quote;
-use syn::{parse_macro_input, Ident};
+use syn::{ext::IdentExt, parse_macro_input, Ident};
 
+use keypath_get::keypath_get_impl;
 use keypath_mutable::keypath_mutable_impl;
 use navigable::navigable_impl;
 
-#[proc_macro_derive(Navigable)]
+#[proc_macro_derive(Navigable, attributes(navigable))]
 #[proc_macro_error]
 pub fn navigable(input: TokenStream) -> TokenStream {
     navigable_impl(&parse_macro_input!(input)).into()
 }
 
+#[proc_macro_derive(KeyPathGet, attributes(keypath_mutable))]
+#[proc_macro_error]
+pub fn keypath_get(input: TokenStream) -> TokenStream {
+    keypath_get_impl(&parse_macro_input!(input)).into()
+}
+
 #[proc_macro_derive(KeyPathMutable, attributes(keypath_mutable))]
 #[proc_macro_error]
 pub fn keypath_mutable(input: TokenStream) -> TokenStream {
     keypath_mutable_impl(&parse_macro_input!(input)).into()
 }
 
+/// Derives both `Navigable` and `KeyPathMutable`, honoring the attributes of each
+#[proc_macro_derive(Pathogen, attributes(navigable, keypath_mutable))]
+#[proc_macro_error]
+pub fn pathogen(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input);
+    let mut tokens = navigable_impl(&input);
+    tokens.extend(keypath_mutable_impl(&input));
+
+    tokens.into()
+}
+
+thread_local! {
+    /// The path set with a `crate = "..."` container attribute on the type being derived
+    static CRATE_PATH: RefCell<Option<syn::Path>> = const { RefCell::new(None) };
+}
+
+/// Expand a derive with the paths to pathogen overridden by `crate_path`, if there is one,
+/// e.g. when pathogen is re-exported under a different name
+fn with_crate_path<R>(crate_path: Option<syn::Path>, expand: impl FnOnce() -> R) -> R {
+    let outer = CRATE_PATH.with(|path| path.replace(crate_path));
+    let expanded = expand();
+    CRATE_PATH.with(|path| path.replace(outer));
+
+    expanded
+}
+
 fn crate_name() -> proc_macro2::TokenStream {
+    if let Some(crate_path) = CRATE_PATH.with(|path| path.borrow().clone()) {
+        return quote! { #crate_path };
+    }
+
     let in_self = env::var("CARGO_PKG_NAME").unwrap() == "pathogen";
     if in_self {
         quote! { crate }
@@ -39,15 +81,58 @@ fn crate_name() -> proc_macro2::TokenStream {
 struct ContainerSerdeAttrs {
     rename_all: Option<String>,
     tag: Option<String>,
-    content: Option<String>,
+    content: Option<SpannedValue<String>>,
     untagged: Option<bool>,
+    deny_unknown_fields: Option<bool>,
+    transparent: Option<bool>,
+    default: Option<Override<syn::Path>>,
+    from: Option<syn::Type>,
+    try_from: Option<syn::Type>,
+}
+
+impl ContainerSerdeAttrs {
+    fn denies_unknown_fields(&self) -> bool {
+        self.deny_unknown_fields.unwrap_or(false)
+    }
+
+    fn is_transparent(&self) -> bool {
+        self.transparent.unwrap_or(false)
+    }
 }
 
 /// Used for attributes on fields or variants
-#[derive(FromAttributes, Debug)]
+#[derive(FromAttributes, Debug, Default)]
 #[darling(attributes(serde), allow_unknown_fields)]
 struct ItemSerdeAtrs {
     rename: Option<String>,
+    skip: Option<bool>,
+    skip_deserializing: Option<bool>,
+    #[darling(multiple)]
+    alias: Vec<String>,
+    default: Option<Override<syn::Path>>,
+    flatten: Option<bool>,
+    with: Option<syn::Path>,
+    deserialize_with: Option<syn::Path>,
+    other: Option<bool>,
+    untagged: Option<bool>,
+}
+
+impl ItemSerdeAtrs {
+    fn skips_deserializing(&self) -> bool {
+        self.skip.unwrap_or(false) || self.skip_deserializing.unwrap_or(false)
+    }
+
+    fn is_flattened(&self) -> bool {
+        self.flatten.unwrap_or(false)
+    }
+
+    fn is_other(&self) -> bool {
+        self.other.unwrap_or(false)
+    }
+
+    fn is_untagged(&self) -> bool {
+        self.untagged.unwrap_or(false)
+    }
 }
 
 enum VariantTagType {
@@ -57,10 +142,39 @@ enum VariantTagType {
     Untagged,
 }
 
+/// The serialized name of a field, renamed like serde does
 fn field_name(
     ident: &Ident,
     container_serde_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
     item_serde_attrs: &Result<ItemSerdeAtrs, darling::Error>,
+) -> String {
+    serialized_name(
+        ident,
+        container_serde_attrs,
+        item_serde_attrs,
+        RenameRule::apply_to_field,
+    )
+}
+
+/// The serialized name of an enum variant, renamed like serde does
+fn variant_name(
+    ident: &Ident,
+    container_serde_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
+    item_serde_attrs: &Result<ItemSerdeAtrs, darling::Error>,
+) -> String {
+    serialized_name(
+        ident,
+        container_serde_attrs,
+        item_serde_attrs,
+        RenameRule::apply_to_variant,
+    )
+}
+
+fn serialized_name(
+    ident: &Ident,
+    container_serde_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
+    item_serde_attrs: &Result<ItemSerdeAtrs, darling::Error>,
+    apply_rule: fn(RenameRule, &str) -> String,
 ) -> String {
     if let Ok(item_attrs) = item_serde_attrs {
         if let Some(name) = &item_attrs.rename {
@@ -68,38 +182,110 @@ fn field_name(
         }
     }
 
+    // serde strips the `r#` of raw identifiers like `r#type`
+    let ident_str = ident.unraw().to_string();
+
     let Ok(conatiner_attrs) = container_serde_attrs else {
-        return ident.to_string();
+        return ident_str;
     };
 
-    let ident_str = ident.to_string();
-
     match conatiner_attrs.rename_all.as_deref() {
         None => ident_str,
-        Some("camelCase") => {
-            let mut upcase = false;
-            let mut renamed = ident_str[0..1].to_lowercase();
-
-            for chr in ident_str[1..].chars() {
-                if chr == '_' {
-                    upcase = true;
-                    continue;
-                }
-
-                if upcase {
-                    renamed.push_str(&chr.to_uppercase().to_string());
-                    upcase = false;
-                } else {
-                    renamed.push(chr);
-                }
-            }
+        Some(rule) => match RenameRule::from_str(rule) {
+            Some(rule) => apply_rule(rule, &ident_str),
+            None => abort_call_site!("Unsupported rename_all value: {}", rule),
+        },
+    }
+}
+
+/// The values of `rename_all`. serde assumes field names are snake_case and variant names are
+/// PascalCase, and converts each from there, so the same rule can leave one unchanged and change
+/// the other.
+#[derive(Clone, Copy)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
 
-            renamed
+impl RenameRule {
+    fn from_str(rule: &str) -> Option<Self> {
+        Some(match rule {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            _ => return None,
+        })
+    }
+
+    /// Rename a variant, the same way as serde's `RenameRule::apply_to_variant`
+    fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            Self::Pascal => variant.to_string(),
+            Self::Lower => variant.to_ascii_lowercase(),
+            Self::Upper => variant.to_ascii_uppercase(),
+            Self::Camel => variant[..1].to_ascii_lowercase() + &variant[1..],
+            Self::Snake => to_snake_case(variant),
+            Self::ScreamingSnake => to_snake_case(variant).to_ascii_uppercase(),
+            Self::Kebab => to_snake_case(variant).replace('_', "-"),
+            Self::ScreamingKebab => to_snake_case(variant)
+                .to_ascii_uppercase()
+                .replace('_', "-"),
+        }
+    }
+
+    /// Rename a field, the same way as serde's `RenameRule::apply_to_field`
+    fn apply_to_field(self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_string(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal => to_pascal_case(field),
+            Self::Camel => {
+                let pascal = to_pascal_case(field);
+                pascal[..1].to_ascii_lowercase() + &pascal[1..]
+            }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
         }
-        Some(other) => {
-            abort_call_site!("Unsupported rename_all value: {}", other);
+    }
+}
+
+/// Convert a PascalCase identifier to snake_case, the same way serde does for variant names
+fn to_snake_case(ident: &str) -> String {
+    let mut snake = String::new();
+
+    for (i, chr) in ident.char_indices() {
+        if i > 0 && chr.is_uppercase() {
+            snake.push('_');
         }
+        snake.push(chr.to_ascii_lowercase());
     }
+
+    snake
+}
+
+/// Convert a snake_case identifier to PascalCase, the same way serde does for field names
+fn to_pascal_case(ident: &str) -> String {
+    ident
+        .split('_')
+        .map(|segment| {
+            let mut chars = segment.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
 }
 
 fn tag_type_from_serde_attrs(
@@ -108,7 +294,16 @@ fn tag_type_from_serde_attrs(
     let Ok(attrs) = attrs else {
         return VariantTagType::External;
     };
-    if attrs.content.is_some() {
+    if let Some(content) = &attrs.content {
+        // serde rejects this too, but we'd otherwise emit keypaths for a representation which
+        // doesn't exist
+        if attrs.tag.is_none() {
+            abort!(
+                content.span(),
+                "#[serde(tag = \"...\", content = \"...\")] must be used together"
+            );
+        }
+
         VariantTagType::Adjacent
     } else if attrs.tag.is_some() {
         VariantTagType::Internal
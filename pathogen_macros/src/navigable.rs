@@ -5,10 +5,11 @@ use darling::{
 use proc_macro2::TokenStream;
 use proc_macro_error::abort_call_site;
 use quote::{quote, ToTokens};
-use syn::{DeriveInput, Ident};
+use syn::{ext::IdentExt, parse_quote, DeriveInput, GenericParam, Generics, Ident};
 
 use crate::{
-    field_name, tag_type_from_serde_attrs, ContainerSerdeAttrs, ItemSerdeAtrs, VariantTagType,
+    field_name, tag_type_from_serde_attrs, to_snake_case, variant_name, ContainerSerdeAttrs,
+    ItemSerdeAtrs, VariantTagType,
 };
 
 pub(crate) fn navigable_impl(input: &DeriveInput) -> TokenStream {
@@ -23,9 +24,10 @@ pub(crate) fn navigable_impl(input: &DeriveInput) -> TokenStream {
 }
 
 #[derive(FromDeriveInput, Debug)]
-#[darling(forward_attrs(serde))]
+#[darling(forward_attrs(serde, navigable))]
 struct NavigableType {
     ident: Ident,
+    generics: Generics,
     data: ast::Data<NavigableEnumVariant, NavigableStructField>,
     attrs: Vec<syn::Attribute>,
 }
@@ -46,20 +48,61 @@ struct NavigableEnumVariant {
     attrs: Vec<syn::Attribute>,
 }
 
+#[derive(FromAttributes, Debug)]
+#[darling(attributes(navigable))]
+struct NavigableAttrs {
+    /// Name the reflection fields of enum variants in snake_case instead of after the variant.
+    ///
+    /// This only affects the generated Rust identifiers, the keys in keypaths still follow serde.
+    snake_reflection: Option<bool>,
+
+    /// The path to pathogen in the generated code, e.g. `"::my_reexport"`, instead of `pathogen`
+    #[darling(rename = "crate")]
+    crate_path: Option<syn::Path>,
+}
+
+impl NavigableAttrs {
+    fn should_use_snake_reflection(&self) -> bool {
+        self.snake_reflection.unwrap_or(false)
+    }
+}
+
 impl NavigableEnumVariant {
     fn is_tuple_variant(&self) -> bool {
         self.fields.iter().any(|f| f.ident.is_none())
     }
+
+    /// The name of the field holding this variant's keypaths in the enum's reflection
+    fn reflection_field_name(&self, snake_case: bool) -> Ident {
+        if !snake_case {
+            return self.ident.clone();
+        }
+
+        let name = to_snake_case(&self.ident.to_string());
+
+        // e.g. `Type` becomes `r#type`
+        syn::parse_str(&name).unwrap_or_else(|_| Ident::new_raw(&name, self.ident.span()))
+    }
 }
 
 impl ToTokens for NavigableType {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let crate_path = NavigableAttrs::from_attributes(&self.attrs)
+            .ok()
+            .and_then(|attrs| attrs.crate_path);
+
+        super::with_crate_path(crate_path, || self.expand(tokens))
+    }
+}
+
+impl NavigableType {
+    fn expand(&self, tokens: &mut TokenStream) {
         if let Some(fields) = self.data.as_ref().take_struct() {
-            return Self::derive_struct(tokens, &self.ident, fields, &self.attrs);
+            return Self::derive_struct(tokens, &self.ident, &self.generics, fields, &self.attrs);
         }
 
         if let Some(variants) = self.data.as_ref().take_enum() {
-            return Self::derive_enum(tokens, &self.ident, variants, &self.attrs);
+            return Self::derive_enum(tokens, &self.ident, &self.generics, variants, &self.attrs);
         }
 
         abort_call_site!("derive(Navigable) only supports structs and enums with struct variants.");
@@ -70,9 +113,14 @@ impl NavigableType {
     fn derive_struct(
         tokens: &mut TokenStream,
         path_source: &Ident,
+        generics: &Generics,
         fields: Fields<&NavigableStructField>,
         attrs: &[syn::Attribute],
     ) {
+        if fields.is_tuple() {
+            return Self::derive_tuple_struct(tokens, path_source, generics, fields);
+        }
+
         let names_and_types: Vec<_> = fields
             .into_iter()
             .map(|f| {
@@ -86,14 +134,25 @@ impl NavigableType {
         let serde_attrs = ContainerSerdeAttrs::from_attributes(attrs);
 
         let reflection_type_name = Self::reflection_type_name(path_source);
+        let reflection_generics = Self::reflection_generics(generics, "Root");
+        let (reflection_impl_generics, reflection_ty_generics, where_clause) =
+            reflection_generics.split_for_impl();
+        let (impl_generics, ty_generics, _) = generics.split_for_impl();
         let (field_declarations, field_values) =
             Self::reflection_type_fields(&names_and_types, &serde_attrs);
+        let field_type_names = names_and_types.iter().map(|(ident, ty, attrs)| {
+            let field_attrs = ItemSerdeAtrs::from_attributes(attrs);
+            let field_str = field_name(ident, &serde_attrs, &field_attrs);
+            quote! {
+                #field_str => Some(::std::any::type_name::<#ty>())
+            }
+        });
 
         let crate_name = super::crate_name();
 
         tokens.extend(quote! {
-            impl #crate_name::Navigable for #path_source {
-                type Reflection<Root> = #reflection_type_name<Root>;
+            impl #impl_generics #crate_name::Navigable for #path_source #ty_generics #where_clause {
+                type Reflection<Root> = #reflection_type_name #reflection_ty_generics;
 
                 fn append_to_keypath<Root>(path: &#crate_name::KeyPath<Root, Self>) -> Self::Reflection<Root>
                 where
@@ -103,47 +162,163 @@ impl NavigableType {
                         #( #field_values ),*
                     }
                 }
+
+                fn field_type_name(key: &str) -> Option<&'static str> {
+                    match key {
+                        #( #field_type_names, )*
+                        _ => None,
+                    }
+                }
             }
         });
 
+        // The reflection mirrors the field names, which serde lets be other than snake_case
         tokens.extend(quote! {
-            pub struct #reflection_type_name<Root> {
+            #[allow(non_snake_case)]
+            pub struct #reflection_type_name #reflection_impl_generics #where_clause {
                 #(#field_declarations),*
             }
         });
+
+        let members: Vec<_> = names_and_types.iter().map(|(ident, _, _)| *ident).collect();
+        tokens.extend(Self::reflection_clone_and_debug(
+            &reflection_type_name,
+            &reflection_generics,
+            &members,
+            false,
+        ));
+    }
+
+    /// Tuple structs get a tuple struct reflection, with a keypath per position. The keys are the
+    /// positions as strings (e.g. `"0"`), like `KeyPathMutable` expects.
+    fn derive_tuple_struct(
+        tokens: &mut TokenStream,
+        path_source: &Ident,
+        generics: &Generics,
+        fields: Fields<&NavigableStructField>,
+    ) {
+        let crate_name = super::crate_name();
+        let reflection_type_name = Self::reflection_type_name(path_source);
+        let reflection_generics = Self::reflection_generics(generics, "Root");
+        let (reflection_impl_generics, reflection_ty_generics, where_clause) =
+            reflection_generics.split_for_impl();
+        let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+        let types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+        let keys: Vec<_> = (0..types.len()).map(|i| i.to_string()).collect();
+        let positions: Vec<_> = (0..types.len()).map(syn::Index::from).collect();
+        let debug_fields = positions
+            .iter()
+            .map(|position| quote!(.field(&self.#position)));
+        let type_str = reflection_type_name.to_string();
+
+        tokens.extend(quote! {
+            impl #impl_generics #crate_name::Navigable for #path_source #ty_generics #where_clause {
+                type Reflection<Root> = #reflection_type_name #reflection_ty_generics;
+
+                fn append_to_keypath<Root>(path: &#crate_name::KeyPath<Root, Self>) -> Self::Reflection<Root>
+                where
+                    Root: Sized,
+                {
+                    #reflection_type_name(
+                        #( path.appending(&#crate_name::KeyPath::field(#keys)) ),*
+                    )
+                }
+
+                fn field_type_name(key: &str) -> Option<&'static str> {
+                    match key {
+                        #( #keys => Some(::std::any::type_name::<#types>()), )*
+                        _ => None,
+                    }
+                }
+            }
+
+            pub struct #reflection_type_name #reflection_impl_generics (
+                #( pub #crate_name::KeyPath<Root, #types> ),*
+            ) #where_clause;
+
+            impl #reflection_impl_generics ::std::clone::Clone for #reflection_type_name #reflection_ty_generics #where_clause {
+                fn clone(&self) -> Self {
+                    Self( #( self.#positions.clone() ),* )
+                }
+            }
+
+            impl #reflection_impl_generics ::std::fmt::Debug for #reflection_type_name #reflection_ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.debug_tuple(#type_str)
+                        #( #debug_fields )*
+                        .finish()
+                }
+            }
+        });
     }
 
     fn derive_enum(
         tokens: &mut TokenStream,
         path_source: &Ident,
+        generics: &Generics,
         variants: Vec<&NavigableEnumVariant>,
         attrs: &[syn::Attribute],
     ) {
         let serde_attrs = ContainerSerdeAttrs::from_attributes(attrs);
+        let snake_reflection = NavigableAttrs::from_attributes(attrs)
+            .map(|it| it.should_use_snake_reflection())
+            .unwrap_or(false);
 
         let reflection_type_name = Self::reflection_type_name(path_source);
+        let reflection_generics = Self::reflection_generics(generics, "Root");
+        let (reflection_impl_generics, reflection_ty_generics, where_clause) =
+            reflection_generics.split_for_impl();
+        let (impl_generics, ty_generics, _) = generics.split_for_impl();
         let (field_declarations, field_values): (Vec<_>, Vec<_>) = variants
             .iter()
             .map(|v| {
                 (
-                    Self::derive_enum_variant_field_declaration(tokens, path_source, v),
-                    Self::derive_enum_variant_field_value(v, &serde_attrs),
+                    Self::derive_enum_variant_field_declaration(
+                        tokens,
+                        path_source,
+                        generics,
+                        v,
+                        snake_reflection,
+                    ),
+                    Self::derive_enum_variant_field_value(v, &serde_attrs, snake_reflection),
                 )
             })
             .unzip();
 
         let crate_name = super::crate_name();
+        let tag_type = Self::tag_type(&serde_attrs);
+        let variant_strs = variants.iter().map(|v| {
+            let variant_attrs = ItemSerdeAtrs::from_attributes(&v.attrs);
+            variant_name(&v.ident, &serde_attrs, &variant_attrs)
+        });
+        let allow_non_snake_case = if snake_reflection {
+            quote! {}
+        } else {
+            quote! { #[allow(non_snake_case)] }
+        };
 
         tokens.extend(quote! {
-            #[allow(non_snake_case)]
-            pub struct #reflection_type_name<Root> {
+            #allow_non_snake_case
+            pub struct #reflection_type_name #reflection_impl_generics #where_clause {
                 #(#field_declarations),*
             }
         });
 
+        let members: Vec<_> = variants
+            .iter()
+            .map(|v| v.reflection_field_name(snake_reflection))
+            .collect();
+        tokens.extend(Self::reflection_clone_and_debug(
+            &reflection_type_name,
+            &reflection_generics,
+            &members.iter().collect::<Vec<_>>(),
+            false,
+        ));
+
         tokens.extend(quote! {
-            impl #crate_name::Navigable for #path_source {
-                type Reflection<Root> = #reflection_type_name<Root>;
+            impl #impl_generics #crate_name::Navigable for #path_source #ty_generics #where_clause {
+                type Reflection<Root> = #reflection_type_name #reflection_ty_generics;
 
                 fn append_to_keypath<Root>(path: &#crate_name::KeyPath<Root, Self>) -> Self::Reflection<Root>
                 where
@@ -153,6 +328,17 @@ impl NavigableType {
                         #( #field_values ),*
                     }
                 }
+
+                fn variant_path(variant_key: &str) -> Option<#crate_name::KeyPathFrom<Self>> {
+                    let key = match variant_key {
+                        #( #variant_strs => #variant_strs, )*
+                        _ => return None,
+                    };
+
+                    Some(#crate_name::KeyPathFrom::dangerously_construct_from_path(vec![
+                        #crate_name::KeyPathElement::Variant { key: key.into(), tag: #tag_type },
+                    ]))
+                }
             }
         });
     }
@@ -160,12 +346,14 @@ impl NavigableType {
     fn derive_enum_variant_field_declaration(
         tokens: &mut TokenStream,
         type_name: &Ident,
+        generics: &Generics,
         variant: &NavigableEnumVariant,
+        snake_reflection: bool,
     ) -> TokenStream {
         if variant.is_tuple_variant() {
-            Self::derive_enum_tuple_variant(variant)
+            Self::derive_enum_tuple_variant(variant, snake_reflection)
         } else {
-            Self::derive_enum_struct_variant(tokens, type_name, variant)
+            Self::derive_enum_struct_variant(tokens, type_name, generics, variant, snake_reflection)
         }
     }
 
@@ -173,9 +361,12 @@ impl NavigableType {
     /// Return the derived type name as a TokenStream (to be compatible with 'derive_enum_struct_variant)
     ///
     /// fields are the unnamed fields of the tuple variant (e.g. `.0` and `.1` in `VariantOne(usize, String)`
-    fn derive_enum_tuple_variant(variant: &NavigableEnumVariant) -> TokenStream {
+    fn derive_enum_tuple_variant(
+        variant: &NavigableEnumVariant,
+        snake_reflection: bool,
+    ) -> TokenStream {
         let crate_name = super::crate_name();
-        let variant_name = &variant.ident;
+        let variant_name = variant.reflection_field_name(snake_reflection);
 
         let tuple_items = variant.fields.iter().map(|f| {
             let ty = &f.ty;
@@ -193,10 +384,15 @@ impl NavigableType {
     /// Return the derived type name as a TokenStream (to be compatible with 'derive_enum_tuple_variant)
     ///
     /// fields are the named fields of the struct variant (the "anonymous struct")
+    ///
+    /// A variant might not use all the generic parameters of the enum, so the reflection of a
+    /// generic enum's variant carries a marker for them.
     fn derive_enum_struct_variant(
         tokens: &mut TokenStream,
         type_name: &Ident,
+        generics: &Generics,
         variant: &NavigableEnumVariant,
+        snake_reflection: bool,
     ) -> TokenStream {
         let fields: Vec<_> = variant
             .fields
@@ -216,19 +412,43 @@ impl NavigableType {
         );
         let serde_attrs = ContainerSerdeAttrs::from_attributes(&variant.attrs);
 
-        let (field_declarations, field_values) =
+        let (mut field_declarations, mut field_values) =
             Self::reflection_type_fields(&fields, &serde_attrs);
         let crate_name = super::crate_name();
 
+        let marker = Self::generics_marker(generics);
+        if let Some(marker) = &marker {
+            field_declarations.push(quote! { _marker: #marker });
+            field_values.push(quote! { _marker: ::std::marker::PhantomData });
+        }
+
+        let reflection_generics = Self::reflection_generics(generics, "Root");
+        let (reflection_impl_generics, reflection_ty_generics, where_clause) =
+            reflection_generics.split_for_impl();
+        let previous_root_generics = Self::reflection_generics(generics, "PreviousRoot");
+        let (previous_root_impl_generics, previous_root_ty_generics, _) =
+            previous_root_generics.split_for_impl();
+
+        // Named after the variant and mirroring its field names, either of which may not follow
+        // the Rust conventions
         tokens.extend(quote! {
-            pub struct #reflection_type_name<Root> {
+            #[allow(non_snake_case, non_camel_case_types)]
+            pub struct #reflection_type_name #reflection_impl_generics #where_clause {
                 #(#field_declarations),*
             }
         });
 
+        let members: Vec<_> = fields.iter().map(|(ident, _, _)| *ident).collect();
+        tokens.extend(Self::reflection_clone_and_debug(
+            &reflection_type_name,
+            &reflection_generics,
+            &members,
+            marker.is_some(),
+        ));
+
         tokens.extend(quote! {
-            impl<T> #crate_name::Navigable for #reflection_type_name<T> {
-                type Reflection<Root> = #reflection_type_name<Root>;
+            impl #previous_root_impl_generics #crate_name::Navigable for #reflection_type_name #previous_root_ty_generics #where_clause {
+                type Reflection<Root> = #reflection_type_name #reflection_ty_generics;
 
                 fn append_to_keypath<Root>(path: &#crate_name::KeyPath<Root, Self>) -> Self::Reflection<Root>
                 where
@@ -241,27 +461,23 @@ impl NavigableType {
             }
         });
 
-        let variant_name = &variant.ident;
+        let variant_name = variant.reflection_field_name(snake_reflection);
         quote! {
-            pub #variant_name: #crate_name::KeyPath<Root, #reflection_type_name<Root>>
+            pub #variant_name: #crate_name::KeyPath<Root, #reflection_type_name #reflection_ty_generics>
         }
     }
 
     fn derive_enum_variant_field_value(
         variant: &NavigableEnumVariant,
         serde_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
+        snake_reflection: bool,
     ) -> TokenStream {
-        let variant_name = &variant.ident;
         let variant_attrs = ItemSerdeAtrs::from_attributes(&variant.attrs);
-        let variant_str = field_name(variant_name, serde_attrs, &variant_attrs);
+        let variant_str = variant_name(&variant.ident, serde_attrs, &variant_attrs);
+        let variant_name = variant.reflection_field_name(snake_reflection);
 
         let crate_name = super::crate_name();
-        let tag_type = match tag_type_from_serde_attrs(serde_attrs) {
-            VariantTagType::External => quote!(#crate_name::VariantTagType::External),
-            VariantTagType::Internal => quote!(#crate_name::VariantTagType::Internal),
-            VariantTagType::Adjacent => quote!(#crate_name::VariantTagType::Adjacent),
-            VariantTagType::Untagged => quote!(#crate_name::VariantTagType::Untagged),
-        };
+        let tag_type = Self::tag_type(serde_attrs);
 
         if variant.is_tuple_variant() {
             let variant_paths = variant.fields.iter().enumerate().map(|(field_index, _)| {
@@ -290,6 +506,17 @@ impl NavigableType {
         }
     }
 
+    fn tag_type(serde_attrs: &Result<ContainerSerdeAttrs, darling::Error>) -> TokenStream {
+        let crate_name = super::crate_name();
+
+        match tag_type_from_serde_attrs(serde_attrs) {
+            VariantTagType::External => quote!(#crate_name::VariantTagType::External),
+            VariantTagType::Internal => quote!(#crate_name::VariantTagType::Internal),
+            VariantTagType::Adjacent => quote!(#crate_name::VariantTagType::Adjacent),
+            VariantTagType::Untagged => quote!(#crate_name::VariantTagType::Untagged),
+        }
+    }
+
     fn reflection_type_fields(
         fields: &[(&Ident, &syn::Type, &[syn::Attribute])],
         serde_attrs: &Result<ContainerSerdeAttrs, darling::Error>,
@@ -318,6 +545,74 @@ impl NavigableType {
         (declarations, values)
     }
 
+    /// Implement `Clone` and `Debug` for a reflection type by hand, because deriving them would
+    /// require `Root` (and the field types) to implement them too
+    fn reflection_clone_and_debug(
+        reflection_type_name: &Ident,
+        reflection_generics: &Generics,
+        members: &[&Ident],
+        has_marker: bool,
+    ) -> TokenStream {
+        let type_str = reflection_type_name.to_string();
+        let member_strs = members.iter().map(|m| m.unraw().to_string());
+        let (impl_generics, ty_generics, where_clause) = reflection_generics.split_for_impl();
+        let marker = has_marker.then(|| quote! { _marker: ::std::marker::PhantomData });
+
+        quote! {
+            impl #impl_generics ::std::clone::Clone for #reflection_type_name #ty_generics #where_clause {
+                fn clone(&self) -> Self {
+                    Self {
+                        #( #members: self.#members.clone(), )*
+                        #marker
+                    }
+                }
+            }
+
+            impl #impl_generics ::std::fmt::Debug for #reflection_type_name #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.debug_struct(#type_str)
+                        #( .field(#member_strs, &self.#members) )*
+                        .finish()
+                }
+            }
+        }
+    }
+
+    /// The generics of a reflection type: those of the navigable type with the root type
+    /// parameter (named `root`) added, after any lifetimes
+    fn reflection_generics(generics: &Generics, root: &str) -> Generics {
+        let root = Ident::new(root, proc_macro2::Span::call_site());
+        let mut generics = generics.clone();
+        let position = generics.lifetimes().count();
+        generics.params.insert(position, parse_quote!(#root));
+
+        generics
+    }
+
+    /// A `PhantomData` using all the generic parameters of the navigable type, if it has any
+    fn generics_marker(generics: &Generics) -> Option<TokenStream> {
+        if generics.params.is_empty() {
+            return None;
+        }
+
+        let params = generics.params.iter().map(|param| match param {
+            GenericParam::Lifetime(lifetime) => {
+                let lifetime = &lifetime.lifetime;
+                quote!(&#lifetime ())
+            }
+            GenericParam::Type(ty) => {
+                let ident = &ty.ident;
+                quote!(#ident)
+            }
+            GenericParam::Const(constant) => {
+                let ident = &constant.ident;
+                quote!([(); #ident])
+            }
+        });
+
+        Some(quote!(::std::marker::PhantomData<fn() -> (#(#params,)*)>))
+    }
+
     fn reflection_type_name(path_source: &Ident) -> Ident {
         Ident::new(
             &format!("{}KeyPathReflection", path_source),
@@ -39,10 +39,27 @@ fn struct_with_one_field() {
                 a: path.appending(&pathogen::KeyPath::field("a")),
             }
         }
+        fn field_type_name(key: &str) -> Option<&'static str> {
+            match key {
+                "a" => Some(::std::any::type_name::<usize>()),
+                _ => None,
+            }
+        }
     }
+    #[allow(non_snake_case)]
     pub struct MyStructKeyPathReflection<Root> {
         pub a: pathogen::KeyPath<Root, usize>,
     }
+    impl<Root> ::std::clone::Clone for MyStructKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self { a: self.a.clone() }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyStructKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyStructKeyPathReflection").field("a", &self.a).finish()
+        }
+    }
     "###);
 }
 
@@ -77,12 +94,39 @@ fn struct_with_multiple_fields() {
                 my_structs: path.appending(&pathogen::KeyPath::field("my_structs")),
             }
         }
+        fn field_type_name(key: &str) -> Option<&'static str> {
+            match key {
+                "my_string" => Some(::std::any::type_name::<String>()),
+                "my_vector" => Some(::std::any::type_name::<Vec<usize>>()),
+                "my_structs" => Some(::std::any::type_name::<Vec<Nested>>()),
+                _ => None,
+            }
+        }
     }
+    #[allow(non_snake_case)]
     pub struct MyStructKeyPathReflection<Root> {
         pub my_string: pathogen::KeyPath<Root, String>,
         pub my_vector: pathogen::KeyPath<Root, Vec<usize>>,
         pub my_structs: pathogen::KeyPath<Root, Vec<Nested>>,
     }
+    impl<Root> ::std::clone::Clone for MyStructKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                my_string: self.my_string.clone(),
+                my_vector: self.my_vector.clone(),
+                my_structs: self.my_structs.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyStructKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyStructKeyPathReflection")
+                .field("my_string", &self.my_string)
+                .field("my_vector", &self.my_vector)
+                .field("my_structs", &self.my_structs)
+                .finish()
+        }
+    }
     "###);
 }
 
@@ -102,10 +146,24 @@ fn enum_with_struct_variants() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    #[allow(non_snake_case, non_camel_case_types)]
     pub struct MyEnumKeyPathReflectionVariantFirstOne<Root> {
         pub a: pathogen::KeyPath<Root, usize>,
     }
-    impl<T> pathogen::Navigable for MyEnumKeyPathReflectionVariantFirstOne<T> {
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflectionVariantFirstOne<Root> {
+        fn clone(&self) -> Self {
+            Self { a: self.a.clone() }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflectionVariantFirstOne<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflectionVariantFirstOne")
+                .field("a", &self.a)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for MyEnumKeyPathReflectionVariantFirstOne<PreviousRoot> {
         type Reflection<Root> = MyEnumKeyPathReflectionVariantFirstOne<Root>;
         fn append_to_keypath<Root>(
             path: &pathogen::KeyPath<Root, Self>,
@@ -118,11 +176,29 @@ fn enum_with_struct_variants() {
             }
         }
     }
+    #[allow(non_snake_case, non_camel_case_types)]
     pub struct MyEnumKeyPathReflectionVariantSecondOne<Root> {
         pub b: pathogen::KeyPath<Root, String>,
         pub c: pathogen::KeyPath<Root, f64>,
     }
-    impl<T> pathogen::Navigable for MyEnumKeyPathReflectionVariantSecondOne<T> {
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflectionVariantSecondOne<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                b: self.b.clone(),
+                c: self.c.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflectionVariantSecondOne<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflectionVariantSecondOne")
+                .field("b", &self.b)
+                .field("c", &self.c)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for MyEnumKeyPathReflectionVariantSecondOne<PreviousRoot> {
         type Reflection<Root> = MyEnumKeyPathReflectionVariantSecondOne<Root>;
         fn append_to_keypath<Root>(
             path: &pathogen::KeyPath<Root, Self>,
@@ -144,6 +220,22 @@ fn enum_with_struct_variants() {
             MyEnumKeyPathReflectionVariantSecondOne<Root>,
         >,
     }
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                FirstOne: self.FirstOne.clone(),
+                SecondOne: self.SecondOne.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflection")
+                .field("FirstOne", &self.FirstOne)
+                .field("SecondOne", &self.SecondOne)
+                .finish()
+        }
+    }
     impl pathogen::Navigable for MyEnum {
         type Reflection<Root> = MyEnumKeyPathReflection<Root>;
         fn append_to_keypath<Root>(
@@ -169,6 +261,21 @@ fn enum_with_struct_variants() {
                     ),
             }
         }
+        fn variant_path(variant_key: &str) -> Option<pathogen::KeyPathFrom<Self>> {
+            let key = match variant_key {
+                "FirstOne" => "FirstOne",
+                "SecondOne" => "SecondOne",
+                _ => return None,
+            };
+            Some(
+                pathogen::KeyPathFrom::dangerously_construct_from_path(
+                    vec![
+                        pathogen::KeyPathElement::Variant { key : key.into(), tag :
+                        pathogen::VariantTagType::External },
+                    ],
+                ),
+            )
+        }
     }
     "###);
 }
@@ -194,6 +301,22 @@ fn enum_with_tuple_variants() {
         pub VariantOne: (pathogen::KeyPath<Root, usize>,),
         pub VariantTwo: (pathogen::KeyPath<Root, Nested>, pathogen::KeyPath<Root, String>),
     }
+    impl<Root> ::std::clone::Clone for TestTupleEnumKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                VariantOne: self.VariantOne.clone(),
+                VariantTwo: self.VariantTwo.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for TestTupleEnumKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("TestTupleEnumKeyPathReflection")
+                .field("VariantOne", &self.VariantOne)
+                .field("VariantTwo", &self.VariantTwo)
+                .finish()
+        }
+    }
     impl pathogen::Navigable for TestTupleEnum {
         type Reflection<Root> = TestTupleEnumKeyPathReflection<Root>;
         fn append_to_keypath<Root>(
@@ -233,6 +356,155 @@ fn enum_with_tuple_variants() {
                 ),
             }
         }
+        fn variant_path(variant_key: &str) -> Option<pathogen::KeyPathFrom<Self>> {
+            let key = match variant_key {
+                "VariantOne" => "VariantOne",
+                "VariantTwo" => "VariantTwo",
+                _ => return None,
+            };
+            Some(
+                pathogen::KeyPathFrom::dangerously_construct_from_path(
+                    vec![
+                        pathogen::KeyPathElement::Variant { key : key.into(), tag :
+                        pathogen::VariantTagType::External },
+                    ],
+                ),
+            )
+        }
+    }
+    "###);
+}
+
+#[test]
+fn enum_with_snake_case_reflection() {
+    let input = r#"
+            #[derive(Navigable)]
+            #[navigable(snake_reflection)]
+            enum TestEnum {
+                VariantOne(usize),
+                VariantTwo { nested: Nested },
+                Type(String),
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = NavigableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    #[allow(non_snake_case, non_camel_case_types)]
+    pub struct TestEnumKeyPathReflectionVariantVariantTwo<Root> {
+        pub nested: pathogen::KeyPath<Root, Nested>,
+    }
+    impl<Root> ::std::clone::Clone for TestEnumKeyPathReflectionVariantVariantTwo<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                nested: self.nested.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for TestEnumKeyPathReflectionVariantVariantTwo<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("TestEnumKeyPathReflectionVariantVariantTwo")
+                .field("nested", &self.nested)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for TestEnumKeyPathReflectionVariantVariantTwo<PreviousRoot> {
+        type Reflection<Root> = TestEnumKeyPathReflectionVariantVariantTwo<Root>;
+        fn append_to_keypath<Root>(
+            path: &pathogen::KeyPath<Root, Self>,
+        ) -> Self::Reflection<Root>
+        where
+            Root: Sized,
+        {
+            TestEnumKeyPathReflectionVariantVariantTwo {
+                nested: path.appending(&pathogen::KeyPath::field("nested")),
+            }
+        }
+    }
+    pub struct TestEnumKeyPathReflection<Root> {
+        pub variant_one: (pathogen::KeyPath<Root, usize>,),
+        pub variant_two: pathogen::KeyPath<
+            Root,
+            TestEnumKeyPathReflectionVariantVariantTwo<Root>,
+        >,
+        pub r#type: (pathogen::KeyPath<Root, String>,),
+    }
+    impl<Root> ::std::clone::Clone for TestEnumKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                variant_one: self.variant_one.clone(),
+                variant_two: self.variant_two.clone(),
+                r#type: self.r#type.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for TestEnumKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("TestEnumKeyPathReflection")
+                .field("variant_one", &self.variant_one)
+                .field("variant_two", &self.variant_two)
+                .field("type", &self.r#type)
+                .finish()
+        }
+    }
+    impl pathogen::Navigable for TestEnum {
+        type Reflection<Root> = TestEnumKeyPathReflection<Root>;
+        fn append_to_keypath<Root>(
+            path: &pathogen::KeyPath<Root, Self>,
+        ) -> Self::Reflection<Root>
+        where
+            Root: Sized,
+        {
+            TestEnumKeyPathReflection {
+                variant_one: (
+                    path
+                        .appending(
+                            &pathogen::KeyPath::tuple_variant(
+                                "VariantOne",
+                                "0",
+                                pathogen::VariantTagType::External,
+                            ),
+                        ),
+                ),
+                variant_two: path
+                    .appending(
+                        &pathogen::KeyPath::variant(
+                            "VariantTwo",
+                            pathogen::VariantTagType::External,
+                        ),
+                    ),
+                r#type: (
+                    path
+                        .appending(
+                            &pathogen::KeyPath::tuple_variant(
+                                "Type",
+                                "0",
+                                pathogen::VariantTagType::External,
+                            ),
+                        ),
+                ),
+            }
+        }
+        fn variant_path(variant_key: &str) -> Option<pathogen::KeyPathFrom<Self>> {
+            let key = match variant_key {
+                "VariantOne" => "VariantOne",
+                "VariantTwo" => "VariantTwo",
+                "Type" => "Type",
+                _ => return None,
+            };
+            Some(
+                pathogen::KeyPathFrom::dangerously_construct_from_path(
+                    vec![
+                        pathogen::KeyPathElement::Variant { key : key.into(), tag :
+                        pathogen::VariantTagType::External },
+                    ],
+                ),
+            )
+        }
     }
     "###);
 }
@@ -265,10 +537,31 @@ fn struct_with_serde_rename() {
                 my_string: path.appending(&pathogen::KeyPath::field("bob")),
             }
         }
+        fn field_type_name(key: &str) -> Option<&'static str> {
+            match key {
+                "bob" => Some(::std::any::type_name::<String>()),
+                _ => None,
+            }
+        }
     }
+    #[allow(non_snake_case)]
     pub struct MyStructKeyPathReflection<Root> {
         pub my_string: pathogen::KeyPath<Root, String>,
     }
+    impl<Root> ::std::clone::Clone for MyStructKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                my_string: self.my_string.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyStructKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyStructKeyPathReflection")
+                .field("my_string", &self.my_string)
+                .finish()
+        }
+    }
     "###);
 }
 
@@ -300,10 +593,31 @@ fn struct_with_serde_rename_and_default() {
                 my_string: path.appending(&pathogen::KeyPath::field("bob")),
             }
         }
+        fn field_type_name(key: &str) -> Option<&'static str> {
+            match key {
+                "bob" => Some(::std::any::type_name::<String>()),
+                _ => None,
+            }
+        }
     }
+    #[allow(non_snake_case)]
     pub struct MyStructKeyPathReflection<Root> {
         pub my_string: pathogen::KeyPath<Root, String>,
     }
+    impl<Root> ::std::clone::Clone for MyStructKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                my_string: self.my_string.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyStructKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyStructKeyPathReflection")
+                .field("my_string", &self.my_string)
+                .finish()
+        }
+    }
     "###);
 }
 
@@ -325,10 +639,24 @@ fn enum_with_serde_rename() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    #[allow(non_snake_case, non_camel_case_types)]
     pub struct MyEnumKeyPathReflectionVariantFirstOne<Root> {
         pub a: pathogen::KeyPath<Root, usize>,
     }
-    impl<T> pathogen::Navigable for MyEnumKeyPathReflectionVariantFirstOne<T> {
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflectionVariantFirstOne<Root> {
+        fn clone(&self) -> Self {
+            Self { a: self.a.clone() }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflectionVariantFirstOne<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflectionVariantFirstOne")
+                .field("a", &self.a)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for MyEnumKeyPathReflectionVariantFirstOne<PreviousRoot> {
         type Reflection<Root> = MyEnumKeyPathReflectionVariantFirstOne<Root>;
         fn append_to_keypath<Root>(
             path: &pathogen::KeyPath<Root, Self>,
@@ -341,11 +669,29 @@ fn enum_with_serde_rename() {
             }
         }
     }
+    #[allow(non_snake_case, non_camel_case_types)]
     pub struct MyEnumKeyPathReflectionVariantSecondOne<Root> {
         pub b: pathogen::KeyPath<Root, String>,
         pub c: pathogen::KeyPath<Root, f64>,
     }
-    impl<T> pathogen::Navigable for MyEnumKeyPathReflectionVariantSecondOne<T> {
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflectionVariantSecondOne<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                b: self.b.clone(),
+                c: self.c.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflectionVariantSecondOne<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflectionVariantSecondOne")
+                .field("b", &self.b)
+                .field("c", &self.c)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for MyEnumKeyPathReflectionVariantSecondOne<PreviousRoot> {
         type Reflection<Root> = MyEnumKeyPathReflectionVariantSecondOne<Root>;
         fn append_to_keypath<Root>(
             path: &pathogen::KeyPath<Root, Self>,
@@ -367,6 +713,22 @@ fn enum_with_serde_rename() {
             MyEnumKeyPathReflectionVariantSecondOne<Root>,
         >,
     }
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                FirstOne: self.FirstOne.clone(),
+                SecondOne: self.SecondOne.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflection")
+                .field("FirstOne", &self.FirstOne)
+                .field("SecondOne", &self.SecondOne)
+                .finish()
+        }
+    }
     impl pathogen::Navigable for MyEnum {
         type Reflection<Root> = MyEnumKeyPathReflection<Root>;
         fn append_to_keypath<Root>(
@@ -392,6 +754,21 @@ fn enum_with_serde_rename() {
                     ),
             }
         }
+        fn variant_path(variant_key: &str) -> Option<pathogen::KeyPathFrom<Self>> {
+            let key = match variant_key {
+                "first" => "first",
+                "second" => "second",
+                _ => return None,
+            };
+            Some(
+                pathogen::KeyPathFrom::dangerously_construct_from_path(
+                    vec![
+                        pathogen::KeyPathElement::Variant { key : key.into(), tag :
+                        pathogen::VariantTagType::External },
+                    ],
+                ),
+            )
+        }
     }
     "###);
 }
@@ -423,10 +800,31 @@ fn struct_with_serde_rename_all() {
                 my_string: path.appending(&pathogen::KeyPath::field("myString")),
             }
         }
+        fn field_type_name(key: &str) -> Option<&'static str> {
+            match key {
+                "myString" => Some(::std::any::type_name::<String>()),
+                _ => None,
+            }
+        }
     }
+    #[allow(non_snake_case)]
     pub struct MyStructKeyPathReflection<Root> {
         pub my_string: pathogen::KeyPath<Root, String>,
     }
+    impl<Root> ::std::clone::Clone for MyStructKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                my_string: self.my_string.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyStructKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyStructKeyPathReflection")
+                .field("my_string", &self.my_string)
+                .finish()
+        }
+    }
     "###);
 }
 
@@ -447,10 +845,24 @@ fn enum_with_serde_rename_all() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    #[allow(non_snake_case, non_camel_case_types)]
     pub struct MyEnumKeyPathReflectionVariantFirstOne<Root> {
         pub a: pathogen::KeyPath<Root, usize>,
     }
-    impl<T> pathogen::Navigable for MyEnumKeyPathReflectionVariantFirstOne<T> {
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflectionVariantFirstOne<Root> {
+        fn clone(&self) -> Self {
+            Self { a: self.a.clone() }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflectionVariantFirstOne<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflectionVariantFirstOne")
+                .field("a", &self.a)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for MyEnumKeyPathReflectionVariantFirstOne<PreviousRoot> {
         type Reflection<Root> = MyEnumKeyPathReflectionVariantFirstOne<Root>;
         fn append_to_keypath<Root>(
             path: &pathogen::KeyPath<Root, Self>,
@@ -463,11 +875,29 @@ fn enum_with_serde_rename_all() {
             }
         }
     }
+    #[allow(non_snake_case, non_camel_case_types)]
     pub struct MyEnumKeyPathReflectionVariantSecondOne<Root> {
         pub b: pathogen::KeyPath<Root, String>,
         pub c: pathogen::KeyPath<Root, f64>,
     }
-    impl<T> pathogen::Navigable for MyEnumKeyPathReflectionVariantSecondOne<T> {
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflectionVariantSecondOne<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                b: self.b.clone(),
+                c: self.c.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflectionVariantSecondOne<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflectionVariantSecondOne")
+                .field("b", &self.b)
+                .field("c", &self.c)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for MyEnumKeyPathReflectionVariantSecondOne<PreviousRoot> {
         type Reflection<Root> = MyEnumKeyPathReflectionVariantSecondOne<Root>;
         fn append_to_keypath<Root>(
             path: &pathogen::KeyPath<Root, Self>,
@@ -489,6 +919,22 @@ fn enum_with_serde_rename_all() {
             MyEnumKeyPathReflectionVariantSecondOne<Root>,
         >,
     }
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                FirstOne: self.FirstOne.clone(),
+                SecondOne: self.SecondOne.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflection")
+                .field("FirstOne", &self.FirstOne)
+                .field("SecondOne", &self.SecondOne)
+                .finish()
+        }
+    }
     impl pathogen::Navigable for MyEnum {
         type Reflection<Root> = MyEnumKeyPathReflection<Root>;
         fn append_to_keypath<Root>(
@@ -514,6 +960,21 @@ fn enum_with_serde_rename_all() {
                     ),
             }
         }
+        fn variant_path(variant_key: &str) -> Option<pathogen::KeyPathFrom<Self>> {
+            let key = match variant_key {
+                "firstOne" => "firstOne",
+                "secondOne" => "secondOne",
+                _ => return None,
+            };
+            Some(
+                pathogen::KeyPathFrom::dangerously_construct_from_path(
+                    vec![
+                        pathogen::KeyPathElement::Variant { key : key.into(), tag :
+                        pathogen::VariantTagType::External },
+                    ],
+                ),
+            )
+        }
     }
     "###);
 }
@@ -534,10 +995,24 @@ fn externally_tagged_enum() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    #[allow(non_snake_case, non_camel_case_types)]
     pub struct MyEnumKeyPathReflectionVariantFirstOne<Root> {
         pub a: pathogen::KeyPath<Root, usize>,
     }
-    impl<T> pathogen::Navigable for MyEnumKeyPathReflectionVariantFirstOne<T> {
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflectionVariantFirstOne<Root> {
+        fn clone(&self) -> Self {
+            Self { a: self.a.clone() }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflectionVariantFirstOne<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflectionVariantFirstOne")
+                .field("a", &self.a)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for MyEnumKeyPathReflectionVariantFirstOne<PreviousRoot> {
         type Reflection<Root> = MyEnumKeyPathReflectionVariantFirstOne<Root>;
         fn append_to_keypath<Root>(
             path: &pathogen::KeyPath<Root, Self>,
@@ -550,11 +1025,29 @@ fn externally_tagged_enum() {
             }
         }
     }
+    #[allow(non_snake_case, non_camel_case_types)]
     pub struct MyEnumKeyPathReflectionVariantSecondOne<Root> {
         pub b: pathogen::KeyPath<Root, String>,
         pub c: pathogen::KeyPath<Root, f64>,
     }
-    impl<T> pathogen::Navigable for MyEnumKeyPathReflectionVariantSecondOne<T> {
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflectionVariantSecondOne<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                b: self.b.clone(),
+                c: self.c.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflectionVariantSecondOne<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflectionVariantSecondOne")
+                .field("b", &self.b)
+                .field("c", &self.c)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for MyEnumKeyPathReflectionVariantSecondOne<PreviousRoot> {
         type Reflection<Root> = MyEnumKeyPathReflectionVariantSecondOne<Root>;
         fn append_to_keypath<Root>(
             path: &pathogen::KeyPath<Root, Self>,
@@ -576,6 +1069,22 @@ fn externally_tagged_enum() {
             MyEnumKeyPathReflectionVariantSecondOne<Root>,
         >,
     }
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                FirstOne: self.FirstOne.clone(),
+                SecondOne: self.SecondOne.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflection")
+                .field("FirstOne", &self.FirstOne)
+                .field("SecondOne", &self.SecondOne)
+                .finish()
+        }
+    }
     impl pathogen::Navigable for MyEnum {
         type Reflection<Root> = MyEnumKeyPathReflection<Root>;
         fn append_to_keypath<Root>(
@@ -601,6 +1110,21 @@ fn externally_tagged_enum() {
                     ),
             }
         }
+        fn variant_path(variant_key: &str) -> Option<pathogen::KeyPathFrom<Self>> {
+            let key = match variant_key {
+                "FirstOne" => "FirstOne",
+                "SecondOne" => "SecondOne",
+                _ => return None,
+            };
+            Some(
+                pathogen::KeyPathFrom::dangerously_construct_from_path(
+                    vec![
+                        pathogen::KeyPathElement::Variant { key : key.into(), tag :
+                        pathogen::VariantTagType::External },
+                    ],
+                ),
+            )
+        }
     }
     "###);
 }
@@ -622,10 +1146,24 @@ fn internally_tagged_enum() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    #[allow(non_snake_case, non_camel_case_types)]
     pub struct MyEnumKeyPathReflectionVariantFirstOne<Root> {
         pub a: pathogen::KeyPath<Root, usize>,
     }
-    impl<T> pathogen::Navigable for MyEnumKeyPathReflectionVariantFirstOne<T> {
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflectionVariantFirstOne<Root> {
+        fn clone(&self) -> Self {
+            Self { a: self.a.clone() }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflectionVariantFirstOne<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflectionVariantFirstOne")
+                .field("a", &self.a)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for MyEnumKeyPathReflectionVariantFirstOne<PreviousRoot> {
         type Reflection<Root> = MyEnumKeyPathReflectionVariantFirstOne<Root>;
         fn append_to_keypath<Root>(
             path: &pathogen::KeyPath<Root, Self>,
@@ -638,11 +1176,29 @@ fn internally_tagged_enum() {
             }
         }
     }
+    #[allow(non_snake_case, non_camel_case_types)]
     pub struct MyEnumKeyPathReflectionVariantSecondOne<Root> {
         pub b: pathogen::KeyPath<Root, String>,
         pub c: pathogen::KeyPath<Root, f64>,
     }
-    impl<T> pathogen::Navigable for MyEnumKeyPathReflectionVariantSecondOne<T> {
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflectionVariantSecondOne<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                b: self.b.clone(),
+                c: self.c.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflectionVariantSecondOne<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflectionVariantSecondOne")
+                .field("b", &self.b)
+                .field("c", &self.c)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for MyEnumKeyPathReflectionVariantSecondOne<PreviousRoot> {
         type Reflection<Root> = MyEnumKeyPathReflectionVariantSecondOne<Root>;
         fn append_to_keypath<Root>(
             path: &pathogen::KeyPath<Root, Self>,
@@ -664,6 +1220,22 @@ fn internally_tagged_enum() {
             MyEnumKeyPathReflectionVariantSecondOne<Root>,
         >,
     }
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                FirstOne: self.FirstOne.clone(),
+                SecondOne: self.SecondOne.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflection")
+                .field("FirstOne", &self.FirstOne)
+                .field("SecondOne", &self.SecondOne)
+                .finish()
+        }
+    }
     impl pathogen::Navigable for MyEnum {
         type Reflection<Root> = MyEnumKeyPathReflection<Root>;
         fn append_to_keypath<Root>(
@@ -689,6 +1261,21 @@ fn internally_tagged_enum() {
                     ),
             }
         }
+        fn variant_path(variant_key: &str) -> Option<pathogen::KeyPathFrom<Self>> {
+            let key = match variant_key {
+                "FirstOne" => "FirstOne",
+                "SecondOne" => "SecondOne",
+                _ => return None,
+            };
+            Some(
+                pathogen::KeyPathFrom::dangerously_construct_from_path(
+                    vec![
+                        pathogen::KeyPathElement::Variant { key : key.into(), tag :
+                        pathogen::VariantTagType::Internal },
+                    ],
+                ),
+            )
+        }
     }
     "###);
 }
@@ -710,10 +1297,24 @@ fn adjacently_tagged_enum() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    #[allow(non_snake_case, non_camel_case_types)]
     pub struct MyEnumKeyPathReflectionVariantFirstOne<Root> {
         pub a: pathogen::KeyPath<Root, usize>,
     }
-    impl<T> pathogen::Navigable for MyEnumKeyPathReflectionVariantFirstOne<T> {
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflectionVariantFirstOne<Root> {
+        fn clone(&self) -> Self {
+            Self { a: self.a.clone() }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflectionVariantFirstOne<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflectionVariantFirstOne")
+                .field("a", &self.a)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for MyEnumKeyPathReflectionVariantFirstOne<PreviousRoot> {
         type Reflection<Root> = MyEnumKeyPathReflectionVariantFirstOne<Root>;
         fn append_to_keypath<Root>(
             path: &pathogen::KeyPath<Root, Self>,
@@ -726,11 +1327,29 @@ fn adjacently_tagged_enum() {
             }
         }
     }
+    #[allow(non_snake_case, non_camel_case_types)]
     pub struct MyEnumKeyPathReflectionVariantSecondOne<Root> {
         pub b: pathogen::KeyPath<Root, String>,
         pub c: pathogen::KeyPath<Root, f64>,
     }
-    impl<T> pathogen::Navigable for MyEnumKeyPathReflectionVariantSecondOne<T> {
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflectionVariantSecondOne<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                b: self.b.clone(),
+                c: self.c.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflectionVariantSecondOne<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflectionVariantSecondOne")
+                .field("b", &self.b)
+                .field("c", &self.c)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for MyEnumKeyPathReflectionVariantSecondOne<PreviousRoot> {
         type Reflection<Root> = MyEnumKeyPathReflectionVariantSecondOne<Root>;
         fn append_to_keypath<Root>(
             path: &pathogen::KeyPath<Root, Self>,
@@ -752,6 +1371,22 @@ fn adjacently_tagged_enum() {
             MyEnumKeyPathReflectionVariantSecondOne<Root>,
         >,
     }
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                FirstOne: self.FirstOne.clone(),
+                SecondOne: self.SecondOne.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflection")
+                .field("FirstOne", &self.FirstOne)
+                .field("SecondOne", &self.SecondOne)
+                .finish()
+        }
+    }
     impl pathogen::Navigable for MyEnum {
         type Reflection<Root> = MyEnumKeyPathReflection<Root>;
         fn append_to_keypath<Root>(
@@ -777,6 +1412,21 @@ fn adjacently_tagged_enum() {
                     ),
             }
         }
+        fn variant_path(variant_key: &str) -> Option<pathogen::KeyPathFrom<Self>> {
+            let key = match variant_key {
+                "FirstOne" => "FirstOne",
+                "SecondOne" => "SecondOne",
+                _ => return None,
+            };
+            Some(
+                pathogen::KeyPathFrom::dangerously_construct_from_path(
+                    vec![
+                        pathogen::KeyPathElement::Variant { key : key.into(), tag :
+                        pathogen::VariantTagType::Adjacent },
+                    ],
+                ),
+            )
+        }
     }
     "###);
 }
@@ -798,10 +1448,24 @@ fn untagged_enum() {
     let actual = quote!(#input);
 
     insta::assert_snapshot!(pretty_print(&actual), @r###"
+    #[allow(non_snake_case, non_camel_case_types)]
     pub struct MyEnumKeyPathReflectionVariantFirstOne<Root> {
         pub a: pathogen::KeyPath<Root, usize>,
     }
-    impl<T> pathogen::Navigable for MyEnumKeyPathReflectionVariantFirstOne<T> {
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflectionVariantFirstOne<Root> {
+        fn clone(&self) -> Self {
+            Self { a: self.a.clone() }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflectionVariantFirstOne<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflectionVariantFirstOne")
+                .field("a", &self.a)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for MyEnumKeyPathReflectionVariantFirstOne<PreviousRoot> {
         type Reflection<Root> = MyEnumKeyPathReflectionVariantFirstOne<Root>;
         fn append_to_keypath<Root>(
             path: &pathogen::KeyPath<Root, Self>,
@@ -814,11 +1478,29 @@ fn untagged_enum() {
             }
         }
     }
+    #[allow(non_snake_case, non_camel_case_types)]
     pub struct MyEnumKeyPathReflectionVariantSecondOne<Root> {
         pub b: pathogen::KeyPath<Root, String>,
         pub c: pathogen::KeyPath<Root, f64>,
     }
-    impl<T> pathogen::Navigable for MyEnumKeyPathReflectionVariantSecondOne<T> {
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflectionVariantSecondOne<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                b: self.b.clone(),
+                c: self.c.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflectionVariantSecondOne<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflectionVariantSecondOne")
+                .field("b", &self.b)
+                .field("c", &self.c)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for MyEnumKeyPathReflectionVariantSecondOne<PreviousRoot> {
         type Reflection<Root> = MyEnumKeyPathReflectionVariantSecondOne<Root>;
         fn append_to_keypath<Root>(
             path: &pathogen::KeyPath<Root, Self>,
@@ -840,6 +1522,22 @@ fn untagged_enum() {
             MyEnumKeyPathReflectionVariantSecondOne<Root>,
         >,
     }
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                FirstOne: self.FirstOne.clone(),
+                SecondOne: self.SecondOne.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflection")
+                .field("FirstOne", &self.FirstOne)
+                .field("SecondOne", &self.SecondOne)
+                .finish()
+        }
+    }
     impl pathogen::Navigable for MyEnum {
         type Reflection<Root> = MyEnumKeyPathReflection<Root>;
         fn append_to_keypath<Root>(
@@ -865,6 +1563,685 @@ fn untagged_enum() {
                     ),
             }
         }
+        fn variant_path(variant_key: &str) -> Option<pathogen::KeyPathFrom<Self>> {
+            let key = match variant_key {
+                "FirstOne" => "FirstOne",
+                "SecondOne" => "SecondOne",
+                _ => return None,
+            };
+            Some(
+                pathogen::KeyPathFrom::dangerously_construct_from_path(
+                    vec![
+                        pathogen::KeyPathElement::Variant { key : key.into(), tag :
+                        pathogen::VariantTagType::Untagged },
+                    ],
+                ),
+            )
+        }
+    }
+    "###);
+}
+
+#[test]
+fn struct_with_serde_rename_all_lowercase() {
+    let input = r#"
+            #[derive(Navigable)]
+            #[serde(rename_all = "lowercase")]
+            struct MyStruct {
+                my_string: String,
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = NavigableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::Navigable for MyStruct {
+        type Reflection<Root> = MyStructKeyPathReflection<Root>;
+        fn append_to_keypath<Root>(
+            path: &pathogen::KeyPath<Root, Self>,
+        ) -> Self::Reflection<Root>
+        where
+            Root: Sized,
+        {
+            MyStructKeyPathReflection {
+                my_string: path.appending(&pathogen::KeyPath::field("my_string")),
+            }
+        }
+        fn field_type_name(key: &str) -> Option<&'static str> {
+            match key {
+                "my_string" => Some(::std::any::type_name::<String>()),
+                _ => None,
+            }
+        }
+    }
+    #[allow(non_snake_case)]
+    pub struct MyStructKeyPathReflection<Root> {
+        pub my_string: pathogen::KeyPath<Root, String>,
+    }
+    impl<Root> ::std::clone::Clone for MyStructKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                my_string: self.my_string.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyStructKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyStructKeyPathReflection")
+                .field("my_string", &self.my_string)
+                .finish()
+        }
+    }
+    "###);
+}
+
+#[test]
+fn enum_with_serde_rename_all_uppercase() {
+    let input = r#"
+            #[derive(Navigable)]
+            #[serde(rename_all = "UPPERCASE")]
+            enum MyEnum {
+                VariantOne { my_field: usize },
+                VariantTwo(String),
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = NavigableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    #[allow(non_snake_case, non_camel_case_types)]
+    pub struct MyEnumKeyPathReflectionVariantVariantOne<Root> {
+        pub my_field: pathogen::KeyPath<Root, usize>,
+    }
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflectionVariantVariantOne<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                my_field: self.my_field.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflectionVariantVariantOne<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflectionVariantVariantOne")
+                .field("my_field", &self.my_field)
+                .finish()
+        }
+    }
+    impl<PreviousRoot> pathogen::Navigable
+    for MyEnumKeyPathReflectionVariantVariantOne<PreviousRoot> {
+        type Reflection<Root> = MyEnumKeyPathReflectionVariantVariantOne<Root>;
+        fn append_to_keypath<Root>(
+            path: &pathogen::KeyPath<Root, Self>,
+        ) -> Self::Reflection<Root>
+        where
+            Root: Sized,
+        {
+            MyEnumKeyPathReflectionVariantVariantOne {
+                my_field: path.appending(&pathogen::KeyPath::field("my_field")),
+            }
+        }
+    }
+    #[allow(non_snake_case)]
+    pub struct MyEnumKeyPathReflection<Root> {
+        pub VariantOne: pathogen::KeyPath<
+            Root,
+            MyEnumKeyPathReflectionVariantVariantOne<Root>,
+        >,
+        pub VariantTwo: (pathogen::KeyPath<Root, String>,),
+    }
+    impl<Root> ::std::clone::Clone for MyEnumKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                VariantOne: self.VariantOne.clone(),
+                VariantTwo: self.VariantTwo.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyEnumKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyEnumKeyPathReflection")
+                .field("VariantOne", &self.VariantOne)
+                .field("VariantTwo", &self.VariantTwo)
+                .finish()
+        }
+    }
+    impl pathogen::Navigable for MyEnum {
+        type Reflection<Root> = MyEnumKeyPathReflection<Root>;
+        fn append_to_keypath<Root>(
+            path: &pathogen::KeyPath<Root, Self>,
+        ) -> Self::Reflection<Root>
+        where
+            Root: Sized,
+        {
+            MyEnumKeyPathReflection {
+                VariantOne: path
+                    .appending(
+                        &pathogen::KeyPath::variant(
+                            "VARIANTONE",
+                            pathogen::VariantTagType::External,
+                        ),
+                    ),
+                VariantTwo: (
+                    path
+                        .appending(
+                            &pathogen::KeyPath::tuple_variant(
+                                "VARIANTTWO",
+                                "0",
+                                pathogen::VariantTagType::External,
+                            ),
+                        ),
+                ),
+            }
+        }
+        fn variant_path(variant_key: &str) -> Option<pathogen::KeyPathFrom<Self>> {
+            let key = match variant_key {
+                "VARIANTONE" => "VARIANTONE",
+                "VARIANTTWO" => "VARIANTTWO",
+                _ => return None,
+            };
+            Some(
+                pathogen::KeyPathFrom::dangerously_construct_from_path(
+                    vec![
+                        pathogen::KeyPathElement::Variant { key : key.into(), tag :
+                        pathogen::VariantTagType::External },
+                    ],
+                ),
+            )
+        }
+    }
+    "###);
+}
+
+#[test]
+fn struct_with_raw_identifier_field() {
+    let input = r#"
+            #[derive(Navigable)]
+            struct MyStruct {
+                r#type: String,
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = NavigableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::Navigable for MyStruct {
+        type Reflection<Root> = MyStructKeyPathReflection<Root>;
+        fn append_to_keypath<Root>(
+            path: &pathogen::KeyPath<Root, Self>,
+        ) -> Self::Reflection<Root>
+        where
+            Root: Sized,
+        {
+            MyStructKeyPathReflection {
+                r#type: path.appending(&pathogen::KeyPath::field("type")),
+            }
+        }
+        fn field_type_name(key: &str) -> Option<&'static str> {
+            match key {
+                "type" => Some(::std::any::type_name::<String>()),
+                _ => None,
+            }
+        }
+    }
+    #[allow(non_snake_case)]
+    pub struct MyStructKeyPathReflection<Root> {
+        pub r#type: pathogen::KeyPath<Root, String>,
+    }
+    impl<Root> ::std::clone::Clone for MyStructKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self {
+                r#type: self.r#type.clone(),
+            }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyStructKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyStructKeyPathReflection").field("type", &self.r#type).finish()
+        }
+    }
+    "###);
+}
+
+#[test]
+fn newtype_struct() {
+    let input = r#"
+            #[derive(Navigable)]
+            struct Wrapper(Inner);
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = NavigableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::Navigable for Wrapper {
+        type Reflection<Root> = WrapperKeyPathReflection<Root>;
+        fn append_to_keypath<Root>(
+            path: &pathogen::KeyPath<Root, Self>,
+        ) -> Self::Reflection<Root>
+        where
+            Root: Sized,
+        {
+            WrapperKeyPathReflection(path.appending(&pathogen::KeyPath::field("0")))
+        }
+        fn field_type_name(key: &str) -> Option<&'static str> {
+            match key {
+                "0" => Some(::std::any::type_name::<Inner>()),
+                _ => None,
+            }
+        }
+    }
+    pub struct WrapperKeyPathReflection<Root>(pub pathogen::KeyPath<Root, Inner>);
+    impl<Root> ::std::clone::Clone for WrapperKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+    impl<Root> ::std::fmt::Debug for WrapperKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_tuple("WrapperKeyPathReflection").field(&self.0).finish()
+        }
+    }
+    "###);
+}
+
+#[test]
+fn tuple_struct() {
+    let input = r#"
+            #[derive(Navigable)]
+            struct Point(f64, f64, String);
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = NavigableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl pathogen::Navigable for Point {
+        type Reflection<Root> = PointKeyPathReflection<Root>;
+        fn append_to_keypath<Root>(
+            path: &pathogen::KeyPath<Root, Self>,
+        ) -> Self::Reflection<Root>
+        where
+            Root: Sized,
+        {
+            PointKeyPathReflection(
+                path.appending(&pathogen::KeyPath::field("0")),
+                path.appending(&pathogen::KeyPath::field("1")),
+                path.appending(&pathogen::KeyPath::field("2")),
+            )
+        }
+        fn field_type_name(key: &str) -> Option<&'static str> {
+            match key {
+                "0" => Some(::std::any::type_name::<f64>()),
+                "1" => Some(::std::any::type_name::<f64>()),
+                "2" => Some(::std::any::type_name::<String>()),
+                _ => None,
+            }
+        }
+    }
+    pub struct PointKeyPathReflection<Root>(
+        pub pathogen::KeyPath<Root, f64>,
+        pub pathogen::KeyPath<Root, f64>,
+        pub pathogen::KeyPath<Root, String>,
+    );
+    impl<Root> ::std::clone::Clone for PointKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), self.1.clone(), self.2.clone())
+        }
+    }
+    impl<Root> ::std::fmt::Debug for PointKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_tuple("PointKeyPathReflection")
+                .field(&self.0)
+                .field(&self.1)
+                .field(&self.2)
+                .finish()
+        }
+    }
+    "###);
+}
+
+#[test]
+fn struct_with_a_type_parameter() {
+    let input = r#"
+            #[derive(Navigable)]
+            struct Page<T> {
+                items: Vec<T>,
+                total: usize,
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = NavigableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl<T> pathogen::Navigable for Page<T> {
+        type Reflection<Root> = PageKeyPathReflection<Root, T>;
+        fn append_to_keypath<Root>(
+            path: &pathogen::KeyPath<Root, Self>,
+        ) -> Self::Reflection<Root>
+        where
+            Root: Sized,
+        {
+            PageKeyPathReflection {
+                items: path.appending(&pathogen::KeyPath::field("items")),
+                total: path.appending(&pathogen::KeyPath::field("total")),
+            }
+        }
+        fn field_type_name(key: &str) -> Option<&'static str> {
+            match key {
+                "items" => Some(::std::any::type_name::<Vec<T>>()),
+                "total" => Some(::std::any::type_name::<usize>()),
+                _ => None,
+            }
+        }
+    }
+    #[allow(non_snake_case)]
+    pub struct PageKeyPathReflection<Root, T> {
+        pub items: pathogen::KeyPath<Root, Vec<T>>,
+        pub total: pathogen::KeyPath<Root, usize>,
+    }
+    impl<Root, T> ::std::clone::Clone for PageKeyPathReflection<Root, T> {
+        fn clone(&self) -> Self {
+            Self {
+                items: self.items.clone(),
+                total: self.total.clone(),
+            }
+        }
+    }
+    impl<Root, T> ::std::fmt::Debug for PageKeyPathReflection<Root, T> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("PageKeyPathReflection")
+                .field("items", &self.items)
+                .field("total", &self.total)
+                .finish()
+        }
+    }
+    "###);
+}
+
+#[test]
+fn struct_with_a_where_clause() {
+    let input = r#"
+            #[derive(Navigable)]
+            struct Lookup<K, V>
+            where
+                K: Ord,
+            {
+                entries: BTreeMap<K, V>,
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = NavigableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl<K, V> pathogen::Navigable for Lookup<K, V>
+    where
+        K: Ord,
+    {
+        type Reflection<Root> = LookupKeyPathReflection<Root, K, V>;
+        fn append_to_keypath<Root>(
+            path: &pathogen::KeyPath<Root, Self>,
+        ) -> Self::Reflection<Root>
+        where
+            Root: Sized,
+        {
+            LookupKeyPathReflection {
+                entries: path.appending(&pathogen::KeyPath::field("entries")),
+            }
+        }
+        fn field_type_name(key: &str) -> Option<&'static str> {
+            match key {
+                "entries" => Some(::std::any::type_name::<BTreeMap<K, V>>()),
+                _ => None,
+            }
+        }
+    }
+    #[allow(non_snake_case)]
+    pub struct LookupKeyPathReflection<Root, K, V>
+    where
+        K: Ord,
+    {
+        pub entries: pathogen::KeyPath<Root, BTreeMap<K, V>>,
+    }
+    impl<Root, K, V> ::std::clone::Clone for LookupKeyPathReflection<Root, K, V>
+    where
+        K: Ord,
+    {
+        fn clone(&self) -> Self {
+            Self {
+                entries: self.entries.clone(),
+            }
+        }
+    }
+    impl<Root, K, V> ::std::fmt::Debug for LookupKeyPathReflection<Root, K, V>
+    where
+        K: Ord,
+    {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("LookupKeyPathReflection")
+                .field("entries", &self.entries)
+                .finish()
+        }
+    }
+    "###);
+}
+
+#[test]
+fn enum_with_a_type_parameter() {
+    let input = r#"
+            #[derive(Navigable)]
+            enum Outcome<T> {
+                Done { value: T },
+                Failed { reason: String },
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = NavigableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    #[allow(non_snake_case, non_camel_case_types)]
+    pub struct OutcomeKeyPathReflectionVariantDone<Root, T> {
+        pub value: pathogen::KeyPath<Root, T>,
+        _marker: ::std::marker::PhantomData<fn() -> (T,)>,
+    }
+    impl<Root, T> ::std::clone::Clone for OutcomeKeyPathReflectionVariantDone<Root, T> {
+        fn clone(&self) -> Self {
+            Self {
+                value: self.value.clone(),
+                _marker: ::std::marker::PhantomData,
+            }
+        }
+    }
+    impl<Root, T> ::std::fmt::Debug for OutcomeKeyPathReflectionVariantDone<Root, T> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("OutcomeKeyPathReflectionVariantDone")
+                .field("value", &self.value)
+                .finish()
+        }
+    }
+    impl<PreviousRoot, T> pathogen::Navigable
+    for OutcomeKeyPathReflectionVariantDone<PreviousRoot, T> {
+        type Reflection<Root> = OutcomeKeyPathReflectionVariantDone<Root, T>;
+        fn append_to_keypath<Root>(
+            path: &pathogen::KeyPath<Root, Self>,
+        ) -> Self::Reflection<Root>
+        where
+            Root: Sized,
+        {
+            OutcomeKeyPathReflectionVariantDone {
+                value: path.appending(&pathogen::KeyPath::field("value")),
+                _marker: ::std::marker::PhantomData,
+            }
+        }
+    }
+    #[allow(non_snake_case, non_camel_case_types)]
+    pub struct OutcomeKeyPathReflectionVariantFailed<Root, T> {
+        pub reason: pathogen::KeyPath<Root, String>,
+        _marker: ::std::marker::PhantomData<fn() -> (T,)>,
+    }
+    impl<Root, T> ::std::clone::Clone for OutcomeKeyPathReflectionVariantFailed<Root, T> {
+        fn clone(&self) -> Self {
+            Self {
+                reason: self.reason.clone(),
+                _marker: ::std::marker::PhantomData,
+            }
+        }
+    }
+    impl<Root, T> ::std::fmt::Debug for OutcomeKeyPathReflectionVariantFailed<Root, T> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("OutcomeKeyPathReflectionVariantFailed")
+                .field("reason", &self.reason)
+                .finish()
+        }
+    }
+    impl<PreviousRoot, T> pathogen::Navigable
+    for OutcomeKeyPathReflectionVariantFailed<PreviousRoot, T> {
+        type Reflection<Root> = OutcomeKeyPathReflectionVariantFailed<Root, T>;
+        fn append_to_keypath<Root>(
+            path: &pathogen::KeyPath<Root, Self>,
+        ) -> Self::Reflection<Root>
+        where
+            Root: Sized,
+        {
+            OutcomeKeyPathReflectionVariantFailed {
+                reason: path.appending(&pathogen::KeyPath::field("reason")),
+                _marker: ::std::marker::PhantomData,
+            }
+        }
+    }
+    #[allow(non_snake_case)]
+    pub struct OutcomeKeyPathReflection<Root, T> {
+        pub Done: pathogen::KeyPath<Root, OutcomeKeyPathReflectionVariantDone<Root, T>>,
+        pub Failed: pathogen::KeyPath<Root, OutcomeKeyPathReflectionVariantFailed<Root, T>>,
+    }
+    impl<Root, T> ::std::clone::Clone for OutcomeKeyPathReflection<Root, T> {
+        fn clone(&self) -> Self {
+            Self {
+                Done: self.Done.clone(),
+                Failed: self.Failed.clone(),
+            }
+        }
+    }
+    impl<Root, T> ::std::fmt::Debug for OutcomeKeyPathReflection<Root, T> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("OutcomeKeyPathReflection")
+                .field("Done", &self.Done)
+                .field("Failed", &self.Failed)
+                .finish()
+        }
+    }
+    impl<T> pathogen::Navigable for Outcome<T> {
+        type Reflection<Root> = OutcomeKeyPathReflection<Root, T>;
+        fn append_to_keypath<Root>(
+            path: &pathogen::KeyPath<Root, Self>,
+        ) -> Self::Reflection<Root>
+        where
+            Root: Sized,
+        {
+            OutcomeKeyPathReflection {
+                Done: path
+                    .appending(
+                        &pathogen::KeyPath::variant(
+                            "Done",
+                            pathogen::VariantTagType::External,
+                        ),
+                    ),
+                Failed: path
+                    .appending(
+                        &pathogen::KeyPath::variant(
+                            "Failed",
+                            pathogen::VariantTagType::External,
+                        ),
+                    ),
+            }
+        }
+        fn variant_path(variant_key: &str) -> Option<pathogen::KeyPathFrom<Self>> {
+            let key = match variant_key {
+                "Done" => "Done",
+                "Failed" => "Failed",
+                _ => return None,
+            };
+            Some(
+                pathogen::KeyPathFrom::dangerously_construct_from_path(
+                    vec![
+                        pathogen::KeyPathElement::Variant { key : key.into(), tag :
+                        pathogen::VariantTagType::External },
+                    ],
+                ),
+            )
+        }
+    }
+    "###);
+}
+
+#[test]
+fn struct_with_a_crate_path() {
+    let input = r#"
+            #[navigable(crate = "::my_reexport")]
+            struct MyStruct {
+                a: usize,
+            }
+        "#;
+
+    let input = parse_str(input).unwrap();
+    let input = NavigableType::from_derive_input(&input).unwrap();
+
+    let actual = quote!(#input);
+
+    insta::assert_snapshot!(pretty_print(&actual), @r###"
+    impl ::my_reexport::Navigable for MyStruct {
+        type Reflection<Root> = MyStructKeyPathReflection<Root>;
+        fn append_to_keypath<Root>(
+            path: &::my_reexport::KeyPath<Root, Self>,
+        ) -> Self::Reflection<Root>
+        where
+            Root: Sized,
+        {
+            MyStructKeyPathReflection {
+                a: path.appending(&::my_reexport::KeyPath::field("a")),
+            }
+        }
+        fn field_type_name(key: &str) -> Option<&'static str> {
+            match key {
+                "a" => Some(::std::any::type_name::<usize>()),
+                _ => None,
+            }
+        }
+    }
+    #[allow(non_snake_case)]
+    pub struct MyStructKeyPathReflection<Root> {
+        pub a: ::my_reexport::KeyPath<Root, usize>,
+    }
+    impl<Root> ::std::clone::Clone for MyStructKeyPathReflection<Root> {
+        fn clone(&self) -> Self {
+            Self { a: self.a.clone() }
+        }
+    }
+    impl<Root> ::std::fmt::Debug for MyStructKeyPathReflection<Root> {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            f.debug_struct("MyStructKeyPathReflection").field("a", &self.a).finish()
+        }
     }
     "###);
 }
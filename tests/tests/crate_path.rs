@@ -0,0 +1,42 @@
+use pathogen::macros::{KeyPathMutable, Navigable};
+use pathogen::{keypath, Change, KeyPathMutable as _, Navigable as _};
+use serde::{Deserialize, Serialize};
+
+// Stands in for pathogen re-exported under a different name
+mod renamed {
+    pub use pathogen::*;
+}
+
+#[derive(Debug, PartialEq, Navigable, KeyPathMutable, Serialize, Deserialize)]
+#[navigable(crate = "crate::renamed")]
+#[keypath_mutable(crate = "crate::renamed")]
+struct State {
+    count: usize,
+    mode: Mode,
+}
+
+#[derive(Debug, PartialEq, Navigable, KeyPathMutable, Serialize, Deserialize)]
+#[navigable(crate = "crate::renamed")]
+#[keypath_mutable(crate = "crate::renamed")]
+enum Mode {
+    Idle { since: usize },
+}
+
+#[test]
+fn derives_with_a_configured_crate_path() {
+    let mut state = State {
+        count: 1,
+        mode: Mode::Idle { since: 0 },
+    };
+
+    state.apply_change(&Change::update(keypath![State: count], 2));
+    state.apply_change(&Change::update(keypath![State: mode.Idle.since], 5));
+
+    assert_eq!(
+        state,
+        State {
+            count: 2,
+            mode: Mode::Idle { since: 5 },
+        }
+    );
+}
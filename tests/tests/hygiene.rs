@@ -0,0 +1,51 @@
+use pathogen::macros::{KeyPathMutable, Navigable};
+use pathogen::{keypath, Change, KeyPathMutable as _, Navigable as _};
+
+// Empty modules stand in for the deriving crate not depending on `serde` or `serde_json` under
+// those names, the derived impls have to reach them through pathogen
+mod serde {}
+mod serde_json {}
+
+#[derive(Debug, PartialEq, Navigable, KeyPathMutable, ::serde::Serialize, ::serde::Deserialize)]
+struct Paginated<T> {
+    items: Vec<T>,
+    cursor: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Navigable, KeyPathMutable, ::serde::Serialize, ::serde::Deserialize)]
+#[serde(tag = "type")]
+enum Status {
+    Idle { since: usize },
+    Counted { count: usize },
+}
+
+#[test]
+fn derives_without_serde_in_scope() {
+    let mut page = Paginated {
+        items: vec![1, 2],
+        cursor: None,
+    };
+
+    page.apply_change(&Change::update(keypath![Paginated<usize>: items[1]], 3));
+
+    assert_eq!(
+        page,
+        Paginated {
+            items: vec![1, 3],
+            cursor: None,
+        }
+    );
+}
+
+#[test]
+fn replaces_tagged_variants_without_serde_json_in_scope() {
+    let mut status = Status::Idle { since: 0 };
+    let counted = Status::variant_path("Counted").unwrap();
+
+    status.apply_change(&Change::update(
+        counted.downcast::<::serde_json::Value>(),
+        ::serde_json::json!({ "count": 1 }),
+    ));
+
+    assert_eq!(status, Status::Counted { count: 1 });
+}
@@ -0,0 +1,16 @@
+use pathogen::macros::KeyPathMutable;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, KeyPathMutable)]
+#[serde(deny_unknown_fields)]
+struct Account {
+    name: String,
+    #[keypath_mutable(skip)]
+    balance: u64,
+    // skipped by both, so this one is consistent
+    #[serde(skip)]
+    #[keypath_mutable(skip)]
+    cache: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use pathogen::macros::KeyPathMutable;
+use serde::{Deserialize, Serialize};
+
+trait Shape {}
+
+#[derive(Serialize, Deserialize, KeyPathMutable)]
+struct Drawing {
+    name: String,
+    #[serde(skip)]
+    shapes: Vec<Box<dyn Shape>>,
+}
+
+fn main() {}
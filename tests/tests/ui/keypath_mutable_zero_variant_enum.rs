@@ -0,0 +1,7 @@
+use pathogen::macros::KeyPathMutable;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, KeyPathMutable)]
+enum Never {}
+
+fn main() {}
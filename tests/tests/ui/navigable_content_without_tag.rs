@@ -0,0 +1,10 @@
+use pathogen::macros::Navigable;
+use serde::Serialize;
+
+#[derive(Serialize, Navigable)]
+#[serde(content = "data")]
+enum Message {
+    Text { body: String },
+}
+
+fn main() {}